@@ -0,0 +1,309 @@
+use handlebars::{
+    Context, Handlebars, Helper, HelperDef, HelperResult, Output, RenderContext, RenderErrorReason,
+};
+
+use crate::count::{count_of, is_singular};
+use crate::irregular::match_case;
+
+/// Irregular verb forms as `(singular, plural)` pairs.
+const VERBS: &[(&str, &str)] = &[
+    ("is", "are"),
+    ("was", "were"),
+    ("has", "have"),
+    ("does", "do"),
+    ("isn't", "aren't"),
+    ("wasn't", "weren't"),
+    ("hasn't", "haven't"),
+    ("doesn't", "don't"),
+];
+
+/// Verbs ending in `us` or `is` that take `es`, told apart from the far more common `use` and
+/// `ise` verbs like `causes` or `promises`.
+const ES_VERBS: &[&str] = &["bus", "focus", "refocus", "nonplus"];
+
+#[derive(Clone, Copy)]
+/// Verb agreement helper for handlebars-rust
+///
+/// # Registration
+///
+/// ```
+/// use handlebars::Handlebars;
+/// use handlebars_inflector::HandlebarsVerbAgreement;
+///
+/// let mut h = Handlebars::new();
+/// h.register_helper("agree", Box::new(HandlebarsVerbAgreement));
+///
+/// assert_eq!(h.render_template(r#"{{agree this "is"}}"#, &vec!["a", "b"]).expect("Render error"), "are");
+/// ```
+///
+/// # Arguments
+///
+/// * `count` - A number, a numeric string, an array or an object whose length is used as count
+/// * `verb` - The verb to agree with the count, in either singular or plural form
+///
+/// # Example usage:
+///
+/// `is` / `are`:
+///
+/// `
+/// {{agree errors "is"}}
+/// `
+///
+/// `exists` / `exist`:
+///
+/// `
+/// {{agree count one="exists" other="exist"}}
+/// `
+///
+/// # Verbs
+///
+/// `is`/`are`, `was`/`were`, `has`/`have`, `does`/`do` and their negated contractions are
+/// recognized in either form. Any other verb may be given in its base form or with the third
+/// person singular ending, which is added when the count is one and removed otherwise: `match`
+/// to `matches`, `tries` to `try`.
+///
+/// The `one` and `other` hash options take precedence over the verb table.
+///
+pub struct HandlebarsVerbAgreement;
+
+impl HelperDef for HandlebarsVerbAgreement {
+    fn call<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        r: &'reg Handlebars,
+        _ctx: &'rc Context,
+        _rc: &mut RenderContext<'reg, 'rc>,
+        out: &mut dyn Output,
+    ) -> HelperResult {
+        let count = if let Some(count) = h.param(0) {
+            count
+        } else {
+            if r.strict_mode() {
+                return Err(RenderErrorReason::ParamNotFoundForIndex("agree", 0).into());
+            }
+
+            return Ok(());
+        };
+
+        let count = if let Some(count) = count_of(count.value()) {
+            count
+        } else {
+            if r.strict_mode() {
+                return Err(RenderErrorReason::ParamTypeMismatchForName(
+                    "agree",
+                    "0".to_string(),
+                    "number or array".to_string(),
+                )
+                .into());
+            }
+
+            return Ok(());
+        };
+
+        let singular = is_singular(count);
+        let key = if singular { "one" } else { "other" };
+
+        if let Some(form) = h.hash_get(key).and_then(|v| v.value().as_str()) {
+            out.write(form)?;
+            return Ok(());
+        }
+
+        let verb = if let Some(verb) = h.param(1).and_then(|v| v.value().as_str()) {
+            verb
+        } else {
+            if r.strict_mode() {
+                return Err(RenderErrorReason::ParamNotFoundForIndex("agree", 1).into());
+            }
+
+            return Ok(());
+        };
+
+        out.write(&agree(verb, singular))?;
+
+        Ok(())
+    }
+}
+
+/// Return the form of `verb` agreeing with a singular or plural subject.
+fn agree(verb: &str, singular: bool) -> String {
+    let lower = verb.to_lowercase();
+
+    let form = VERBS
+        .iter()
+        .find(|(one, other)| *one == lower || *other == lower)
+        .map(|(one, other)| {
+            if singular {
+                one.to_string()
+            } else {
+                other.to_string()
+            }
+        })
+        .unwrap_or_else(|| {
+            let base = base_form(&lower).unwrap_or_else(|| lower.clone());
+
+            if singular {
+                third_person(&base)
+            } else {
+                base
+            }
+        });
+
+    match_case(verb, &form)
+}
+
+/// Base form of a regular verb already in the third person singular, or `None` when `verb`
+/// doesn't end like one.
+///
+/// `matches`, `passes`, `focuses` and `goes` lose `es`, `tries` becomes `try` and `runs` or `uses`
+/// lose the `s`. Verbs ending in `ss`, `us` or `is` are taken as base forms (`pass`, `focus`).
+fn base_form(verb: &str) -> Option<String> {
+    if let Some(stem) = verb.strip_suffix("ies") {
+        if stem.len() > 1 && !stem.ends_with(['a', 'e', 'o', 'u']) {
+            return Some(format!("{}y", stem));
+        }
+    }
+
+    if let Some(stem) = verb.strip_suffix("es") {
+        if ["ss", "x", "z", "ch", "sh", "o"]
+            .iter()
+            .any(|ending| stem.ends_with(ending))
+            || ES_VERBS.contains(&stem)
+        {
+            return Some(stem.to_string());
+        }
+    }
+
+    if verb.ends_with("ss") || verb.ends_with("us") || verb.ends_with("is") {
+        return None;
+    }
+
+    verb.strip_suffix('s')
+        .filter(|stem| !stem.is_empty())
+        .map(str::to_string)
+}
+
+/// Third person singular present tense of a regular verb.
+fn third_person(verb: &str) -> String {
+    if verb.ends_with('s')
+        || verb.ends_with('x')
+        || verb.ends_with('z')
+        || verb.ends_with("ch")
+        || verb.ends_with("sh")
+        || verb.ends_with('o')
+    {
+        format!("{}es", verb)
+    } else if verb.ends_with('y')
+        && !verb.ends_with("ay")
+        && !verb.ends_with("ey")
+        && !verb.ends_with("oy")
+        && !verb.ends_with("uy")
+    {
+        format!("{}ies", &verb[..verb.len() - 1])
+    } else {
+        format!("{}s", verb)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use handlebars::Handlebars;
+
+    #[test]
+    fn it_works() {
+        let mut h = Handlebars::new();
+        h.register_helper("agree", Box::new(HandlebarsVerbAgreement));
+
+        assert_eq!(
+            h.render_template(r#"{{agree 1 "are"}}"#, &())
+                .expect("Render error"),
+            "is",
+            "Failed to test singular count"
+        );
+        assert_eq!(
+            h.render_template(r#"{{agree 0 "is"}}"#, &())
+                .expect("Render error"),
+            "are",
+            "Failed to test zero count"
+        );
+        assert_eq!(
+            h.render_template(r#"{{agree this "Was"}}"#, &vec![1, 2])
+                .expect("Render error"),
+            "Were",
+            "Failed to test array length and capitalization"
+        );
+        assert_eq!(
+            h.render_template(r#"{{agree this "has"}}"#, &vec![1])
+                .expect("Render error"),
+            "has",
+            "Failed to test has"
+        );
+        assert_eq!(
+            h.render_template(r#"{{agree "1" "don't"}}"#, &())
+                .expect("Render error"),
+            "doesn't",
+            "Failed to test numeric string and contraction"
+        );
+        assert_eq!(
+            h.render_template(r#"{{agree 1 "match"}}"#, &())
+                .expect("Render error"),
+            "matches",
+            "Failed to test regular verb"
+        );
+        assert_eq!(
+            h.render_template(r#"{{agree 1 "try"}} {{agree 2 "try"}}"#, &())
+                .expect("Render error"),
+            "tries try",
+            "Failed to test regular verb ending in y"
+        );
+
+        for (template, expected) in [
+            (r#"{{agree 1 "matches"}}"#, "matches"),
+            (r#"{{agree 2 "matches"}}"#, "match"),
+            (r#"{{agree 1 "tries"}}"#, "tries"),
+            (r#"{{agree 2 "Tries"}}"#, "Try"),
+            (r#"{{agree 2 "uses"}}"#, "use"),
+            (r#"{{agree 1 "EXISTS"}}"#, "EXISTS"),
+            (r#"{{agree 1 "pass"}}"#, "passes"),
+            (r#"{{agree 1 "focus"}}"#, "focuses"),
+            (r#"{{agree 2 "focuses"}}"#, "focus"),
+            (r#"{{agree 2 "buses"}}"#, "bus"),
+            (r#"{{agree 2 "passes"}}"#, "pass"),
+            (r#"{{agree 2 "causes"}}"#, "cause"),
+            (r#"{{agree 2 "goes"}}"#, "go"),
+        ] {
+            assert_eq!(
+                h.render_template(template, &()).expect("Render error"),
+                expected,
+                "Failed to test {}",
+                template
+            );
+        }
+        assert_eq!(
+            h.render_template(r#"{{agree 3 one="exists" other="exist"}}"#, &())
+                .expect("Render error"),
+            "exist",
+            "Failed to test custom forms"
+        );
+    }
+
+    #[test]
+    fn it_errors_in_strict_mode() {
+        let mut h = Handlebars::new();
+        h.register_helper("agree", Box::new(HandlebarsVerbAgreement));
+
+        assert_eq!(
+            h.render_template(r#"{{agree true "is"}}"#, &())
+                .expect("Render error"),
+            "",
+            "Failed to test lenient type mismatch"
+        );
+
+        h.set_strict_mode(true);
+
+        assert!(
+            h.render_template(r#"{{agree true "is"}}"#, &()).is_err(),
+            "Failed to test strict type mismatch"
+        );
+    }
+}
@@ -0,0 +1,171 @@
+use std::collections::HashMap;
+
+use handlebars::{
+    Context, Handlebars, Helper, HelperDef, HelperResult, Output, RenderContext, RenderErrorReason,
+};
+
+use crate::flag;
+use crate::scope::{Occurrences, RenderScoped};
+
+static OCCURRENCES: RenderScoped<Occurrences> =
+    RenderScoped::new("__handlebars_inflector_heading_anchors");
+
+#[derive(Clone, Copy)]
+/// GitHub-compatible heading anchor helper for handlebars-rust
+///
+/// Produces the anchors GitHub generates for Markdown headings: lowercase, punctuation and
+/// symbols dropped, spaces replaced with hyphens. Repeated headings within a render get a
+/// counter suffix (`usage`, `usage-1`, `usage-2`), exactly like GitHub's slugger.
+///
+/// # Registration
+///
+/// ```
+/// use handlebars::Handlebars;
+/// use handlebars_inflector::HandlebarsHeadingAnchor;
+///
+/// let mut h = Handlebars::new();
+/// h.register_helper("heading_anchor", Box::new(HandlebarsHeadingAnchor));
+///
+/// assert_eq!(h.render_template(r#"{{heading_anchor "Quick Start: `inflect`!"}} {{heading_anchor "Quick start: inflect"}}"#, &()).expect("Render error"), "quick-start-inflect quick-start-inflect-1");
+/// ```
+///
+/// # Arguments
+///
+/// * `heading` - The heading text
+///
+/// # Hash options
+///
+/// `maintain_case`: keep the original letter case
+///
+/// `scope`: name of an independent duplicate counter. A table of contents rendered before the
+/// headings should use its own scope, so it links to the same anchors the headings produce.
+///
+/// # Example usage:
+///
+/// `
+/// {{#each sections}}<a href="#{{heading_anchor title scope="toc"}}">{{title}}</a>{{/each}}
+/// `
+///
+pub struct HandlebarsHeadingAnchor;
+
+impl HelperDef for HandlebarsHeadingAnchor {
+    fn call<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        r: &'reg Handlebars,
+        _ctx: &'rc Context,
+        rc: &mut RenderContext<'reg, 'rc>,
+        out: &mut dyn Output,
+    ) -> HelperResult {
+        let Some(heading) = h.param(0).and_then(|v| v.value().as_str()) else {
+            if r.strict_mode() {
+                return Err(RenderErrorReason::ParamNotFoundForIndex("heading_anchor", 0).into());
+            }
+
+            return Ok(());
+        };
+
+        let slug = slug(heading, flag(h, "maintain_case"));
+        let scope = h
+            .hash_get("scope")
+            .and_then(|v| v.value().as_str())
+            .unwrap_or_default();
+
+        let anchor = OCCURRENCES.with(rc, |occurrences| {
+            unique(occurrences.entry(scope.to_string()).or_default(), slug)
+        });
+
+        out.write(&anchor)?;
+
+        Ok(())
+    }
+}
+
+/// Slugify a heading the way github-slugger does, without the duplicate counter.
+pub(crate) fn slug(heading: &str, maintain_case: bool) -> String {
+    let heading = if maintain_case {
+        heading.to_string()
+    } else {
+        heading.to_lowercase()
+    };
+
+    heading
+        .chars()
+        .filter(|c| c.is_alphanumeric() || matches!(c, ' ' | '-' | '_'))
+        .map(|c| if c == ' ' { '-' } else { c })
+        .collect()
+}
+
+/// Disambiguate `slug` against the slugs seen so far.
+fn unique(occurrences: &mut HashMap<String, usize>, slug: String) -> String {
+    let mut result = slug.clone();
+
+    while occurrences.contains_key(&result) {
+        let count = occurrences.entry(slug.clone()).or_default();
+        *count += 1;
+        result = format!("{}-{}", slug, count);
+    }
+
+    occurrences.insert(result.clone(), 0);
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use handlebars::Handlebars;
+
+    #[test]
+    fn it_slugs_like_github() {
+        for (heading, expected) in [
+            ("Hello World", "hello-world"),
+            ("API Reference (v2.0)", "api-reference-v20"),
+            ("snake_case & kebab-case", "snake_case--kebab-case"),
+            ("Ünïcödé Heading", "ünïcödé-heading"),
+            ("🚀 Launch", "-launch"),
+            ("  Spaces  ", "--spaces--"),
+        ] {
+            assert_eq!(slug(heading, false), expected, "Failed to slug {}", heading);
+        }
+
+        assert_eq!(slug("Hello World", true), "Hello-World");
+    }
+
+    #[test]
+    fn it_works() {
+        let mut h = Handlebars::new();
+        h.register_helper("heading_anchor", Box::new(HandlebarsHeadingAnchor));
+
+        let template = r#"{{#each this}}[{{heading_anchor this scope="toc"}}]{{/each}}{{#each this}}({{heading_anchor this}}){{/each}}"#;
+        let headings = ["Usage", "Usage", "Usage 1", "Usage"];
+
+        for _ in 0..2 {
+            assert_eq!(
+                h.render_template(template, &headings)
+                    .expect("Render error"),
+                "[usage][usage-1][usage-1-1][usage-2](usage)(usage-1)(usage-1-1)(usage-2)",
+                "Failed to test duplicate counters"
+            );
+        }
+
+        for (template, expected) in [
+            (
+                r#"{{heading_anchor this maintain_case=true}}"#,
+                "Getting-Started",
+            ),
+            (
+                r#"{{heading_anchor this maintain_case=false}}"#,
+                "getting-started",
+            ),
+        ] {
+            assert_eq!(
+                h.render_template(template, &"Getting Started")
+                    .expect("Render error"),
+                expected,
+                "Failed to test {}",
+                template
+            );
+        }
+    }
+}
@@ -0,0 +1,134 @@
+/// Words starting with a silent `h`
+const SILENT_H: &[&str] = &["heir", "honest", "honor", "honour", "hour"];
+
+/// Letters whose name starts with a vowel sound, like `F` (eff) or `S` (ess)
+const VOWEL_LETTERS: &str = "AEFHILMNORSX";
+
+/// English indefinite article for `phrase`, judged by the pronunciation of its first word.
+///
+/// Acronyms are assumed to be spelled out letter by letter (`an API`, `a URL`) and numbers to
+/// be read as words (`an 8`, `an 11`, `a 1`).
+pub(crate) fn indefinite_article(phrase: &str) -> &'static str {
+    let word = phrase
+        .split(|c: char| c.is_whitespace() || c == '-' || c == '_')
+        .find(|word| !word.is_empty())
+        .unwrap_or_default();
+
+    if word.starts_with(|c: char| c.is_ascii_digit()) {
+        return number_article(word);
+    }
+
+    let letters: String = word.chars().take_while(|c| c.is_alphabetic()).collect();
+    let is_acronym = letters.chars().count() == 1
+        || letters.len() > 1 && letters.chars().all(|c| c.is_uppercase());
+
+    if is_acronym {
+        let first = letters
+            .chars()
+            .next()
+            .unwrap_or_default()
+            .to_ascii_uppercase();
+        return if VOWEL_LETTERS.contains(first) {
+            "an"
+        } else {
+            "a"
+        };
+    }
+
+    if starts_with_vowel_sound(&letters.to_lowercase()) {
+        "an"
+    } else {
+        "a"
+    }
+}
+
+/// Article for a number read as words: eight, eleven, eighteen and their multiples take `an`.
+fn number_article(word: &str) -> &'static str {
+    let digits: String = word
+        .chars()
+        .take_while(|c| c.is_ascii_digit() || *c == ',' || *c == '_')
+        .filter(char::is_ascii_digit)
+        .collect();
+
+    let eleven_or_eighteen =
+        digits.len() % 3 == 2 && (digits.starts_with("11") || digits.starts_with("18"));
+
+    if digits.starts_with('8') || eleven_or_eighteen {
+        "an"
+    } else {
+        "a"
+    }
+}
+
+fn starts_with_vowel_sound(word: &str) -> bool {
+    if SILENT_H.iter().any(|prefix| word.starts_with(prefix)) {
+        return true;
+    }
+
+    // One, European and ewe start with a consonant sound
+    if matches!(word, "one" | "once") || word.starts_with("eu") || word.starts_with("ewe") {
+        return false;
+    }
+
+    let mut chars = word.chars();
+
+    match chars.next() {
+        // Unit and user sound like "you", unless `un` is a prefix as in unable
+        Some('u') if word.starts_with("uni") => false,
+        Some('u') if word.starts_with("un") => true,
+        Some('u') => {
+            let is_vowel = |c: char| "aeiouy".contains(c);
+
+            !matches!(
+                (chars.next(), chars.next()),
+                (Some(consonant), Some(vowel)) if !is_vowel(consonant) && is_vowel(vowel)
+            )
+        }
+        Some(c) => "aeio".contains(c),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_picks_indefinite_articles() {
+        for (input, expected) in [
+            ("user", "a"),
+            ("apple", "an"),
+            ("hour", "an"),
+            ("honest mistake", "an"),
+            ("house", "a"),
+            ("API", "an"),
+            ("URL", "a"),
+            ("FBI agent", "an"),
+            ("X", "an"),
+            ("b", "a"),
+            ("s", "an"),
+            ("university", "a"),
+            ("unicorn", "a"),
+            ("umbrella", "an"),
+            ("unusual thing", "an"),
+            ("utility", "a"),
+            ("European", "a"),
+            ("one-off", "a"),
+            ("onion", "an"),
+            ("8", "an"),
+            ("80s", "an"),
+            ("11", "an"),
+            ("18,000", "an"),
+            ("110", "a"),
+            ("1", "a"),
+            ("", "a"),
+        ] {
+            assert_eq!(
+                indefinite_article(input),
+                expected,
+                "Failed to test {}",
+                input
+            );
+        }
+    }
+}
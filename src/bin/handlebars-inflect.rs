@@ -0,0 +1,190 @@
+//! Apply `inflect` operations to plain strings from the command line
+//!
+//! ```text
+//! echo ProductImages | handlebars-inflect --op snake --op plural
+//! ```
+
+use std::io::{self, BufRead, Write};
+use std::process::ExitCode;
+
+use handlebars_inflector::Pipeline;
+
+const USAGE: &str = "\
+Usage: handlebars-inflect [OPTIONS] [INPUT]...
+
+Applies the operations to every INPUT, or to every line of standard input when no INPUT is
+given, writing one result per line.
+
+Options:
+  --op NAME[=VALUE]     Apply an operation, in the order given: a full hash option name like
+                        `to_snake_case` or `to=kebab`, or a short name like `snake` or `plural`
+  --option KEY=VALUE    Pass a hash option to every operation, e.g. `profile=rust`
+  --list                List the available operations
+  -h, --help            Print this help";
+
+#[derive(Default)]
+struct Args {
+    pipeline: Pipeline,
+    inputs: Vec<String>,
+}
+
+enum Command {
+    Run(Box<Args>),
+    List,
+    Help,
+}
+
+fn parse_args<I: IntoIterator<Item = String>>(args: I) -> Result<Command, String> {
+    let mut parsed = Args::default();
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "--list" => return Ok(Command::List),
+            "--op" => {
+                let op = args.next().ok_or("`--op` requires an operation")?;
+                parsed.pipeline = parsed.pipeline.with_op(&op).map_err(|e| e.to_string())?;
+            }
+            "--option" => {
+                let option = args.next().ok_or("`--option` requires KEY=VALUE")?;
+                let (key, value) = option
+                    .split_once('=')
+                    .ok_or_else(|| format!("`{}` is not KEY=VALUE", option))?;
+                parsed.pipeline = parsed
+                    .pipeline
+                    .with_option(key, value)
+                    .map_err(|e| e.to_string())?;
+            }
+            _ if arg.starts_with("--") => return Err(format!("unknown argument `{}`", arg)),
+            _ => parsed.inputs.push(arg),
+        }
+    }
+
+    if parsed.pipeline.is_empty() {
+        return Err("at least one `--op` is required".to_string());
+    }
+
+    Ok(Command::Run(Box::new(parsed)))
+}
+
+/// Write the result for every input, or for every line of `stdin`, to `stdout`.
+///
+/// Blank lines are written as they are.
+fn run(args: Args, stdin: impl BufRead, mut stdout: impl Write) -> Result<(), String> {
+    let mut emit = |input: &str| -> Result<(), String> {
+        if input.trim().is_empty() {
+            return writeln!(stdout, "{}", input).map_err(|e| e.to_string());
+        }
+
+        let output = args
+            .pipeline
+            .apply(input)
+            .map_err(|e| format!("`{}`: {}", input, e))?;
+        writeln!(stdout, "{}", output).map_err(|e| e.to_string())
+    };
+
+    if args.inputs.is_empty() {
+        for line in stdin.lines() {
+            emit(&line.map_err(|e| e.to_string())?)?;
+        }
+    } else {
+        for input in &args.inputs {
+            emit(input)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let result = match parse_args(std::env::args().skip(1)) {
+        Ok(Command::Run(args)) => run(*args, io::stdin().lock(), io::stdout().lock()),
+        Ok(Command::List) => {
+            Pipeline::operations()
+                .iter()
+                .for_each(|name| println!("{}", name));
+            Ok(())
+        }
+        Ok(Command::Help) => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+        Err(e) => Err(format!("{}\n\n{}", e, USAGE)),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("handlebars-inflect: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Args {
+        match parse_args(args.iter().map(|a| a.to_string())).expect("Parse error") {
+            Command::Run(args) => *args,
+            _ => panic!("Failed to parse {:?}", args),
+        }
+    }
+
+    #[test]
+    fn it_parses_arguments() {
+        assert!(parse_args(["--op".to_string(), "pluralise".to_string()]).is_err());
+        assert!(parse_args(["ProductImages".to_string()]).is_err());
+        assert!(parse_args(["--option".to_string(), "profile".to_string()]).is_err());
+        assert!(parse_args(
+            ["--op", "snake", "--option", "to_plural=1"]
+                .iter()
+                .map(|a| a.to_string())
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn it_applies_operations_in_order() {
+        for (argv, input, expected) in [
+            (
+                vec!["--op", "snake", "--op", "plural"],
+                "ProductImage",
+                "product_images",
+            ),
+            (vec!["--op", "plural", "--op", "upper"], "box", "BOXES"),
+            (vec!["--op", "to=kebab"], "ABTest", "ab-test"),
+            (
+                vec!["--op", "identifier", "--option", "profile=rust"],
+                "2fa enabled",
+                "_2fa_enabled",
+            ),
+        ] {
+            assert_eq!(
+                args(&argv).pipeline.apply(input).expect("Render error"),
+                expected,
+                "Failed to test {:?}",
+                argv
+            );
+        }
+    }
+
+    #[test]
+    fn it_passes_blank_lines_through() {
+        let mut stdout = Vec::new();
+
+        run(
+            args(&["--op", "snake", "--op", "plural"]),
+            "ProductImage\n\n  \nbox\n".as_bytes(),
+            &mut stdout,
+        )
+        .expect("Run error");
+
+        assert_eq!(
+            String::from_utf8(stdout).expect("UTF-8 error"),
+            "product_images\n\n  \nboxes\n"
+        );
+    }
+}
@@ -0,0 +1,55 @@
+use handlebars::{
+    BlockContext, Context, Handlebars, Helper, HelperResult, JsonValue, Output, RenderContext,
+    Renderable,
+};
+
+/// Render the main block of a block helper call with `value` as its context.
+pub(crate) fn render_block<'reg: 'rc, 'rc>(
+    h: &Helper<'rc>,
+    r: &'reg Handlebars,
+    ctx: &'rc Context,
+    rc: &mut RenderContext<'reg, 'rc>,
+    out: &mut dyn Output,
+    value: JsonValue,
+) -> HelperResult {
+    let Some(template) = h.template() else {
+        return Ok(());
+    };
+
+    let mut block = BlockContext::new();
+    block.set_base_value(value);
+
+    rc.push_block(block);
+    let result = template.render(r, ctx, rc, out);
+    rc.pop_block();
+
+    result
+}
+
+/// Render the main block of a block helper call in the current context.
+pub(crate) fn render_body<'reg: 'rc, 'rc>(
+    h: &Helper<'rc>,
+    r: &'reg Handlebars,
+    ctx: &'rc Context,
+    rc: &mut RenderContext<'reg, 'rc>,
+    out: &mut dyn Output,
+) -> HelperResult {
+    match h.template() {
+        Some(template) => template.render(r, ctx, rc, out),
+        None => Ok(()),
+    }
+}
+
+/// Render the `{{else}}` block of a block helper call, if there is one.
+pub(crate) fn render_inverse<'reg: 'rc, 'rc>(
+    h: &Helper<'rc>,
+    r: &'reg Handlebars,
+    ctx: &'rc Context,
+    rc: &mut RenderContext<'reg, 'rc>,
+    out: &mut dyn Output,
+) -> HelperResult {
+    match h.inverse() {
+        Some(inverse) => inverse.render(r, ctx, rc, out),
+        None => Ok(()),
+    }
+}
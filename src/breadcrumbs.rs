@@ -0,0 +1,208 @@
+use handlebars::{
+    Context, Handlebars, Helper, HelperDef, JsonValue, RenderContext, RenderError,
+    RenderErrorReason, ScopedJson,
+};
+use serde_json::{json, Map};
+
+use crate::flag;
+
+#[derive(Clone, Copy)]
+/// Breadcrumb helper for handlebars-rust
+///
+/// Splits a URL path into segments and returns an array of humanized, title-cased labels, so
+/// breadcrumb partials only need a single `#each`.
+///
+/// # Registration
+///
+/// ```
+/// use handlebars::Handlebars;
+/// use handlebars_inflector::HandlebarsBreadcrumbs;
+///
+/// let mut h = Handlebars::new();
+/// h.register_helper("breadcrumbs", Box::new(HandlebarsBreadcrumbs));
+///
+/// assert_eq!(h.render_template(r#"{{#each (breadcrumbs this)}}{{#unless @first}} / {{/unless}}{{this}}{{/each}}"#, &String::from("/docs/getting-started")).expect("Render error"), "Docs / Getting Started");
+/// ```
+///
+/// # Arguments
+///
+/// * `path` - A URL or URL path. Scheme, host, query and fragment are ignored.
+///
+/// # Hash options
+///
+/// `hrefs`: return objects with a `label` and the cumulative `href` of each segment instead of
+/// plain labels
+///
+/// `home`: label of an extra leading crumb pointing to `/`
+///
+/// # Example usage:
+///
+/// `
+/// {{#each (breadcrumbs url hrefs=true home="Home")}}<a href="{{href}}">{{label}}</a>{{/each}}
+/// `
+///
+pub struct HandlebarsBreadcrumbs;
+
+impl HelperDef for HandlebarsBreadcrumbs {
+    fn call_inner<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        r: &'reg Handlebars,
+        _ctx: &'rc Context,
+        _rc: &mut RenderContext<'reg, 'rc>,
+    ) -> Result<ScopedJson<'rc>, RenderError> {
+        let Some(url) = h.param(0).and_then(|v| v.value().as_str()) else {
+            if r.strict_mode() {
+                return Err(RenderErrorReason::ParamNotFoundForIndex("breadcrumbs", 0).into());
+            }
+
+            return Ok(ScopedJson::Derived(JsonValue::Array(Vec::new())));
+        };
+
+        let hrefs = flag(h, "hrefs");
+        let mut crumbs = Vec::new();
+
+        if let Some(home) = h.hash_get("home").and_then(|v| v.value().as_str()) {
+            crumbs.push(crumb(home.to_string(), "/".to_string(), hrefs));
+        }
+
+        let mut href = String::new();
+
+        for segment in path_of(url).split('/').filter(|s| !s.is_empty()) {
+            href.push('/');
+            href.push_str(segment);
+
+            crumbs.push(crumb(humanize_segment(segment), href.clone(), hrefs));
+        }
+
+        Ok(ScopedJson::Derived(JsonValue::Array(crumbs)))
+    }
+}
+
+fn crumb(label: String, href: String, hrefs: bool) -> JsonValue {
+    if hrefs {
+        let mut object = Map::new();
+        object.insert("label".to_string(), json!(label));
+        object.insert("href".to_string(), json!(href));
+        JsonValue::Object(object)
+    } else {
+        json!(label)
+    }
+}
+
+/// Strip scheme, authority, query and fragment from `url`.
+fn path_of(url: &str) -> &str {
+    let url = url.split(['?', '#']).next().unwrap_or_default();
+
+    match url.split_once("://") {
+        Some((_, rest)) => rest.find('/').map(|i| &rest[i..]).unwrap_or_default(),
+        None => url,
+    }
+}
+
+/// Turn a path segment into a label: `getting-started.html` to `Getting Started`.
+fn humanize_segment(segment: &str) -> String {
+    let decoded = percent_decode(segment);
+    let name = match decoded.rsplit_once('.') {
+        Some((name, extension))
+            if !name.is_empty()
+                && extension.starts_with(|c: char| c.is_ascii_alphabetic())
+                && extension.chars().all(|c| c.is_ascii_alphanumeric()) =>
+        {
+            name
+        }
+        _ => decoded.as_str(),
+    };
+
+    name.split(|c: char| c == '-' || c == '_' || c == '+' || c.is_whitespace())
+        .filter(|w| !w.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|c| c.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Decode `%XX` escapes, leaving malformed escapes untouched.
+fn percent_decode(input: &str) -> String {
+    let bytes = input.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let escape = (bytes[i] == b'%')
+            .then(|| input.get(i + 1..i + 3))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+
+        match escape {
+            Some(byte) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use handlebars::Handlebars;
+
+    #[test]
+    fn it_humanizes_segments() {
+        assert_eq!(path_of("https://example.com/docs/faq?x=1#top"), "/docs/faq");
+        assert_eq!(path_of("https://example.com"), "");
+        assert_eq!(humanize_segment("getting-started"), "Getting Started");
+        assert_eq!(humanize_segment("FAQ.html"), "FAQ");
+        assert_eq!(
+            humanize_segment("release_notes%20v1.2"),
+            "Release Notes V1.2"
+        );
+        assert_eq!(humanize_segment("caf%C3%A9"), "Café");
+    }
+
+    #[test]
+    fn it_works() {
+        let mut h = Handlebars::new();
+        h.register_helper("breadcrumbs", Box::new(HandlebarsBreadcrumbs));
+
+        assert_eq!(
+            h.render_template(
+                r#"{{#each (breadcrumbs this)}}[{{this}}]{{/each}}"#,
+                &"https://example.com/docs/api-reference/?page=2"
+            )
+            .expect("Render error"),
+            "[Docs][Api Reference]",
+            "Failed to test labels"
+        );
+        assert_eq!(
+            h.render_template(
+                r#"{{#each (breadcrumbs this hrefs=true home="Home")}}<a href="{{href}}">{{label}}</a>{{/each}}"#,
+                &"/docs/getting-started"
+            )
+            .expect("Render error"),
+            r#"<a href="/">Home</a><a href="/docs">Docs</a><a href="/docs/getting-started">Getting Started</a>"#,
+            "Failed to test hrefs"
+        );
+        assert_eq!(
+            h.render_template(
+                r#"{{#each (breadcrumbs this hrefs=false)}}[{{this}}]{{/each}}"#,
+                &"/docs/getting-started"
+            )
+            .expect("Render error"),
+            "[Docs][Getting Started]",
+            "Failed to test hrefs=false"
+        );
+    }
+}
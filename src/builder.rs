@@ -0,0 +1,229 @@
+#[cfg(feature = "i18n")]
+use crate::Locale;
+use crate::{CaseSpec, HandlebarsInflector, ReservedWords, Rules, Strictness};
+
+/// Builder of a configured `inflect` helper, created with [`HandlebarsInflector::builder`]
+///
+/// Every method mirrors a `with_*` method of [`HandlebarsInflector`]; an unconfigured helper is
+/// still available as the `HandlebarsInflector` constant.
+///
+/// ```
+/// use handlebars::Handlebars;
+/// use handlebars_inflector::{HandlebarsInflector, Strictness};
+///
+/// let inflector = HandlebarsInflector::builder()
+///     .acronyms(["API"])
+///     .rules([("octopus", "octopuses")])
+///     .strictness(Strictness::Always)
+///     .default_ops("to_pascal_case")
+///     .build();
+///
+/// let mut h = Handlebars::new();
+/// h.register_helper("inflect", Box::new(inflector));
+///
+/// assert_eq!(h.render_template(r#"{{inflect this}}"#, &String::from("api_key")).expect("Render error"), "APIKey");
+/// ```
+#[derive(Clone, Default)]
+pub struct HandlebarsInflectorBuilder {
+    inflector: HandlebarsInflector,
+}
+
+impl HandlebarsInflectorBuilder {
+    /// Start from a helper without any configuration
+    pub const fn new() -> Self {
+        HandlebarsInflectorBuilder {
+            inflector: HandlebarsInflector::new(),
+        }
+    }
+
+    /// See [`HandlebarsInflector::with_reserved`]
+    pub fn reserved<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.inflector = self.inflector.with_reserved(names);
+        self
+    }
+
+    /// See [`HandlebarsInflector::with_reserved_suffix`]
+    pub fn reserved_suffix<S: Into<String>>(mut self, suffix: S) -> Self {
+        self.inflector = self.inflector.with_reserved_suffix(suffix);
+        self
+    }
+
+    /// See [`HandlebarsInflector::with_reserved_words`]
+    pub fn reserved_words<S: Into<String>>(mut self, name: S, words: ReservedWords) -> Self {
+        self.inflector = self.inflector.with_reserved_words(name, words);
+        self
+    }
+
+    /// See [`HandlebarsInflector::with_overrides`]
+    pub fn overrides<I, S, T>(mut self, overrides: I) -> Self
+    where
+        I: IntoIterator<Item = (S, T)>,
+        S: Into<String>,
+        T: Into<String>,
+    {
+        self.inflector = self.inflector.with_overrides(overrides);
+        self
+    }
+
+    /// See [`HandlebarsInflector::with_rules`]
+    pub fn rules<I, S, T>(mut self, rules: I) -> Self
+    where
+        I: IntoIterator<Item = (S, T)>,
+        S: Into<String>,
+        T: Into<String>,
+    {
+        self.inflector = self.inflector.with_rules(rules);
+        self
+    }
+
+    /// See [`HandlebarsInflector::with_uncountable`]
+    pub fn uncountable<I, S>(mut self, nouns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.inflector = self.inflector.with_uncountable(nouns);
+        self
+    }
+
+    /// See [`HandlebarsInflector::with_acronyms`]
+    pub fn acronyms<I, S>(mut self, acronyms: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.inflector = self.inflector.with_acronyms(acronyms);
+        self
+    }
+
+    /// See [`HandlebarsInflector::with_abbreviations`]
+    pub fn abbreviations<I, S, T>(mut self, abbreviations: I) -> Self
+    where
+        I: IntoIterator<Item = (S, T)>,
+        S: Into<String>,
+        T: Into<String>,
+    {
+        self.inflector = self.inflector.with_abbreviations(abbreviations);
+        self
+    }
+
+    /// See [`HandlebarsInflector::with_case`]
+    pub fn case<S: Into<String>>(mut self, name: S, spec: CaseSpec) -> Self {
+        self.inflector = self.inflector.with_case(name, spec);
+        self
+    }
+
+    /// See [`HandlebarsInflector::with_table_prefix`]
+    pub fn table_prefix<S: Into<String>>(mut self, prefix: S) -> Self {
+        self.inflector = self.inflector.with_table_prefix(prefix);
+        self
+    }
+
+    /// See [`HandlebarsInflector::with_vendor_prefixes`]
+    pub fn vendor_prefixes<I, S>(mut self, prefixes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.inflector = self.inflector.with_vendor_prefixes(prefixes);
+        self
+    }
+
+    /// See [`HandlebarsInflector::with_namespace_separator`]
+    pub fn namespace_separator<S: Into<String>>(mut self, separator: S) -> Self {
+        self.inflector = self.inflector.with_namespace_separator(separator);
+        self
+    }
+
+    /// See [`HandlebarsInflector::with_default_ops`]
+    pub fn default_ops<S: Into<String>>(mut self, ops: S) -> Self {
+        self.inflector = self.inflector.with_default_ops(ops);
+        self
+    }
+
+    /// See [`HandlebarsInflector::with_coercion`]
+    pub fn coercion(mut self, coerce: bool) -> Self {
+        self.inflector = self.inflector.with_coercion(coerce);
+        self
+    }
+
+    /// See [`HandlebarsInflector::with_pipeline`]
+    pub fn pipeline<S: Into<String>, T: Into<String>>(mut self, name: S, ops: T) -> Self {
+        self.inflector = self.inflector.with_pipeline(name, ops);
+        self
+    }
+
+    /// See [`HandlebarsInflector::with_locale`]
+    #[cfg(feature = "i18n")]
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.inflector = self.inflector.with_locale(locale);
+        self
+    }
+
+    /// See [`HandlebarsInflector::with_strictness`]
+    pub fn strictness(mut self, strictness: Strictness) -> Self {
+        self.inflector = self.inflector.with_strictness(strictness);
+        self
+    }
+
+    /// See [`HandlebarsInflector::with_rule_set`]
+    pub fn rule_set(mut self, rules: Rules) -> Self {
+        self.inflector = self.inflector.with_rule_set(rules);
+        self
+    }
+
+    /// Finish configuring the helper
+    pub fn build(self) -> HandlebarsInflector {
+        self.inflector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use handlebars::Handlebars;
+
+    use super::*;
+
+    #[test]
+    fn it_builds_configured_helpers() {
+        let mut h = Handlebars::new();
+        h.register_helper(
+            "inflect",
+            Box::new(
+                HandlebarsInflector::builder()
+                    .reserved(["type"])
+                    .uncountable(["equipment"])
+                    .default_ops("to_snake_case|to_plural")
+                    .build(),
+            ),
+        );
+
+        for (template, input, expected) in [
+            (r#"{{inflect this}}"#, "ProductImage", "product_images"),
+            (r#"{{inflect this}}"#, "MiningEquipment", "mining_equipment"),
+            (
+                r#"{{inflect this to_kebab_case=true}}"#,
+                "ProductImage",
+                "product-image",
+            ),
+            (
+                r#"{{inflect this ops="to_camel_case"}}"#,
+                "product_image",
+                "productImage",
+            ),
+            (r#"{{inflect this to_lower_case=true}}"#, "TYPE", "type_"),
+        ] {
+            assert_eq!(
+                h.render_template(template, &input).expect("Render error"),
+                expected,
+                "Failed to test {} with {}",
+                template,
+                input
+            );
+        }
+    }
+}
@@ -0,0 +1,669 @@
+use std::collections::BTreeSet;
+use std::str::FromStr;
+
+use crate::case_spec::{split_digits, Digits};
+
+/// Case convention used by the `from` and `to` hash options
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Convention {
+    /// `productImages`
+    Camel,
+    /// `ProductImages`
+    Pascal,
+    /// `product_images`
+    Snake,
+    /// `PRODUCT_IMAGES`
+    ScreamingSnake,
+    /// `product-images`
+    Kebab,
+    /// `Product-Images`
+    Train,
+    /// `Product images`
+    Sentence,
+    /// `Product Images`
+    Title,
+    /// `product.images`
+    Dot,
+    /// `product/images`
+    Path,
+}
+
+impl FromStr for Convention {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.strip_suffix("_case").unwrap_or(s) {
+            "camel" => Ok(Convention::Camel),
+            "pascal" => Ok(Convention::Pascal),
+            "snake" => Ok(Convention::Snake),
+            "screaming_snake" | "constant" => Ok(Convention::ScreamingSnake),
+            "kebab" => Ok(Convention::Kebab),
+            "train" => Ok(Convention::Train),
+            "sentence" => Ok(Convention::Sentence),
+            "title" => Ok(Convention::Title),
+            "dot" => Ok(Convention::Dot),
+            "path" => Ok(Convention::Path),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Style guide applied by `to_title_case` with the `style` hash option
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum TitleStyle {
+    /// AP Stylebook: articles, conjunctions and prepositions of up to three letters stay lowercase
+    Ap,
+    /// Chicago Manual of Style: articles, prepositions of any length and `and`, `but`, `for`,
+    /// `or`, `nor` stay lowercase
+    Chicago,
+}
+
+/// Words kept lowercase in AP style titles
+const AP_MINOR_WORDS: &[&str] = &[
+    "a", "an", "and", "as", "at", "but", "by", "for", "in", "nor", "of", "off", "on", "or", "out",
+    "per", "so", "the", "to", "up", "via", "yet",
+];
+
+/// Words kept lowercase in Chicago style titles
+const CHICAGO_MINOR_WORDS: &[&str] = &[
+    "a",
+    "about",
+    "above",
+    "across",
+    "after",
+    "against",
+    "along",
+    "among",
+    "an",
+    "and",
+    "around",
+    "as",
+    "at",
+    "before",
+    "behind",
+    "below",
+    "beneath",
+    "beside",
+    "between",
+    "beyond",
+    "but",
+    "by",
+    "down",
+    "during",
+    "except",
+    "for",
+    "from",
+    "in",
+    "inside",
+    "into",
+    "like",
+    "near",
+    "nor",
+    "of",
+    "off",
+    "on",
+    "onto",
+    "or",
+    "out",
+    "outside",
+    "over",
+    "past",
+    "per",
+    "since",
+    "the",
+    "through",
+    "throughout",
+    "till",
+    "to",
+    "toward",
+    "towards",
+    "under",
+    "underneath",
+    "until",
+    "up",
+    "upon",
+    "via",
+    "with",
+    "within",
+    "without",
+];
+
+impl FromStr for TitleStyle {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ap" => Ok(TitleStyle::Ap),
+            "chicago" => Ok(TitleStyle::Chicago),
+            _ => Err(()),
+        }
+    }
+}
+
+impl TitleStyle {
+    /// Lowercase the minor words of a title case `title`, except the first and the last word.
+    pub(crate) fn apply(self, title: &str) -> String {
+        let minor_words = match self {
+            TitleStyle::Ap => AP_MINOR_WORDS,
+            TitleStyle::Chicago => CHICAGO_MINOR_WORDS,
+        };
+        let words: Vec<&str> = title.split(' ').collect();
+        let last = words.len().saturating_sub(1);
+
+        words
+            .iter()
+            .enumerate()
+            .map(|(i, word)| {
+                let lower = word.to_lowercase();
+
+                if i > 0 && i < last && minor_words.contains(&lower.as_str()) {
+                    lower
+                } else {
+                    word.to_string()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+impl Convention {
+    /// Split `input` into words, assuming it is written in this convention.
+    ///
+    /// Separated conventions only split on their separator, camel and pascal case only split on
+    /// case humps, so digits and acronyms never introduce guessed boundaries.
+    pub(crate) fn split(self, input: &str, boundaries: Boundaries) -> Vec<&str> {
+        let words = match self {
+            Convention::Camel | Convention::Pascal => return split_words(input, boundaries),
+            Convention::Snake | Convention::ScreamingSnake => split_on(input, |c| c == '_'),
+            Convention::Kebab | Convention::Train => split_on(input, |c| c == '-'),
+            Convention::Sentence | Convention::Title => split_on(input, char::is_whitespace),
+            Convention::Dot => split_on(input, |c| c == '.'),
+            Convention::Path => split_on(input, |c| c == '/'),
+        };
+
+        boundaries.split_digits(words)
+    }
+
+    /// Join `words` according to this convention.
+    pub(crate) fn join(self, words: &[&str]) -> String {
+        self.join_with_acronyms(words, &BTreeSet::new())
+    }
+
+    /// Join `words` according to this convention, writing capitalized words found in `acronyms`
+    /// in uppercase.
+    pub(crate) fn join_with_acronyms(self, words: &[&str], acronyms: &BTreeSet<String>) -> String {
+        let (separator, first, rest) = match self {
+            Convention::Camel => ("", WordCase::Lower, WordCase::Capitalized),
+            Convention::Pascal => ("", WordCase::Capitalized, WordCase::Capitalized),
+            Convention::Snake => ("_", WordCase::Lower, WordCase::Lower),
+            Convention::ScreamingSnake => ("_", WordCase::Upper, WordCase::Upper),
+            Convention::Kebab => ("-", WordCase::Lower, WordCase::Lower),
+            Convention::Train => ("-", WordCase::Capitalized, WordCase::Capitalized),
+            Convention::Sentence => (" ", WordCase::Capitalized, WordCase::Lower),
+            Convention::Title => (" ", WordCase::Title, WordCase::Title),
+            Convention::Dot => (".", WordCase::Lower, WordCase::Lower),
+            Convention::Path => ("/", WordCase::Lower, WordCase::Lower),
+        };
+
+        let mut output = String::new();
+
+        for (i, word) in words.iter().enumerate() {
+            let case = if i == 0 { first } else { rest };
+
+            if i > 0 {
+                output.push_str(separator);
+            }
+
+            if case != WordCase::Lower && acronyms.contains(&word.to_uppercase()) {
+                output.push_str(&word.to_uppercase());
+            } else {
+                output.push_str(&case.apply(word));
+            }
+        }
+
+        output
+    }
+}
+
+/// Casing applied to a single word
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WordCase {
+    /// `word`
+    Lower,
+    /// `WORD`
+    Upper,
+    /// `Word`
+    Capitalized,
+    /// `Word`, hyphenated compounds as `State-of-the-Art`
+    Title,
+}
+
+impl WordCase {
+    pub(crate) fn apply(self, word: &str) -> String {
+        match self {
+            WordCase::Lower => word.to_lowercase(),
+            WordCase::Upper => word.to_uppercase(),
+            WordCase::Capitalized => capitalize(word),
+            WordCase::Title => capitalize_compound(word),
+        }
+    }
+}
+
+/// Split `input` into words without knowing its convention.
+///
+/// Every non-alphanumeric character is a separator and case humps start new words. Apostrophes
+/// and periods between two alphanumeric characters are kept when `boundaries` says so.
+pub(crate) fn split_words(input: &str, boundaries: Boundaries) -> Vec<&str> {
+    let chars: Vec<(usize, char)> = input.char_indices().collect();
+    let mut words = Vec::new();
+    let mut start = None;
+
+    for (i, &(index, c)) in chars.iter().enumerate() {
+        let word_char = c.is_alphanumeric()
+            || (boundaries.keeps(c)
+                && i > 0
+                && chars[i - 1].1.is_alphanumeric()
+                && chars.get(i + 1).is_some_and(|(_, n)| n.is_alphanumeric()));
+
+        match (word_char, start) {
+            (true, None) => start = Some(index),
+            (false, Some(s)) => {
+                words.extend(split_humps(&input[s..index]));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(s) = start {
+        words.extend(split_humps(&input[s..]));
+    }
+
+    boundaries.split_digits(words)
+}
+
+/// Treatment of apostrophes, periods or hyphens, selected with the `apostrophes`, `periods` and
+/// `hyphens` hash options
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) enum Punctuation {
+    /// Act as a word boundary: `don't` to `don`, `t`
+    #[default]
+    Split,
+    /// Stay part of the word: `v1.2` to `v1.2`
+    Keep,
+    /// Be removed before any splitting: `don't` to `dont`
+    Strip,
+}
+
+impl FromStr for Punctuation {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "split" => Ok(Punctuation::Split),
+            "keep" => Ok(Punctuation::Keep),
+            "strip" => Ok(Punctuation::Strip),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Word boundary configuration for apostrophes, periods, hyphens and digits
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Boundaries {
+    pub(crate) apostrophes: Punctuation,
+    pub(crate) periods: Punctuation,
+    pub(crate) hyphens: Punctuation,
+    /// Set by the `digits` hash option, `None` leaves digits to each operation
+    pub(crate) digits: Option<Digits>,
+}
+
+impl Boundaries {
+    fn treatment(&self, c: char) -> Option<Punctuation> {
+        match c {
+            '\'' | '\u{2019}' => Some(self.apostrophes),
+            '.' => Some(self.periods),
+            '-' | '\u{2010}' => Some(self.hyphens),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn keeps(&self, c: char) -> bool {
+        self.treatment(c) == Some(Punctuation::Keep)
+    }
+
+    /// Whether any punctuation is kept or digits are configured, so case conversions have to
+    /// split words with [`split_words`] instead of leaving it to Inflector.
+    pub(crate) fn is_custom(&self) -> bool {
+        self.digits.is_some()
+            || [self.apostrophes, self.periods, self.hyphens].contains(&Punctuation::Keep)
+    }
+
+    /// Make every run of digits in `words` a word of its own with [`Digits::Separate`].
+    pub(crate) fn split_digits<'a>(&self, words: Vec<&'a str>) -> Vec<&'a str> {
+        match self.digits {
+            Some(Digits::Separate) => words.into_iter().flat_map(split_digits).collect(),
+            _ => words,
+        }
+    }
+
+    /// Remove all punctuation configured with [`Punctuation::Strip`] from `input`.
+    pub(crate) fn strip(&self, input: &str) -> String {
+        input
+            .chars()
+            .filter(|c| self.treatment(*c) != Some(Punctuation::Strip))
+            .collect()
+    }
+}
+
+fn split_on(input: &str, separator: fn(char) -> bool) -> Vec<&str> {
+    input.split(separator).filter(|w| !w.is_empty()).collect()
+}
+
+/// Split a single run of alphanumeric characters on case humps.
+///
+/// A new word starts at an uppercase letter following a lowercase letter or a digit, and at the
+/// last uppercase letter of an uppercase run followed by a lowercase letter (`ABTest` to `AB`,
+/// `Test`). Digits stay attached to the preceding word.
+fn split_humps(input: &str) -> Vec<&str> {
+    let chars: Vec<(usize, char)> = input.char_indices().collect();
+    let mut words = Vec::new();
+    let mut start = 0;
+
+    for i in 1..chars.len() {
+        let (index, current) = chars[i];
+        let previous = chars[i - 1].1;
+        let next = chars.get(i + 1).map(|(_, c)| *c);
+
+        let boundary = current.is_uppercase()
+            && (previous.is_lowercase()
+                || previous.is_numeric()
+                || (previous.is_uppercase() && next.is_some_and(char::is_lowercase)));
+
+        if boundary {
+            words.push(&input[start..index]);
+            start = index;
+        }
+    }
+
+    if start < input.len() {
+        words.push(&input[start..]);
+    }
+
+    words
+}
+
+/// Uppercase the first character of `word` and lowercase the rest.
+pub(crate) fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+
+    chars
+        .next()
+        .map(|c| {
+            c.to_uppercase()
+                .chain(chars.flat_map(char::to_lowercase))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Uppercase the first character of `word`, leaving the rest as it is.
+pub(crate) fn upper_first(word: &str) -> String {
+    let mut chars = word.chars();
+
+    chars
+        .next()
+        .map(|c| c.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+/// Lowercase the first character of `word`, leaving the rest as it is.
+pub(crate) fn lower_first(word: &str) -> String {
+    let mut chars = word.chars();
+
+    chars
+        .next()
+        .map(|c| c.to_lowercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+/// Uppercase first characters of `words`, each followed by a period when `dotted`.
+pub(crate) fn initials(words: &[&str], dotted: bool) -> String {
+    let mut output = String::new();
+
+    for word in words {
+        if let Some(c) = word.chars().next() {
+            output.extend(c.to_uppercase());
+
+            if dotted {
+                output.push('.');
+            }
+        }
+    }
+
+    output
+}
+
+/// Invert the case of every character of `input`.
+pub(crate) fn swap_case(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+
+    for c in input.chars() {
+        if c.is_uppercase() {
+            output.extend(c.to_lowercase());
+        } else if c.is_lowercase() {
+            output.extend(c.to_uppercase());
+        } else {
+            output.push(c);
+        }
+    }
+
+    output
+}
+
+/// Words kept lowercase inside a hyphenated compound, unless they start it
+const MINOR_WORDS: &[&str] = &[
+    "a", "an", "and", "as", "at", "but", "by", "for", "in", "nor", "of", "on", "or", "the", "to",
+    "vs", "via",
+];
+
+/// Capitalize every part of a hyphenated compound except minor words after the first part.
+///
+/// `state-of-the-art` becomes `State-of-the-Art`, `e-commerce` becomes `E-Commerce`.
+pub(crate) fn capitalize_compound(word: &str) -> String {
+    let mut output = String::with_capacity(word.len());
+
+    for (i, part) in word.split_inclusive(['-', '\u{2010}']).enumerate() {
+        let (part, hyphen) = part.split_at(part.trim_end_matches(['-', '\u{2010}']).len());
+        let lower = part.to_lowercase();
+
+        if i > 0 && MINOR_WORDS.contains(&lower.as_str()) {
+            output.push_str(&lower);
+        } else {
+            output.push_str(&capitalize(part));
+        }
+
+        output.push_str(hyphen);
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_applies_title_styles() {
+        for (style, input, expected) in [
+            (
+                TitleStyle::Ap,
+                "The Lord Of The Rings",
+                "The Lord of the Rings",
+            ),
+            (
+                TitleStyle::Ap,
+                "A Walk Through The Woods",
+                "A Walk Through the Woods",
+            ),
+            (
+                TitleStyle::Chicago,
+                "A Walk Through The Woods",
+                "A Walk through the Woods",
+            ),
+            (TitleStyle::Ap, "What It Stands For", "What It Stands For"),
+            (TitleStyle::Chicago, "Of", "Of"),
+            (TitleStyle::Chicago, "", ""),
+        ] {
+            assert_eq!(
+                style.apply(input),
+                expected,
+                "Failed to test {:?} with {}",
+                style,
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn it_splits_declared_conventions() {
+        for (input, from, expected) in [
+            ("ABTest", Convention::Pascal, vec!["AB", "Test"]),
+            ("v2Id", Convention::Camel, vec!["v2", "Id"]),
+            (
+                "productImageURL",
+                Convention::Camel,
+                vec!["product", "Image", "URL"],
+            ),
+            (
+                "product_images_v2",
+                Convention::Snake,
+                vec!["product", "images", "v2"],
+            ),
+            ("userID_list", Convention::Snake, vec!["userID", "list"]),
+            (
+                "e-commerce-API",
+                Convention::Kebab,
+                vec!["e", "commerce", "API"],
+            ),
+            (
+                "Product  images",
+                Convention::Sentence,
+                vec!["Product", "images"],
+            ),
+            (
+                "server.max_connections",
+                Convention::Dot,
+                vec!["server", "max_connections"],
+            ),
+            ("/api//users/", Convention::Path, vec!["api", "users"]),
+        ] {
+            assert_eq!(
+                from.split(input, Boundaries::default()),
+                expected,
+                "Failed to split {} as {:?}",
+                input,
+                from
+            );
+        }
+    }
+
+    #[test]
+    fn it_joins_conventions() {
+        let words = ["ab", "Test", "ID"];
+
+        for (to, expected) in [
+            (Convention::Camel, "abTestId"),
+            (Convention::Pascal, "AbTestId"),
+            (Convention::Snake, "ab_test_id"),
+            (Convention::ScreamingSnake, "AB_TEST_ID"),
+            (Convention::Kebab, "ab-test-id"),
+            (Convention::Train, "Ab-Test-Id"),
+            (Convention::Sentence, "Ab test id"),
+            (Convention::Title, "Ab Test Id"),
+            (Convention::Dot, "ab.test.id"),
+            (Convention::Path, "ab/test/id"),
+        ] {
+            assert_eq!(to.join(&words), expected, "Failed to join as {:?}", to);
+        }
+    }
+
+    #[test]
+    fn it_joins_acronyms() {
+        let acronyms = BTreeSet::from(["API".to_string(), "HTTP".to_string()]);
+
+        for (words, to, expected) in [
+            (vec!["api", "key"], Convention::Pascal, "APIKey"),
+            (vec!["api", "key"], Convention::Camel, "apiKey"),
+            (vec!["http", "api"], Convention::Camel, "httpAPI"),
+            (vec!["HTTP", "Server"], Convention::Snake, "http_server"),
+            (vec!["http", "server"], Convention::Title, "HTTP Server"),
+        ] {
+            assert_eq!(
+                to.join_with_acronyms(&words, &acronyms),
+                expected,
+                "Failed to join {:?} as {:?}",
+                words,
+                to
+            );
+        }
+    }
+
+    #[test]
+    fn it_splits_heuristically() {
+        assert_eq!(
+            split_words("Product-image_URL list", Boundaries::default()),
+            vec!["Product", "image", "URL", "list"]
+        );
+        assert!(split_words("__", Boundaries::default()).is_empty());
+    }
+
+    #[test]
+    fn it_configures_punctuation_boundaries() {
+        let keep = Boundaries {
+            apostrophes: Punctuation::Keep,
+            periods: Punctuation::Keep,
+            hyphens: Punctuation::Keep,
+            digits: None,
+        };
+        let strip = Boundaries {
+            apostrophes: Punctuation::Strip,
+            periods: Punctuation::Strip,
+            hyphens: Punctuation::Strip,
+            digits: None,
+        };
+
+        assert_eq!(
+            split_words("don't stop v1.2.", Boundaries::default()),
+            vec!["don", "t", "stop", "v1", "2"]
+        );
+        assert_eq!(
+            split_words("don't stop v1.2.", keep),
+            vec!["don't", "stop", "v1.2"]
+        );
+        assert_eq!(strip.strip("don’t stop v1.2"), "dont stop v12");
+        assert_eq!(
+            split_words("state-of-the-art e-commerce -", keep),
+            vec!["state-of-the-art", "e-commerce"]
+        );
+    }
+
+    #[test]
+    fn it_capitalizes_compounds() {
+        for (input, expected) in [
+            ("state-of-the-art", "State-of-the-Art"),
+            ("e-commerce", "E-Commerce"),
+            ("UP-TO-DATE", "Up-to-Date"),
+            ("the-end", "The-End"),
+            ("product", "Product"),
+        ] {
+            assert_eq!(
+                capitalize_compound(input),
+                expected,
+                "Failed to test {}",
+                input
+            );
+        }
+    }
+}
@@ -0,0 +1,194 @@
+use std::str::FromStr;
+
+use crate::case::WordCase;
+
+/// Treatment of acronyms by a [`CaseSpec`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Acronyms {
+    /// Case acronyms like any other word: `URL` to `Url`
+    #[default]
+    Apply,
+    /// Keep words of two or more uppercase letters as they are: `URL` stays `URL`
+    Preserve,
+}
+
+/// Treatment of digits by a [`CaseSpec`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Digits {
+    /// Keep digits attached to the preceding letters: `v2`
+    #[default]
+    Attach,
+    /// Make every run of digits a word of its own: `v`, `2`
+    Separate,
+}
+
+impl FromStr for Digits {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "attach" => Ok(Digits::Attach),
+            "separate" => Ok(Digits::Separate),
+            _ => Err(()),
+        }
+    }
+}
+
+/// User-defined target case for the `to` hash option of the
+/// [`inflect`](struct@crate::HandlebarsInflector) helper
+///
+/// A spec describes how words are joined: the separator between them, the casing of the first
+/// and of every subsequent word, and how acronyms and digits are treated. Register it under a
+/// name with [`HandlebarsInflector::with_case`](crate::HandlebarsInflector::with_case) and
+/// select it like a built-in convention:
+///
+/// ```
+/// use handlebars::Handlebars;
+/// use handlebars_inflector::{Acronyms, CaseSpec, Digits, HandlebarsInflector, WordCase};
+///
+/// let spec = CaseSpec::new(".")
+///     .with_first(WordCase::Upper)
+///     .with_rest(WordCase::Capitalized)
+///     .with_acronyms(Acronyms::Preserve)
+///     .with_digits(Digits::Separate);
+///
+/// let mut h = Handlebars::new();
+/// h.register_helper("inflect", Box::new(HandlebarsInflector::new().with_case("acme", spec)));
+///
+/// assert_eq!(h.render_template(r#"{{inflect "product image URL v2" to="acme"}}"#, &()).expect("Render error"), "PRODUCT.Image.URL.V.2");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CaseSpec {
+    separator: String,
+    first: WordCase,
+    rest: WordCase,
+    acronyms: Acronyms,
+    digits: Digits,
+}
+
+impl CaseSpec {
+    /// Create a spec joining lowercase words with `separator`
+    pub fn new<S: Into<String>>(separator: S) -> Self {
+        CaseSpec {
+            separator: separator.into(),
+            first: WordCase::Lower,
+            rest: WordCase::Lower,
+            acronyms: Acronyms::default(),
+            digits: Digits::default(),
+        }
+    }
+
+    /// Casing of the first word
+    pub fn with_first(mut self, case: WordCase) -> Self {
+        self.first = case;
+        self
+    }
+
+    /// Casing of every word after the first one
+    pub fn with_rest(mut self, case: WordCase) -> Self {
+        self.rest = case;
+        self
+    }
+
+    /// Treatment of acronyms, [`Acronyms::Apply`] by default
+    pub fn with_acronyms(mut self, acronyms: Acronyms) -> Self {
+        self.acronyms = acronyms;
+        self
+    }
+
+    /// Treatment of digits, [`Digits::Attach`] by default
+    pub fn with_digits(mut self, digits: Digits) -> Self {
+        self.digits = digits;
+        self
+    }
+
+    /// Join `words` according to this spec.
+    pub(crate) fn join(&self, words: &[&str]) -> String {
+        let words: Vec<&str> = match self.digits {
+            Digits::Attach => words.to_vec(),
+            Digits::Separate => words.iter().flat_map(|word| split_digits(word)).collect(),
+        };
+
+        words
+            .iter()
+            .enumerate()
+            .map(|(i, word)| {
+                if self.acronyms == Acronyms::Preserve && is_acronym(word) {
+                    word.to_string()
+                } else if i == 0 {
+                    self.first.apply(word)
+                } else {
+                    self.rest.apply(word)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(&self.separator)
+    }
+}
+
+/// Whether `word` consists of two or more uppercase letters and optional digits.
+fn is_acronym(word: &str) -> bool {
+    word.chars().filter(|c| c.is_alphabetic()).count() >= 2
+        && word.chars().all(|c| c.is_uppercase() || c.is_ascii_digit())
+}
+
+/// Split `word` at every transition between digits and other characters.
+pub(crate) fn split_digits(word: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut previous = None;
+
+    for (index, c) in word.char_indices() {
+        let digit = c.is_ascii_digit();
+
+        if previous.is_some_and(|p| p != digit) {
+            parts.push(&word[start..index]);
+            start = index;
+        }
+
+        previous = Some(digit);
+    }
+
+    if start < word.len() {
+        parts.push(&word[start..]);
+    }
+
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_joins_words() {
+        let words = ["user", "ID", "v2"];
+
+        for (spec, expected) in [
+            (CaseSpec::new("_"), "user_id_v2"),
+            (
+                CaseSpec::new("").with_rest(WordCase::Capitalized),
+                "userIdV2",
+            ),
+            (
+                CaseSpec::new("").with_acronyms(Acronyms::Preserve),
+                "userIDv2",
+            ),
+            (
+                CaseSpec::new("-")
+                    .with_first(WordCase::Upper)
+                    .with_digits(Digits::Separate),
+                "USER-id-v-2",
+            ),
+        ] {
+            assert_eq!(spec.join(&words), expected, "Failed to test {:?}", spec);
+        }
+    }
+
+    #[test]
+    fn it_splits_digits() {
+        assert_eq!(split_digits("v2beta10"), vec!["v", "2", "beta", "10"]);
+        assert_eq!(split_digits("42"), vec!["42"]);
+        assert!(split_digits("").is_empty());
+    }
+}
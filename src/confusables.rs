@@ -0,0 +1,183 @@
+/// Look-alike characters and their ASCII skeletons.
+///
+/// A curated subset of the Unicode confusables data covering the scripts and symbols that most
+/// commonly end up in user-supplied names.
+const CONFUSABLES: &[(char, &str)] = &[
+    // Cyrillic
+    ('А', "A"),
+    ('В', "B"),
+    ('Е', "E"),
+    ('К', "K"),
+    ('М', "M"),
+    ('Н', "H"),
+    ('О', "O"),
+    ('Р', "P"),
+    ('С', "C"),
+    ('Т', "T"),
+    ('У', "Y"),
+    ('Х', "X"),
+    ('Ѕ', "S"),
+    ('І', "I"),
+    ('Ј', "J"),
+    ('Ү', "Y"),
+    ('Ԛ', "Q"),
+    ('Ԝ', "W"),
+    ('а', "a"),
+    ('е', "e"),
+    ('о', "o"),
+    ('р', "p"),
+    ('с', "c"),
+    ('у', "y"),
+    ('х', "x"),
+    ('ѕ', "s"),
+    ('і', "i"),
+    ('ј', "j"),
+    ('һ', "h"),
+    ('ү', "y"),
+    ('ӏ', "l"),
+    ('ԛ', "q"),
+    ('ԝ', "w"),
+    // Greek
+    ('Α', "A"),
+    ('Β', "B"),
+    ('Ε', "E"),
+    ('Ζ', "Z"),
+    ('Η', "H"),
+    ('Ι', "I"),
+    ('Κ', "K"),
+    ('Μ', "M"),
+    ('Ν', "N"),
+    ('Ο', "O"),
+    ('Ρ', "P"),
+    ('Τ', "T"),
+    ('Υ', "Y"),
+    ('Χ', "X"),
+    ('α', "a"),
+    ('ι', "i"),
+    ('ν', "v"),
+    ('ο', "o"),
+    ('ρ', "p"),
+    ('υ', "u"),
+    // Latin look-alikes
+    ('ı', "i"),
+    ('ȷ', "j"),
+    ('ɑ', "a"),
+    ('ɡ', "g"),
+    ('ℂ', "C"),
+    ('ℊ', "g"),
+    ('ℋ', "H"),
+    ('ℌ', "H"),
+    ('ℍ', "H"),
+    ('ℎ', "h"),
+    ('ℐ', "I"),
+    ('ℑ', "I"),
+    ('ℒ', "L"),
+    ('ℓ', "l"),
+    ('ℕ', "N"),
+    ('ℙ', "P"),
+    ('ℚ', "Q"),
+    ('ℛ', "R"),
+    ('ℜ', "R"),
+    ('ℝ', "R"),
+    ('ℤ', "Z"),
+    ('ℨ', "Z"),
+    ('ℬ', "B"),
+    ('ℭ', "C"),
+    ('ℯ', "e"),
+    ('ℰ', "E"),
+    ('ℱ', "F"),
+    ('ℳ', "M"),
+    ('ℴ', "o"),
+    // Punctuation and spacing
+    ('\u{00A0}', " "),
+    ('\u{2002}', " "),
+    ('\u{2003}', " "),
+    ('\u{2009}', " "),
+    ('\u{3000}', " "),
+    ('\u{2010}', "-"),
+    ('\u{2011}', "-"),
+    ('\u{2012}', "-"),
+    ('\u{2013}', "-"),
+    ('\u{2014}', "-"),
+    ('\u{2212}', "-"),
+    ('\u{2018}', "'"),
+    ('\u{2019}', "'"),
+    ('\u{201C}', "\""),
+    ('\u{201D}', "\""),
+    ('\u{2024}', "."),
+    ('\u{2026}', "..."),
+    ('\u{2044}', "/"),
+    ('\u{2215}', "/"),
+    // Invisible characters
+    ('\u{00AD}', ""),
+    ('\u{200B}', ""),
+    ('\u{200C}', ""),
+    ('\u{200D}', ""),
+    ('\u{2060}', ""),
+    ('\u{FEFF}', ""),
+];
+
+/// Replace confusable characters in `input` with their ASCII skeletons.
+///
+/// Besides the [`CONFUSABLES`] table this folds fullwidth ASCII forms (`Ａ`, `１`) and the
+/// mathematical alphanumeric symbols (`𝐀`, `𝟙`). Characters without a skeleton are kept.
+pub(crate) fn to_ascii_skeleton(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+
+    for c in input.chars() {
+        let code = c as u32;
+
+        if (0xFF01..=0xFF5E).contains(&code) {
+            output.extend(char::from_u32(code - 0xFEE0));
+        } else if (0x1D400..0x1D400 + 52 * 13).contains(&code) {
+            let index = ((code - 0x1D400) % 52) as u8;
+            output.push(if index < 26 {
+                (b'A' + index) as char
+            } else {
+                (b'a' + index - 26) as char
+            });
+        } else if (0x1D7CE..=0x1D7FF).contains(&code) {
+            output.push((b'0' + ((code - 0x1D7CE) % 10) as u8) as char);
+        } else if let Some((_, skeleton)) = CONFUSABLES.iter().find(|(k, _)| *k == c) {
+            output.push_str(skeleton);
+        } else {
+            output.push(c);
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_works() {
+        assert_eq!(
+            to_ascii_skeleton("Ρаураl"),
+            "Paypal",
+            "Failed to test cyrillic and greek confusables"
+        );
+        assert_eq!(
+            to_ascii_skeleton("Ｐｒｏｄｕｃｔ１"),
+            "Product1",
+            "Failed to test fullwidth forms"
+        );
+        assert_eq!(
+            to_ascii_skeleton("𝐔𝐬𝐞𝐫𝟐"),
+            "User2",
+            "Failed to test mathematical alphanumerics"
+        );
+        assert_eq!(
+            to_ascii_skeleton("user\u{200B}name – ok"),
+            "username - ok",
+            "Failed to test punctuation and invisible characters"
+        );
+        assert_eq!(
+            to_ascii_skeleton("Crème brûlée"),
+            "Crème brûlée",
+            "Failed to test characters without skeleton"
+        );
+    }
+}
@@ -0,0 +1,20 @@
+use handlebars::JsonValue;
+
+/// Extract a count from a helper parameter.
+///
+/// Numbers are used as they are, numeric strings are parsed and arrays and objects contribute
+/// their length. Anything else yields `None`.
+pub(crate) fn count_of(value: &JsonValue) -> Option<f64> {
+    match value {
+        JsonValue::Number(n) => n.as_f64(),
+        JsonValue::String(s) => s.trim().parse::<f64>().ok(),
+        JsonValue::Array(a) => Some(a.len() as f64),
+        JsonValue::Object(o) => Some(o.len() as f64),
+        _ => None,
+    }
+}
+
+/// Whether `count` calls for the singular form in English.
+pub(crate) fn is_singular(count: f64) -> bool {
+    count == 1.0
+}
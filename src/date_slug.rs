@@ -0,0 +1,272 @@
+use handlebars::{
+    Context, Handlebars, Helper, HelperDef, HelperResult, JsonValue, Output, RenderContext,
+    RenderErrorReason,
+};
+
+use crate::case::{split_words, Boundaries};
+
+/// A calendar date
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Date {
+    year: i64,
+    month: u32,
+    day: u32,
+}
+
+impl Date {
+    /// Parse the leading `YYYY-MM-DD` of an ISO 8601 date or date-time.
+    fn parse(input: &str) -> Option<Date> {
+        let input = input.trim();
+        let year = input.get(0..4)?.parse().ok()?;
+        let month = input.get(5..7)?.parse().ok()?;
+        let day = input.get(8..10)?.parse().ok()?;
+
+        let separators = input.get(4..5) == Some("-") && input.get(7..8) == Some("-");
+        let end = input[10..]
+            .chars()
+            .next()
+            .is_none_or(|c| c == 'T' || c == 't' || c == ' ');
+
+        let date = Date { year, month, day };
+
+        (separators && end && date.is_valid()).then_some(date)
+    }
+
+    /// Convert a unix timestamp in seconds to a UTC date.
+    fn from_timestamp(timestamp: i64) -> Date {
+        // Howard Hinnant's civil_from_days
+        let z = timestamp.div_euclid(86_400) + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z.rem_euclid(146_097);
+        let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+        let year = yoe + era * 400 + i64::from(month <= 2);
+
+        Date { year, month, day }
+    }
+
+    fn is_valid(&self) -> bool {
+        let leap = (self.year % 4 == 0 && self.year % 100 != 0) || self.year % 400 == 0;
+        let days = match self.month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if leap => 29,
+            2 => 28,
+            _ => return false,
+        };
+
+        (1..=days).contains(&self.day)
+    }
+
+    /// Format the date with a strftime-like pattern supporting `%Y`, `%y`, `%m`, `%d` and `%%`.
+    fn format(&self, pattern: &str) -> String {
+        let mut output = String::new();
+        let mut chars = pattern.chars();
+
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                output.push(c);
+                continue;
+            }
+
+            match chars.next() {
+                Some('Y') => output.push_str(&format!("{:04}", self.year)),
+                Some('y') => output.push_str(&format!("{:02}", self.year.rem_euclid(100))),
+                Some('m') => output.push_str(&format!("{:02}", self.month)),
+                Some('d') => output.push_str(&format!("{:02}", self.day)),
+                Some('%') => output.push('%'),
+                Some(other) => {
+                    output.push('%');
+                    output.push(other);
+                }
+                None => output.push('%'),
+            }
+        }
+
+        output
+    }
+}
+
+#[derive(Clone, Copy)]
+/// Date-prefixed slug helper for handlebars-rust
+///
+/// Composes Jekyll and Hugo style post slugs from a date and a title.
+///
+/// # Registration
+///
+/// ```
+/// use handlebars::Handlebars;
+/// use handlebars_inflector::HandlebarsDateSlug;
+///
+/// let mut h = Handlebars::new();
+/// h.register_helper("date_slug", Box::new(HandlebarsDateSlug));
+///
+/// assert_eq!(h.render_template(r#"{{date_slug "2024-06-01T10:00:00Z" "My First Post!"}}"#, &()).expect("Render error"), "2024-06-01-my-first-post");
+/// ```
+///
+/// # Arguments
+///
+/// * `date` - An ISO 8601 date or date-time string, or a unix timestamp in seconds
+/// * `title` - The title to slugify
+///
+/// # Hash options
+///
+/// `format`: date format using `%Y`, `%y`, `%m`, `%d` and `%%`, defaults to `%Y-%m-%d`
+///
+/// `separator`: separator between the date and the title words, defaults to `-`
+///
+/// # Example usage:
+///
+/// `2024/06/01_my_first_post`:
+///
+/// `
+/// {{date_slug date title format="%Y/%m/%d" separator="_"}}
+/// `
+///
+pub struct HandlebarsDateSlug;
+
+impl HelperDef for HandlebarsDateSlug {
+    fn call<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        r: &'reg Handlebars,
+        _ctx: &'rc Context,
+        _rc: &mut RenderContext<'reg, 'rc>,
+        out: &mut dyn Output,
+    ) -> HelperResult {
+        let date = match h.param(0).map(|v| v.value()) {
+            Some(JsonValue::String(s)) => Date::parse(s),
+            Some(JsonValue::Number(n)) => n.as_i64().map(Date::from_timestamp),
+            Some(_) => None,
+            None => {
+                if r.strict_mode() {
+                    return Err(RenderErrorReason::ParamNotFoundForIndex("date_slug", 0).into());
+                }
+
+                return Ok(());
+            }
+        };
+
+        let Some(date) = date else {
+            if r.strict_mode() {
+                return Err(RenderErrorReason::ParamTypeMismatchForName(
+                    "date_slug",
+                    "0".to_string(),
+                    "ISO 8601 date or unix timestamp".to_string(),
+                )
+                .into());
+            }
+
+            return Ok(());
+        };
+
+        let Some(title) = h.param(1).and_then(|v| v.value().as_str()) else {
+            if r.strict_mode() {
+                return Err(RenderErrorReason::ParamNotFoundForIndex("date_slug", 1).into());
+            }
+
+            return Ok(());
+        };
+
+        let format = h
+            .hash_get("format")
+            .and_then(|v| v.value().as_str())
+            .unwrap_or("%Y-%m-%d");
+        let separator = h
+            .hash_get("separator")
+            .and_then(|v| v.value().as_str())
+            .unwrap_or("-");
+
+        let mut slug = date.format(format);
+
+        for word in split_words(title, Boundaries::default()) {
+            slug.push_str(separator);
+            slug.push_str(&word.to_lowercase());
+        }
+
+        out.write(&slug)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use handlebars::Handlebars;
+
+    #[test]
+    fn it_parses_dates() {
+        assert_eq!(
+            Date::parse("2024-02-29"),
+            Some(Date {
+                year: 2024,
+                month: 2,
+                day: 29
+            })
+        );
+        assert_eq!(Date::parse("2023-02-29"), None);
+        assert_eq!(Date::parse("2024-6-1"), None);
+        assert_eq!(Date::parse("2024-06-01junk"), None);
+        assert_eq!(
+            Date::from_timestamp(1_717_243_200),
+            Date {
+                year: 2024,
+                month: 6,
+                day: 1
+            }
+        );
+        assert_eq!(
+            Date::from_timestamp(-86_400),
+            Date {
+                year: 1969,
+                month: 12,
+                day: 31
+            }
+        );
+    }
+
+    #[test]
+    fn it_works() {
+        let mut h = Handlebars::new();
+        h.register_helper("date_slug", Box::new(HandlebarsDateSlug));
+
+        for (template, expected) in [
+            (
+                r#"{{date_slug "2024-06-01" "My First Post"}}"#,
+                "2024-06-01-my-first-post",
+            ),
+            (
+                r#"{{date_slug 1717243200 "Hello, World!"}}"#,
+                "2024-06-01-hello-world",
+            ),
+            (
+                r#"{{date_slug "2024-06-01 08:00" "My First Post" format="%Y/%m/%d" separator="_"}}"#,
+                "2024/06/01_my_first_post",
+            ),
+            (
+                r#"{{date_slug "2024-06-01" "ReleaseNotes v2" format="%y%m%d"}}"#,
+                "240601-release-notes-v2",
+            ),
+            (r#"{{date_slug "June 1st" "My First Post"}}"#, ""),
+        ] {
+            assert_eq!(
+                h.render_template(template, &()).expect("Render error"),
+                expected,
+                "Failed to test {}",
+                template
+            );
+        }
+
+        h.set_strict_mode(true);
+
+        assert!(
+            h.render_template(r#"{{date_slug "June 1st" "My First Post"}}"#, &())
+                .is_err(),
+            "Failed to test invalid date in strict mode"
+        );
+    }
+}
@@ -0,0 +1,264 @@
+use handlebars::{
+    Context, Handlebars, Helper, HelperDef, HelperResult, JsonValue, Output, RenderContext,
+    RenderErrorReason,
+};
+
+/// PostgreSQL's identifier length limit in bytes (`NAMEDATALEN - 1`)
+const MAX_LENGTH: usize = 63;
+
+/// Collect the column names given as string or array parameters from index `from` on.
+fn columns_of(h: &Helper, from: usize) -> Vec<String> {
+    h.params()
+        .iter()
+        .skip(from)
+        .flat_map(|param| match param.value() {
+            JsonValue::String(s) => vec![s.clone()],
+            JsonValue::Array(a) => a
+                .iter()
+                .filter_map(|v| v.as_str().map(str::to_string))
+                .collect(),
+            _ => Vec::new(),
+        })
+        .collect()
+}
+
+/// Read the `max_length` hash option, defaulting to [`MAX_LENGTH`].
+fn max_length_of(h: &Helper) -> usize {
+    h.hash_get("max_length")
+        .and_then(|v| v.value().as_u64())
+        .map_or(MAX_LENGTH, |max| max as usize)
+}
+
+/// Shorten `name` to at most `max` bytes.
+///
+/// Names that fit are returned as they are. Longer names are cut and end in `_` followed by
+/// eight hex digits of a hash of the full name, so names sharing a long prefix stay distinct.
+pub(crate) fn truncate_identifier(name: &str, max: usize) -> String {
+    if name.len() <= max {
+        return name.to_string();
+    }
+
+    let hash = format!("{:08x}", fnv1a(name));
+    let mut end = max.saturating_sub(hash.len() + 1);
+
+    while !name.is_char_boundary(end) {
+        end -= 1;
+    }
+
+    let head = name[..end].trim_end_matches('_');
+
+    if head.is_empty() {
+        hash[..max.min(hash.len())].to_string()
+    } else {
+        format!("{}_{}", head, hash)
+    }
+}
+
+/// 32 bit FNV-1a hash, stable across platforms and releases.
+fn fnv1a(input: &str) -> u32 {
+    input.bytes().fold(0x811c_9dc5, |hash, byte| {
+        (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193)
+    })
+}
+
+#[derive(Clone, Copy)]
+/// Index name helper for handlebars-rust
+///
+/// Generates Rails-style index names, truncated to PostgreSQL's 63 byte identifier limit.
+///
+/// # Registration
+///
+/// ```
+/// use handlebars::Handlebars;
+/// use handlebars_inflector::HandlebarsIndexName;
+///
+/// let mut h = Handlebars::new();
+/// h.register_helper("index_name", Box::new(HandlebarsIndexName));
+///
+/// assert_eq!(h.render_template(r#"{{index_name "products" "user_id" "created_at"}}"#, &()).expect("Render error"), "index_products_on_user_id_and_created_at");
+/// ```
+///
+/// # Arguments
+///
+/// * `table` - The table name
+/// * `columns...` - The indexed columns, as strings or arrays of strings
+///
+/// # Hash options
+///
+/// `max_length`: identifier limit in bytes, defaults to 63. Longer names are cut and end in an
+/// eight digit hash of the full name.
+///
+/// # Example usage:
+///
+/// `
+/// CREATE INDEX {{index_name table columns}} ON {{table}} (...);
+/// `
+///
+pub struct HandlebarsIndexName;
+
+impl HelperDef for HandlebarsIndexName {
+    fn call<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        r: &'reg Handlebars,
+        _ctx: &'rc Context,
+        _rc: &mut RenderContext<'reg, 'rc>,
+        out: &mut dyn Output,
+    ) -> HelperResult {
+        let Some(table) = h.param(0).and_then(|v| v.value().as_str()) else {
+            if r.strict_mode() {
+                return Err(RenderErrorReason::ParamNotFoundForIndex("index_name", 0).into());
+            }
+
+            return Ok(());
+        };
+
+        let columns = columns_of(h, 1);
+
+        if columns.is_empty() {
+            if r.strict_mode() {
+                return Err(RenderErrorReason::ParamNotFoundForIndex("index_name", 1).into());
+            }
+
+            return Ok(());
+        }
+
+        let name = format!("index_{}_on_{}", table, columns.join("_and_"));
+
+        out.write(&truncate_identifier(&name, max_length_of(h)))?;
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy)]
+/// Constraint name helper for handlebars-rust
+///
+/// Generates constraint names from a kind prefix, the table and the constrained columns,
+/// truncated to PostgreSQL's 63 byte identifier limit.
+///
+/// # Registration
+///
+/// ```
+/// use handlebars::Handlebars;
+/// use handlebars_inflector::HandlebarsConstraintName;
+///
+/// let mut h = Handlebars::new();
+/// h.register_helper("constraint_name", Box::new(HandlebarsConstraintName));
+///
+/// assert_eq!(h.render_template(r#"{{constraint_name "fk" "orders" "customer_id"}}"#, &()).expect("Render error"), "fk_orders_customer_id");
+/// ```
+///
+/// # Arguments
+///
+/// * `kind` - The name prefix, e.g. `fk`, `pk`, `uq` or `ck`
+/// * `table` - The table name
+/// * `columns...` - The constrained columns, as strings or arrays of strings. Optional, as
+///   primary keys are commonly named after the table alone.
+///
+/// # Hash options
+///
+/// `max_length`: identifier limit in bytes, defaults to 63. Longer names are cut and end in an
+/// eight digit hash of the full name.
+///
+/// # Example usage:
+///
+/// `
+/// CONSTRAINT {{constraint_name "uq" table "tenant_id" "email"}} UNIQUE (tenant_id, email)
+/// `
+///
+pub struct HandlebarsConstraintName;
+
+impl HelperDef for HandlebarsConstraintName {
+    fn call<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        r: &'reg Handlebars,
+        _ctx: &'rc Context,
+        _rc: &mut RenderContext<'reg, 'rc>,
+        out: &mut dyn Output,
+    ) -> HelperResult {
+        let mut parts = Vec::new();
+
+        for index in 0..2 {
+            match h.param(index).and_then(|v| v.value().as_str()) {
+                Some(part) => parts.push(part.to_string()),
+                None => {
+                    if r.strict_mode() {
+                        return Err(RenderErrorReason::ParamNotFoundForIndex(
+                            "constraint_name",
+                            index,
+                        )
+                        .into());
+                    }
+
+                    return Ok(());
+                }
+            }
+        }
+
+        parts.extend(columns_of(h, 2));
+
+        out.write(&truncate_identifier(&parts.join("_"), max_length_of(h)))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use handlebars::Handlebars;
+
+    #[test]
+    fn it_truncates_identifiers() {
+        let long = format!("index_{}_on_user_id", "a".repeat(60));
+        let other = format!("index_{}_on_team_id", "a".repeat(60));
+
+        let truncated = truncate_identifier(&long, MAX_LENGTH);
+
+        assert_eq!(truncated.len(), MAX_LENGTH);
+        assert!(truncated.starts_with("index_aaa"));
+        assert_ne!(truncated, truncate_identifier(&other, MAX_LENGTH));
+        assert_eq!(truncated, truncate_identifier(&long, MAX_LENGTH));
+
+        assert_eq!(truncate_identifier("short_name", MAX_LENGTH), "short_name");
+        assert!(truncate_identifier(&"ü".repeat(40), MAX_LENGTH).len() <= MAX_LENGTH);
+    }
+
+    #[test]
+    fn it_works() {
+        let mut h = Handlebars::new();
+        h.register_helper("index_name", Box::new(HandlebarsIndexName));
+        h.register_helper("constraint_name", Box::new(HandlebarsConstraintName));
+
+        let data = serde_json::json!({"columns": ["tenant_id", "email"]});
+
+        for (template, expected) in [
+            (
+                r#"{{index_name "products" "user_id"}}"#,
+                "index_products_on_user_id",
+            ),
+            (
+                r#"{{index_name "users" columns}}"#,
+                "index_users_on_tenant_id_and_email",
+            ),
+            (
+                r#"{{constraint_name "uq" "users" columns}}"#,
+                "uq_users_tenant_id_email",
+            ),
+            (r#"{{constraint_name "pk" "users"}}"#, "pk_users"),
+            (
+                r#"{{index_name "products" "user_id" max_length=20}}"#,
+                "index_produ_ec244cda",
+            ),
+        ] {
+            assert_eq!(
+                h.render_template(template, &data).expect("Render error"),
+                expected,
+                "Failed to test {}",
+                template
+            );
+        }
+    }
+}
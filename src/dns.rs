@@ -0,0 +1,98 @@
+use crate::transliterate::fold_diacritics;
+
+/// Maximum length of a DNS label
+const MAX_LABEL_LENGTH: usize = 63;
+
+/// Maximum length of a DNS name
+const MAX_NAME_LENGTH: usize = 253;
+
+/// RFC 1123 label of `input`, as required for Kubernetes and most cloud resource names.
+///
+/// Diacritics are folded and letters lowercased, apostrophes dropped and runs of any other
+/// character but ASCII letters, digits and hyphens replaced with a single `-`. The label is cut
+/// to `max_length` characters and never starts or ends with a hyphen.
+pub(crate) fn to_dns_label(input: &str, max_length: usize) -> String {
+    let mut output = String::with_capacity(input.len());
+
+    for c in fold_diacritics(input).chars() {
+        if c.is_ascii_alphanumeric() || c == '-' {
+            output.push(c.to_ascii_lowercase());
+        } else if !matches!(c, '\'' | '’') && !output.ends_with('-') {
+            output.push('-');
+        }
+    }
+
+    let output = output.trim_start_matches('-');
+    let output = &output[..output.len().min(max_length)];
+
+    output.trim_end_matches('-').to_string()
+}
+
+/// RFC 1123 subdomain of `input`: dot separated labels, each made valid with [`to_dns_label`],
+/// cut to `max_length` characters.
+pub(crate) fn to_dns_name(input: &str, max_length: usize) -> String {
+    let labels: Vec<String> = input
+        .split('.')
+        .map(|label| to_dns_label(label, MAX_LABEL_LENGTH))
+        .filter(|label| !label.is_empty())
+        .collect();
+
+    let output = labels.join(".");
+    let output = &output[..output.len().min(max_length)];
+
+    output.trim_end_matches(['-', '.']).to_string()
+}
+
+/// Default maximum length of a label, or of a name with `subdomain`.
+pub(crate) fn max_dns_length(subdomain: bool) -> usize {
+    if subdomain {
+        MAX_NAME_LENGTH
+    } else {
+        MAX_LABEL_LENGTH
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_makes_dns_labels() {
+        let long = "a".repeat(62) + "-b";
+
+        for (input, expected) in [
+            ("Product Images", "product-images"),
+            ("user_profile-service", "user-profile-service"),
+            ("--Crème Brûlée's API--", "creme-brulees-api"),
+            ("xn--bcher-kva", "xn--bcher-kva"),
+            ("v1.2.3", "v1-2-3"),
+            ("2fa", "2fa"),
+            (long.as_str(), &"a".repeat(62)),
+            ("!!!", ""),
+        ] {
+            assert_eq!(
+                to_dns_label(input, MAX_LABEL_LENGTH),
+                expected,
+                "Failed to test {}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn it_makes_dns_names() {
+        for (input, max_length, expected) in [
+            ("API.Example_Corp.com", 253, "api.example-corp.com"),
+            ("a..b.", 253, "a.b"),
+            ("web.example.com", 5, "web.e"),
+            ("web.example.com", 4, "web"),
+        ] {
+            assert_eq!(
+                to_dns_name(input, max_length),
+                expected,
+                "Failed to test {}",
+                input
+            );
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use handlebars::{
+    BlockContext, Context, Handlebars, Helper, HelperDef, HelperResult, JsonValue, Output,
+    RenderContext, Renderable, StringOutput,
+};
+
+use crate::block::render_inverse;
+use crate::HandlebarsInflector;
+
+#[derive(Clone, Default)]
+/// Inflecting iteration helper for handlebars-rust
+///
+/// Iterates an array like `#each`, exposing the result of the `inflect` operations requested in
+/// the hash for the current item as `@inflected`, next to `@index`, `@first` and `@last`. Items
+/// other than strings have a null `@inflected`. The `{{else}}` block is rendered when the input
+/// is empty or not an array.
+///
+/// # Registration
+///
+/// ```
+/// use handlebars::Handlebars;
+/// use handlebars_inflector::HandlebarsInflectEach;
+///
+/// let mut h = Handlebars::new();
+/// h.register_helper("inflect_each", Box::new(HandlebarsInflectEach));
+///
+/// assert_eq!(h.render_template(r#"{{#inflect_each this to_snake_case=true}}{{this}}={{@inflected}};{{/inflect_each}}"#, &vec!["firstName", "LastName"]).expect("Render error"), "firstName=first_name;LastName=last_name;");
+/// ```
+///
+/// # Arguments
+///
+/// * `items` - An array of strings
+///
+/// # Hash options
+///
+/// Any operation or option of the `inflect` helper.
+///
+/// # Example usage:
+///
+/// `
+/// {{#inflect_each fields to_camel_case=true}}{{@inflected}}: row.{{this}},{{/inflect_each}}
+/// `
+///
+pub struct HandlebarsInflectEach {
+    inflector: HandlebarsInflector,
+}
+
+/// The `inflect_each` helper without any configuration, equal to [`HandlebarsInflectEach::new`]
+#[allow(non_upper_case_globals)]
+pub const HandlebarsInflectEach: HandlebarsInflectEach = HandlebarsInflectEach::new();
+
+impl HandlebarsInflectEach {
+    /// Create a helper inflecting with the `inflect` helper without any configuration
+    pub const fn new() -> Self {
+        HandlebarsInflectEach {
+            inflector: HandlebarsInflector::new(),
+        }
+    }
+
+    /// Inflect with a configured `inflect` helper instead of the default one
+    pub fn with_inflector(mut self, inflector: HandlebarsInflector) -> Self {
+        self.inflector = inflector;
+        self
+    }
+}
+
+impl HelperDef for HandlebarsInflectEach {
+    fn call<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        r: &'reg Handlebars,
+        ctx: &'rc Context,
+        rc: &mut RenderContext<'reg, 'rc>,
+        out: &mut dyn Output,
+    ) -> HelperResult {
+        let items = match h.param(0).map(|items| items.value()) {
+            Some(JsonValue::Array(items)) if !items.is_empty() => items,
+            _ => return render_inverse(h, r, ctx, rc, out),
+        };
+
+        let Some(template) = h.template() else {
+            return Ok(());
+        };
+
+        for (index, item) in items.iter().enumerate() {
+            let inflected = match self.inflector.input_of(h, item) {
+                Some(input) => {
+                    let mut inflected = StringOutput::new();
+                    self.inflector
+                        .inflect_input(h, r, rc, input, &mut inflected)?;
+
+                    JsonValue::String(inflected.into_string().unwrap_or_default())
+                }
+                None => JsonValue::Null,
+            };
+
+            let mut block = BlockContext::new();
+            block.set_base_value(item.clone());
+            block.set_local_var("index", JsonValue::from(index));
+            block.set_local_var("first", JsonValue::Bool(index == 0));
+            block.set_local_var("last", JsonValue::Bool(index == items.len() - 1));
+            block.set_local_var("inflected", inflected);
+
+            rc.push_block(block);
+            let result = template.render(r, ctx, rc, out);
+            rc.pop_block();
+
+            result?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn it_iterates_with_inflected_items() {
+        let mut h = Handlebars::new();
+        h.register_helper("inflect_each", Box::new(HandlebarsInflectEach));
+        h.register_helper(
+            "inflect_acronyms",
+            Box::new(
+                HandlebarsInflectEach::new()
+                    .with_inflector(HandlebarsInflector::new().with_acronyms(["ID"])),
+            ),
+        );
+
+        let data = json!({ "fields": ["user_id", "first_name", 3], "empty": [] });
+
+        for (template, expected) in [
+            (
+                r#"{{#inflect_each fields to_camel_case=true}}{{@index}}:{{this}}={{@inflected}}{{#unless @last}},{{/unless}}{{/inflect_each}}"#,
+                "0:user_id=userId,1:first_name=firstName,2:3=",
+            ),
+            (
+                r#"{{#inflect_acronyms fields to_pascal_case=true}}{{#if @first}}{{@inflected}}{{/if}}{{/inflect_acronyms}}"#,
+                "UserID",
+            ),
+            (
+                r#"{{#inflect_each empty to_camel_case=true}}{{this}}{{else}}none{{/inflect_each}}"#,
+                "none",
+            ),
+            (
+                r#"{{#inflect_each "user_id" to_camel_case=true}}{{this}}{{else}}none{{/inflect_each}}"#,
+                "none",
+            ),
+        ] {
+            assert_eq!(
+                h.render_template(template, &data).expect("Render error"),
+                expected,
+                "Failed to test {}",
+                template
+            );
+        }
+    }
+}
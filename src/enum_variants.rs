@@ -0,0 +1,182 @@
+use std::collections::HashSet;
+
+use handlebars::{
+    Context, Handlebars, Helper, HelperDef, JsonValue, RenderContext, RenderError,
+    RenderErrorReason, ScopedJson,
+};
+use serde_json::{json, Map};
+
+use crate::case::{split_words, Boundaries, Convention};
+use crate::hash_option_for;
+use crate::identifier::{is_identifier, to_identifier, Profile};
+
+#[derive(Clone, Copy)]
+/// Enum variant helper for handlebars-rust
+///
+/// Converts an array of arbitrary JSON enum values into PascalCase variant names that are valid
+/// identifiers of the target language. Values whose names collide get a numeric suffix in order
+/// of appearance, so the output is deterministic.
+///
+/// # Registration
+///
+/// ```
+/// use handlebars::Handlebars;
+/// use handlebars_inflector::HandlebarsEnumVariants;
+///
+/// let mut h = Handlebars::new();
+/// h.register_helper("enum_variants", Box::new(HandlebarsEnumVariants));
+///
+/// assert_eq!(h.render_template(r#"{{#each (enum_variants this)}}{{variant}} {{/each}}"#, &vec!["in-progress", "2xx", "N/A", "n/a"]).expect("Render error"), "InProgress Value2xx NA NA2 ");
+/// ```
+///
+/// # Arguments
+///
+/// * `values` - An array of enum values. Strings are used as they are, other values in their
+///   JSON form.
+///
+/// # Hash options
+///
+/// `profile`: identifier rules of the target language, as for the `to_identifier` operation
+/// of [`HandlebarsInflector`](struct@crate::HandlebarsInflector)
+///
+/// `prefix`: prepended to names that don't start with a letter, defaults to `Value`
+///
+/// # Example usage:
+///
+/// Each entry is an object with the `variant` name and the original `value`:
+///
+/// `
+/// {{#each (enum_variants schema.enum profile="rust")}}
+///     #[serde(rename = "{{value}}")]
+///     {{variant}},
+/// {{/each}}
+/// `
+///
+pub struct HandlebarsEnumVariants;
+
+impl HelperDef for HandlebarsEnumVariants {
+    fn call_inner<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        r: &'reg Handlebars,
+        _ctx: &'rc Context,
+        _rc: &mut RenderContext<'reg, 'rc>,
+    ) -> Result<ScopedJson<'rc>, RenderError> {
+        let Some(values) = h.param(0).and_then(|v| v.value().as_array()) else {
+            if r.strict_mode() {
+                return Err(RenderErrorReason::ParamNotFoundForIndex("enum_variants", 0).into());
+            }
+
+            return Ok(ScopedJson::Derived(JsonValue::Array(Vec::new())));
+        };
+
+        let profile = hash_option_for::<Profile>(
+            "enum_variants",
+            h,
+            r.strict_mode(),
+            "profile",
+            "identifier profile",
+        )?
+        .unwrap_or_default();
+        let prefix = h
+            .hash_get("prefix")
+            .and_then(|v| v.value().as_str())
+            .unwrap_or("Value");
+
+        let mut taken = HashSet::new();
+        let mut variants = Vec::with_capacity(values.len());
+
+        for value in values {
+            let name = dedup(&mut taken, variant_name(value, profile, prefix));
+
+            let mut variant = Map::new();
+            variant.insert("variant".to_string(), json!(name));
+            variant.insert("value".to_string(), value.clone());
+            variants.push(JsonValue::Object(variant));
+        }
+
+        Ok(ScopedJson::Derived(JsonValue::Array(variants)))
+    }
+}
+
+/// PascalCase identifier for an enum value.
+fn variant_name(value: &JsonValue, profile: Profile, prefix: &str) -> String {
+    let text = match value {
+        JsonValue::String(s) => s.clone(),
+        other => other.to_string(),
+    };
+
+    let mut name = Convention::Pascal.join(&split_words(&text, Boundaries::default()));
+
+    let starts_valid = name
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_alphabetic() && is_identifier(&c.to_string(), profile));
+
+    if !starts_valid {
+        name.insert_str(0, prefix);
+    }
+
+    to_identifier(&name, profile)
+}
+
+/// Suffix `name` with the lowest free number from 2 on when it is already taken.
+fn dedup(taken: &mut HashSet<String>, name: String) -> String {
+    let name = if taken.contains(&name) {
+        (2..)
+            .map(|n| format!("{}{}", name, n))
+            .find(|candidate| !taken.contains(candidate))
+            .unwrap_or_default()
+    } else {
+        name
+    };
+
+    taken.insert(name.clone());
+
+    name
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use handlebars::Handlebars;
+
+    #[test]
+    fn it_works() {
+        let mut h = Handlebars::new();
+        h.register_helper("enum_variants", Box::new(HandlebarsEnumVariants));
+
+        let values = serde_json::json!([
+            "in-progress",
+            "IN_PROGRESS",
+            "InProgress2",
+            "2xx",
+            "N/A",
+            "",
+            200,
+            true,
+            null,
+            "état"
+        ]);
+
+        assert_eq!(
+            h.render_template(
+                r#"{{#each (enum_variants this)}}{{variant}}={{value}} {{/each}}"#,
+                &values
+            )
+            .expect("Render error"),
+            "InProgress=in-progress InProgress2=IN_PROGRESS InProgress22=InProgress2 Value2xx=2xx NA=N/A Value= Value200=200 True=true Null= État=état ",
+            "Failed to test enum_variants"
+        );
+
+        assert_eq!(
+            h.render_template(
+                r#"{{#each (enum_variants this prefix="Http")}}{{variant}} {{/each}}"#,
+                &["2xx", "4xx"]
+            )
+            .expect("Render error"),
+            "Http2xx Http4xx ",
+            "Failed to test enum_variants with prefix"
+        );
+    }
+}
@@ -0,0 +1,55 @@
+use crate::case::{split_words, Boundaries};
+use crate::transliterate::fold_diacritics;
+
+/// Environment variable name of `input`, prefixed with the words of `prefix`.
+///
+/// Words are written in uppercase and joined with `_`. Diacritics are folded, any other character
+/// but ASCII letters and digits separates words, and a name starting with a digit gets a leading
+/// `_`.
+pub(crate) fn to_env_var(input: &str, prefix: &str, boundaries: Boundaries) -> String {
+    let input = fold_diacritics(input);
+
+    let words: Vec<String> = split_words(prefix, Boundaries::default())
+        .into_iter()
+        .chain(split_words(&input, boundaries))
+        .flat_map(|word| word.split(|c: char| !c.is_ascii_alphanumeric()))
+        .filter(|word| !word.is_empty())
+        .map(str::to_uppercase)
+        .collect();
+
+    let mut output = words.join("_");
+
+    if output.starts_with(|c: char| c.is_ascii_digit()) {
+        output.insert(0, '_');
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_names_environment_variables() {
+        for (input, prefix, expected) in [
+            ("db.pool-size", "myapp", "MYAPP_DB_POOL_SIZE"),
+            ("db.pool-size", "MYAPP_", "MYAPP_DB_POOL_SIZE"),
+            ("maxRetryCount", "", "MAX_RETRY_COUNT"),
+            ("server port", "MyApp", "MY_APP_SERVER_PORT"),
+            ("café_größe", "", "CAFE_GROSSE"),
+            ("price_€", "", "PRICE"),
+            ("2fa enabled", "", "_2FA_ENABLED"),
+            ("2fa enabled", "app", "APP_2FA_ENABLED"),
+            ("", "", ""),
+        ] {
+            assert_eq!(
+                to_env_var(input, prefix, Boundaries::default()),
+                expected,
+                "Failed to test {} with {}",
+                input,
+                prefix
+            );
+        }
+    }
+}
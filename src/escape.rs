@@ -0,0 +1,71 @@
+use std::str::FromStr;
+
+/// Escape mode selected with the `escape` hash option
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Escape {
+    /// Backslash-escape characters with inline meaning in Markdown
+    Markdown,
+}
+
+impl FromStr for Escape {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "markdown" | "md" => Ok(Escape::Markdown),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Escape {
+    /// Escape `input` for embedding in the target format.
+    pub(crate) fn apply(self, input: &str) -> String {
+        match self {
+            Escape::Markdown => escape_markdown(input),
+        }
+    }
+}
+
+/// Backslash-escape the characters that can open emphasis, code spans, links, tables or
+/// strikethrough anywhere in a line. Characters that are only special at the start of a line
+/// (`#`, `-`, `+`, `.`) are left alone so kebab- and dot-separated names stay readable.
+fn escape_markdown(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+
+    for c in input.chars() {
+        if matches!(
+            c,
+            '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '|' | '~'
+        ) {
+            output.push('\\');
+        }
+
+        output.push(c);
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_escapes_markdown() {
+        for (input, expected) in [
+            ("product_image_url", r"product\_image\_url"),
+            ("*args|~kwargs", r"\*args\|\~kwargs"),
+            ("`Vec<T>`", r"\`Vec\<T\>\`"),
+            ("[link]\\", r"\[link\]\\"),
+            ("kebab-case.name", "kebab-case.name"),
+        ] {
+            assert_eq!(
+                Escape::Markdown.apply(input),
+                expected,
+                "Failed to escape {}",
+                input
+            );
+        }
+    }
+}
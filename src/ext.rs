@@ -0,0 +1,72 @@
+use handlebars::Handlebars;
+
+use crate::HandlebarsInflector;
+
+/// One-line registration of the `inflect` helper on a registry
+///
+/// ```
+/// use handlebars::Handlebars;
+/// use handlebars_inflector::HandlebarsInflectorExt;
+///
+/// let h = Handlebars::new().with_inflector();
+///
+/// assert_eq!(h.render_template(r#"{{inflect this to_snake_case=true}}"#, &String::from("ProductImage")).expect("Render error"), "product_image");
+/// ```
+pub trait HandlebarsInflectorExt: Sized {
+    /// Register the `inflect` helper without any configuration and return the registry
+    fn with_inflector(self) -> Self;
+
+    /// Register the `inflect` helper without any configuration under `name`
+    fn register_inflector(&mut self, name: &str) -> &mut Self;
+
+    /// Register a configured `inflect` helper under `name`
+    fn register_inflector_with(&mut self, name: &str, inflector: HandlebarsInflector) -> &mut Self;
+}
+
+impl HandlebarsInflectorExt for Handlebars<'_> {
+    fn with_inflector(mut self) -> Self {
+        self.register_inflector(HandlebarsInflector::DEFAULT_HELPER_NAME);
+        self
+    }
+
+    fn register_inflector(&mut self, name: &str) -> &mut Self {
+        self.register_inflector_with(name, HandlebarsInflector::new())
+    }
+
+    fn register_inflector_with(&mut self, name: &str, inflector: HandlebarsInflector) -> &mut Self {
+        self.register_helper(name, Box::new(inflector));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_registers_the_helper() {
+        let mut h = Handlebars::new();
+        h.register_inflector("inf").register_inflector_with(
+            "table",
+            HandlebarsInflector::new().with_default_ops("to_table_case"),
+        );
+
+        assert_eq!(
+            Handlebars::new()
+                .with_inflector()
+                .render_template(r#"{{inflect this to_snake_case=true}}"#, &"ProductImage")
+                .expect("Render error"),
+            "product_image"
+        );
+        assert_eq!(
+            h.render_template(r#"{{inf this to_plural=true}}"#, &"box")
+                .expect("Render error"),
+            "boxes"
+        );
+        assert_eq!(
+            h.render_template(r#"{{table this}}"#, &"ProductImage")
+                .expect("Render error"),
+            "product_images"
+        );
+    }
+}
@@ -0,0 +1,126 @@
+/// Characters Windows forbids in file names, including both path separators
+const RESERVED: &[char] = &['/', '\\', '<', '>', ':', '"', '|', '?', '*'];
+
+/// Device names Windows reserves with any extension
+const DEVICES: &[&str] = &[
+    "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8",
+    "COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+/// File name safe on every common file system made of `input`.
+///
+/// Control characters are dropped, path separators and other reserved characters become a
+/// single `_`, and leading or trailing spaces, dots and such `_` are trimmed. Windows device
+/// names get a leading `_` and a name left empty, like `..`, becomes `_`. With `max_length` the
+/// name is cut to that many characters, dropping a separator left dangling at the cut and keeping
+/// the extension intact when `keep_extension` is set and it fits.
+pub(crate) fn to_filename(input: &str, max_length: Option<usize>, keep_extension: bool) -> String {
+    // Each character with whether it replaced a reserved one, so only those `_` get trimmed
+    let mut chars: Vec<(char, bool)> = Vec::with_capacity(input.len());
+
+    for c in input.chars().filter(|c| !c.is_control()) {
+        if RESERVED.contains(&c) {
+            if chars.last().is_none_or(|(last, _)| *last != '_') {
+                chars.push(('_', true));
+            }
+        } else {
+            chars.push((c, false));
+        }
+    }
+
+    let mut output = trim_edges(&chars);
+
+    if output.is_empty() && !input.is_empty() {
+        output.push('_');
+    }
+
+    let stem = output.split('.').next().unwrap_or_default();
+    if DEVICES
+        .iter()
+        .any(|device| device.eq_ignore_ascii_case(stem))
+    {
+        output.insert(0, '_');
+    }
+
+    let Some(max_length) = max_length else {
+        return output;
+    };
+
+    if output.chars().count() <= max_length {
+        return output;
+    }
+
+    let extension = match output.rfind('.') {
+        Some(dot) if keep_extension && dot > 0 && output[dot..].chars().count() < max_length => {
+            output[dot..].to_string()
+        }
+        _ => String::new(),
+    };
+
+    let stem_length = max_length.saturating_sub(extension.chars().count());
+    let stem: String = output.chars().take(stem_length).collect();
+
+    format!("{}{}", stem.trim_end_matches([' ', '.', '_']), extension)
+}
+
+/// Collect `chars` without leading and trailing spaces, dots and replacement underscores.
+fn trim_edges(chars: &[(char, bool)]) -> String {
+    let is_edge = |&(c, replaced): &(char, bool)| replaced || c == ' ' || c == '.';
+    let start = chars
+        .iter()
+        .position(|c| !is_edge(c))
+        .unwrap_or(chars.len());
+    let end = chars
+        .iter()
+        .rposition(|c| !is_edge(c))
+        .map_or(start, |i| i + 1);
+
+    chars[start..end].iter().map(|(c, _)| c).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_sanitizes_filenames() {
+        for (input, max_length, keep_extension, expected) in [
+            ("Product images.rs", None, false, "Product images.rs"),
+            ("src/../etc/passwd", None, false, "src_.._etc_passwd"),
+            (
+                "What? <Why>: \"Because\"",
+                None,
+                false,
+                "What_ _Why_ _Because",
+            ),
+            ("tab\tseparated\n", None, false, "tabseparated"),
+            ("  .hidden. ", None, false, "hidden"),
+            ("con", None, false, "_con"),
+            ("Aux.txt", None, false, "_Aux.txt"),
+            ("console.log", None, false, "console.log"),
+            ("..", None, false, "_"),
+            ("/", None, false, "_"),
+            ("", None, false, ""),
+            ("__init__.py", None, false, "__init__.py"),
+            ("_private", None, false, "_private"),
+            ("/tmp/cache/", None, false, "tmp_cache"),
+            (
+                "very_long_file_name.tar.gz",
+                Some(12),
+                false,
+                "very_long_fi",
+            ),
+            ("very_long_file_name.tar.gz", Some(12), true, "very_long.gz"),
+            ("long_name.rs", Some(8), true, "long.rs"),
+            ("short.rs", Some(12), true, "short.rs"),
+            ("abc.verylongextension", Some(5), true, "abc.v"),
+        ] {
+            assert_eq!(
+                to_filename(input, max_length, keep_extension),
+                expected,
+                "Failed to test {}",
+                input
+            );
+        }
+    }
+}
@@ -0,0 +1,212 @@
+use intl_pluralrules::{PluralRuleType, PluralRules};
+use unic_langid::LanguageIdentifier;
+
+use handlebars::{
+    Context, Handlebars, Helper, HelperDef, HelperResult, Output, RenderContext, RenderErrorReason,
+};
+
+use crate::plural::{select_message, Operands, PluralCategory};
+
+impl From<intl_pluralrules::PluralCategory> for PluralCategory {
+    fn from(category: intl_pluralrules::PluralCategory) -> Self {
+        use intl_pluralrules::PluralCategory::*;
+
+        match category {
+            ZERO => PluralCategory::Zero,
+            ONE => PluralCategory::One,
+            TWO => PluralCategory::Two,
+            FEW => PluralCategory::Few,
+            MANY => PluralCategory::Many,
+            OTHER => PluralCategory::Other,
+        }
+    }
+}
+
+/// Look up the CLDR rules for `locale`, falling back to its language alone (`fr-CA` to `fr`).
+fn rules_for(locale: &str, kind: PluralRuleType) -> Option<PluralRules> {
+    let langid: LanguageIdentifier = locale.parse().ok()?;
+
+    PluralRules::create(langid.clone(), kind).ok().or_else(|| {
+        PluralRules::create(
+            LanguageIdentifier::from_parts(langid.language, None, None, &[]),
+            kind,
+        )
+        .ok()
+    })
+}
+
+#[derive(Clone, Copy)]
+/// CLDR plural and ordinal category helper for handlebars-rust
+///
+/// Category selection uses the CLDR data of `intl_pluralrules`, the same rules Project Fluent
+/// applies, so templates agree with Fluent-based localization. Available with the `fluent`
+/// feature.
+///
+/// # Registration
+///
+/// ```
+/// use handlebars::Handlebars;
+/// use handlebars_inflector::HandlebarsCldrPlural;
+///
+/// let mut h = Handlebars::new();
+/// h.register_helper("cldr_plural", Box::new(HandlebarsCldrPlural));
+///
+/// assert_eq!(h.render_template(r##"{{cldr_plural 22 type="ordinal" one="#st" two="#nd" few="#rd" other="#th"}}"##, &()).expect("Render error"), "22nd");
+/// assert_eq!(h.render_template(r#"{{cldr_plural 5 locale="pl"}}"#, &()).expect("Render error"), "many");
+/// ```
+///
+/// # Arguments
+///
+/// * `count` - A number, a numeric string, an array or an object whose length is used as count
+///
+/// # Hash options
+///
+/// `locale`: BCP 47 language tag whose rules apply, `en` by default
+///
+/// `type`: `cardinal` (default) or `ordinal`
+///
+/// `zero`, `one`, `two`, `few`, `many`, `other`: the message for each category, with `#`
+/// replaced by the count. As in [`HandlebarsPluralSelect`](crate::HandlebarsPluralSelect), a
+/// cardinal count of exactly zero selects `zero` when given. Without any message the category
+/// name itself is written, for use in subexpressions.
+///
+/// # Example usage:
+///
+/// `
+/// {{#if (eq (cldr_plural count locale=lang) "few")}}...{{/if}}
+/// `
+///
+pub struct HandlebarsCldrPlural;
+
+impl HelperDef for HandlebarsCldrPlural {
+    fn call<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        r: &'reg Handlebars,
+        _ctx: &'rc Context,
+        _rc: &mut RenderContext<'reg, 'rc>,
+        out: &mut dyn Output,
+    ) -> HelperResult {
+        let count = if let Some(count) = h.param(0) {
+            count
+        } else {
+            if r.strict_mode() {
+                return Err(RenderErrorReason::ParamNotFoundForIndex("cldr_plural", 0).into());
+            }
+
+            return Ok(());
+        };
+
+        let operands = if let Some(operands) = Operands::of(count.value()) {
+            operands
+        } else {
+            if r.strict_mode() {
+                return Err(RenderErrorReason::ParamTypeMismatchForName(
+                    "cldr_plural",
+                    "0".to_string(),
+                    "number or array".to_string(),
+                )
+                .into());
+            }
+
+            return Ok(());
+        };
+
+        let kind = match h.hash_get("type").and_then(|v| v.value().as_str()) {
+            None | Some("cardinal") => PluralRuleType::CARDINAL,
+            Some("ordinal") => PluralRuleType::ORDINAL,
+            Some(_) => {
+                if r.strict_mode() {
+                    return Err(RenderErrorReason::HashTypeMismatchForName(
+                        "cldr_plural",
+                        "type".to_string(),
+                        "cardinal or ordinal".to_string(),
+                    )
+                    .into());
+                }
+
+                return Ok(());
+            }
+        };
+
+        let locale = h
+            .hash_get("locale")
+            .and_then(|v| v.value().as_str())
+            .unwrap_or("en");
+
+        let category =
+            rules_for(locale, kind).and_then(|rules| rules.select(operands.display.as_str()).ok());
+
+        let category: PluralCategory = if let Some(category) = category {
+            category.into()
+        } else {
+            if r.strict_mode() {
+                return Err(RenderErrorReason::HashTypeMismatchForName(
+                    "cldr_plural",
+                    "locale".to_string(),
+                    "locale with CLDR plural rules".to_string(),
+                )
+                .into());
+            }
+
+            return Ok(());
+        };
+
+        let has_messages = ["zero", "one", "two", "few", "many", "other"]
+            .iter()
+            .any(|key| h.hash_get(key).is_some());
+
+        if !has_messages {
+            out.write(category.as_str())?;
+        } else if let Some(selected) =
+            select_message(h, &operands, category, kind == PluralRuleType::CARDINAL)
+        {
+            out.write(&selected)?;
+        } else if r.strict_mode() {
+            return Err(RenderErrorReason::ParamNotFoundForName(
+                "cldr_plural",
+                "other".to_string(),
+            )
+            .into());
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use handlebars::Handlebars;
+
+    #[test]
+    fn it_works() {
+        let mut h = Handlebars::new();
+        h.register_helper("cldr_plural", Box::new(HandlebarsCldrPlural));
+
+        for (template, expected) in [
+            (r#"{{cldr_plural 1}}"#, "one"),
+            (r#"{{cldr_plural "1.0"}}"#, "other"),
+            (r#"{{cldr_plural 22 locale="ru"}}"#, "few"),
+            (r#"{{cldr_plural 1.5 locale="fr-CA"}}"#, "one"),
+            (r#"{{cldr_plural 3 locale="en" type="ordinal"}}"#, "few"),
+            (r#"{{cldr_plural 11 locale="en" type="ordinal"}}"#, "other"),
+            (r#"{{cldr_plural 2 locale="cy" type="ordinal"}}"#, "two"),
+            (
+                r##"{{cldr_plural 0 zero="no files" one="# file" other="# files"}}"##,
+                "no files",
+            ),
+            (
+                r##"{{cldr_plural 5 locale="pl" one="# plik" few="# pliki" many="# plików"}}"##,
+                "5 plików",
+            ),
+        ] {
+            assert_eq!(
+                h.render_template(template, &()).expect("Render error"),
+                expected,
+                "Failed to test {}",
+                template
+            );
+        }
+    }
+}
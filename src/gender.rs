@@ -0,0 +1,429 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+
+use handlebars::{
+    Context, Handlebars, Helper, HelperDef, HelperResult, Output, RenderContext, RenderErrorReason,
+};
+
+use crate::flag;
+
+/// Grammatical gender of a noun
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Gender {
+    Masculine,
+    Feminine,
+    Neuter,
+}
+
+impl FromStr for Gender {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "m" | "masculine" | "der" | "le" | "el" => Ok(Gender::Masculine),
+            "f" | "feminine" | "die" | "la" => Ok(Gender::Feminine),
+            "n" | "neuter" | "das" => Ok(Gender::Neuter),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Languages supported by [`HandlebarsGenderedArticle`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Language {
+    German,
+    French,
+    Spanish,
+}
+
+impl FromStr for Language {
+    type Err = ();
+
+    /// Parse a language from its ISO 639-1 code, ignoring any region subtag (`de-AT`, `fr_CA`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let code = s.split(['-', '_']).next().unwrap_or_default();
+
+        match code.to_lowercase().as_str() {
+            "de" => Ok(Language::German),
+            "fr" => Ok(Language::French),
+            "es" => Ok(Language::Spanish),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Error produced while loading a [`GenderDictionary`]
+#[derive(Debug)]
+pub enum GenderDictionaryError {
+    /// The dictionary file could not be read
+    Io(std::io::Error),
+    /// A line is not a `noun gender` pair
+    Parse { line: usize, content: String },
+}
+
+impl fmt::Display for GenderDictionaryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GenderDictionaryError::Io(e) => write!(f, "Failed to read gender dictionary: {}", e),
+            GenderDictionaryError::Parse { line, content } => {
+                write!(
+                    f,
+                    "Invalid gender dictionary entry on line {}: {}",
+                    line, content
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for GenderDictionaryError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            GenderDictionaryError::Io(e) => Some(e),
+            GenderDictionaryError::Parse { .. } => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for GenderDictionaryError {
+    fn from(e: std::io::Error) -> Self {
+        GenderDictionaryError::Io(e)
+    }
+}
+
+/// Noun to grammatical gender lookup table
+///
+/// Dictionaries are loaded from plain text, one `noun gender` pair per line. The gender can be
+/// written as `m`/`f`/`n`, `masculine`/`feminine`/`neuter` or as the matching definite article
+/// (`der`, `die`, `das`, `le`, `la`, `el`). Empty lines and lines starting with `#` are ignored.
+///
+/// ```
+/// use handlebars_inflector::{Gender, GenderDictionary};
+///
+/// let dictionary = GenderDictionary::parse("# German\nHaus n\nTür f\nTisch der").expect("Parse error");
+///
+/// assert_eq!(dictionary.get("haus"), Some(Gender::Neuter));
+/// assert_eq!(dictionary.get("Tisch"), Some(Gender::Masculine));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct GenderDictionary {
+    entries: HashMap<String, Gender>,
+}
+
+impl GenderDictionary {
+    /// Create an empty dictionary
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse a dictionary from its text representation
+    pub fn parse(source: &str) -> Result<Self, GenderDictionaryError> {
+        let mut dictionary = Self::new();
+
+        for (index, line) in source.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let entry = line
+                .rsplit_once(char::is_whitespace)
+                .and_then(|(noun, gender)| Some((noun.trim(), gender.parse().ok()?)));
+
+            match entry {
+                Some((noun, gender)) if !noun.is_empty() => dictionary.insert(noun, gender),
+                _ => {
+                    return Err(GenderDictionaryError::Parse {
+                        line: index + 1,
+                        content: line.to_string(),
+                    })
+                }
+            }
+        }
+
+        Ok(dictionary)
+    }
+
+    /// Load a dictionary from a text file
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, GenderDictionaryError> {
+        Self::parse(&std::fs::read_to_string(path)?)
+    }
+
+    /// Add or replace the gender of a noun
+    pub fn insert(&mut self, noun: &str, gender: Gender) {
+        self.entries.insert(noun.to_lowercase(), gender);
+    }
+
+    /// Look up the gender of a noun, ignoring case
+    pub fn get(&self, noun: &str) -> Option<Gender> {
+        self.entries.get(&noun.to_lowercase()).copied()
+    }
+
+    /// Merge all entries of `other` into this dictionary
+    pub fn extend(&mut self, other: GenderDictionary) {
+        self.entries.extend(other.entries);
+    }
+}
+
+#[derive(Clone, Default)]
+/// Gendered article helper for handlebars-rust
+///
+/// Available with the `articles` feature.
+///
+/// # Registration
+///
+/// ```
+/// use handlebars::Handlebars;
+/// use handlebars_inflector::{GenderDictionary, HandlebarsGenderedArticle, Language};
+///
+/// let german = GenderDictionary::parse("Haus n\nTür f").expect("Parse error");
+///
+/// let mut h = Handlebars::new();
+/// h.register_helper(
+///     "gendered_article",
+///     Box::new(HandlebarsGenderedArticle::new().with_dictionary(Language::German, german)),
+/// );
+///
+/// assert_eq!(h.render_template(r#"{{gendered_article this lang="de"}}"#, &String::from("Haus")).expect("Render error"), "das");
+/// ```
+///
+/// # Arguments
+///
+/// * `noun` - The noun to find the article for
+///
+/// # Hash options
+///
+/// `lang`: ISO 639-1 code of the noun's language (`de`, `fr`, `es`), optional when a default
+/// language is configured
+///
+/// `indefinite`: produce the indefinite article (`ein`, `une`, `un`) instead
+///
+/// `with_noun`: output the article followed by the noun (`das Haus`, `l'arbre`)
+///
+/// # Example usage:
+///
+/// `die` / `la` / `la`:
+///
+/// `
+/// {{gendered_article noun lang="de"}}
+/// `
+///
+/// `un arbre`:
+///
+/// `
+/// {{gendered_article noun lang="fr" indefinite=true with_noun=true}}
+/// `
+///
+/// French singular definite articles elide to `l'` before a vowel or `h`. French and Spanish
+/// have no neuter, so neuter entries fall back to the masculine article.
+///
+pub struct HandlebarsGenderedArticle {
+    dictionaries: HashMap<Language, GenderDictionary>,
+    default_language: Option<Language>,
+}
+
+impl HandlebarsGenderedArticle {
+    /// Create a helper without any dictionaries
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a dictionary for `language`, merging it into any dictionary already present
+    pub fn with_dictionary(mut self, language: Language, dictionary: GenderDictionary) -> Self {
+        self.dictionaries
+            .entry(language)
+            .or_default()
+            .extend(dictionary);
+        self
+    }
+
+    /// Language used when the template doesn't pass `lang`
+    pub fn with_default_language(mut self, language: Language) -> Self {
+        self.default_language = Some(language);
+        self
+    }
+}
+
+impl HelperDef for HandlebarsGenderedArticle {
+    fn call<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        r: &'reg Handlebars,
+        _ctx: &'rc Context,
+        _rc: &mut RenderContext<'reg, 'rc>,
+        out: &mut dyn Output,
+    ) -> HelperResult {
+        let noun = if let Some(noun) = h.param(0).and_then(|v| v.value().as_str()) {
+            noun.trim()
+        } else {
+            if r.strict_mode() {
+                return Err(RenderErrorReason::ParamNotFoundForIndex("gendered_article", 0).into());
+            }
+
+            return Ok(());
+        };
+
+        let language = match h.hash_get("lang").and_then(|v| v.value().as_str()) {
+            Some(lang) => lang.parse().ok(),
+            None => self.default_language,
+        };
+
+        let Some(language) = language else {
+            if r.strict_mode() {
+                return Err(RenderErrorReason::HashTypeMismatchForName(
+                    "gendered_article",
+                    "lang".to_string(),
+                    "supported language code".to_string(),
+                )
+                .into());
+            }
+
+            return Ok(());
+        };
+
+        let gender = self
+            .dictionaries
+            .get(&language)
+            .and_then(|dictionary| dictionary.get(noun));
+
+        let Some(gender) = gender else {
+            if r.strict_mode() {
+                return Err(RenderErrorReason::Other(format!(
+                    "Helper gendered_article has no gender for noun \"{}\"",
+                    noun
+                ))
+                .into());
+            }
+
+            return Ok(());
+        };
+
+        let article = article(language, gender, noun, flag(h, "indefinite"));
+
+        if flag(h, "with_noun") {
+            if article.ends_with('\'') {
+                out.write(&format!("{}{}", article, noun))?;
+            } else {
+                out.write(&format!("{} {}", article, noun))?;
+            }
+        } else {
+            out.write(article)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Select the nominative singular article for a noun.
+fn article(language: Language, gender: Gender, noun: &str, indefinite: bool) -> &'static str {
+    match (language, gender, indefinite) {
+        (Language::German, Gender::Masculine, false) => "der",
+        (Language::German, Gender::Feminine, false) => "die",
+        (Language::German, Gender::Neuter, false) => "das",
+        (Language::German, Gender::Feminine, true) => "eine",
+        (Language::German, _, true) => "ein",
+        (Language::French, _, false) if elides(noun) => "l'",
+        (Language::French, Gender::Feminine, false) => "la",
+        (Language::French, _, false) => "le",
+        (Language::French, Gender::Feminine, true) => "une",
+        (Language::French, _, true) => "un",
+        (Language::Spanish, Gender::Feminine, false) => "la",
+        (Language::Spanish, _, false) => "el",
+        (Language::Spanish, Gender::Feminine, true) => "una",
+        (Language::Spanish, _, true) => "un",
+    }
+}
+
+/// Whether a French definite article elides before `noun`.
+fn elides(noun: &str) -> bool {
+    noun.chars().next().is_some_and(|c| {
+        "aàâäeéèêëiîïoôöuùûüyh"
+            .chars()
+            .any(|v| c.to_lowercase().eq(std::iter::once(v)))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use handlebars::Handlebars;
+
+    #[test]
+    fn it_works() {
+        let german = GenderDictionary::parse("Haus n\nTür f\nTisch m").expect("Parse error");
+        let french = GenderDictionary::parse("maison f\narbre m\nlivre le").expect("Parse error");
+        let spanish = GenderDictionary::parse("casa f\nlibro m").expect("Parse error");
+
+        let mut h = Handlebars::new();
+        h.register_helper(
+            "gendered_article",
+            Box::new(
+                HandlebarsGenderedArticle::new()
+                    .with_dictionary(Language::German, german)
+                    .with_dictionary(Language::French, french)
+                    .with_dictionary(Language::Spanish, spanish)
+                    .with_default_language(Language::German),
+            ),
+        );
+
+        for (template, expected) in [
+            (r#"{{gendered_article "Haus"}}"#, "das"),
+            (r#"{{gendered_article "Tür" lang="de"}}"#, "die"),
+            (r#"{{gendered_article "tisch" lang="de-AT"}}"#, "der"),
+            (r#"{{gendered_article "Haus" indefinite=true}}"#, "ein"),
+            (
+                r#"{{gendered_article "Tür" indefinite=true with_noun=true}}"#,
+                "eine Tür",
+            ),
+            (r#"{{gendered_article "maison" lang="fr"}}"#, "la"),
+            (r#"{{gendered_article "livre" lang="fr"}}"#, "le"),
+            (
+                r#"{{gendered_article "arbre" lang="fr" with_noun=true}}"#,
+                "l'arbre",
+            ),
+            (
+                r#"{{gendered_article "arbre" lang="fr" indefinite=true}}"#,
+                "un",
+            ),
+            (r#"{{gendered_article "casa" lang="es"}}"#, "la"),
+            (
+                r#"{{gendered_article "libro" lang="es" indefinite=true}}"#,
+                "un",
+            ),
+            (r#"{{gendered_article "Auto" lang="de"}}"#, ""),
+        ] {
+            assert_eq!(
+                h.render_template(template, &()).expect("Render error"),
+                expected,
+                "Failed to test {}",
+                template
+            );
+        }
+
+        h.set_strict_mode(true);
+
+        assert!(
+            h.render_template(r#"{{gendered_article "Auto" lang="de"}}"#, &())
+                .is_err(),
+            "Failed to test unknown noun in strict mode"
+        );
+    }
+
+    #[test]
+    fn it_rejects_invalid_dictionaries() {
+        assert!(
+            matches!(
+                GenderDictionary::parse("Haus n\nTür x"),
+                Err(GenderDictionaryError::Parse { line: 2, .. })
+            ),
+            "Failed to test invalid gender"
+        );
+        assert!(
+            GenderDictionary::parse("n").is_err(),
+            "Failed to test missing noun"
+        );
+    }
+}
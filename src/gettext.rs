@@ -0,0 +1,468 @@
+use std::str::FromStr;
+
+use handlebars::{
+    Context, Handlebars, Helper, HelperDef, HelperResult, JsonRender, Output, RenderContext,
+    RenderErrorReason,
+};
+
+use crate::count::count_of;
+
+/// Parsed gettext `Plural-Forms` rule
+///
+/// Accepts a full header value (`nplurals=3; plural=...;`) or a bare `plural` expression. The
+/// expression language is the C subset gettext uses: the variable `n`, unsigned integers, `!`,
+/// arithmetic (`* / % + -`), comparisons, `&&`, `||`, `?:` and parentheses. Expressions longer
+/// than [`MAX_LENGTH`] or nested deeper than [`MAX_DEPTH`] are rejected, as rules may come from
+/// template data.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct PluralForms {
+    nplurals: Option<usize>,
+    plural: Expr,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Expr {
+    N,
+    Number(u64),
+    Not(Box<Expr>),
+    Binary(Box<Expr>, Op, Box<Expr>),
+    Ternary(Box<Expr>, Box<Expr>, Box<Expr>),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Op {
+    Or,
+    And,
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+}
+
+/// Deepest nesting of parentheses, negations and conditionals in a plural expression
+const MAX_DEPTH: usize = 32;
+
+/// Longest plural expression in bytes, bounding the depth of operator chains like `n+n+...+n`
+const MAX_LENGTH: usize = 1024;
+
+/// Binary operators from the lowest to the highest precedence level.
+const PRECEDENCE: &[&[(&str, Op)]] = &[
+    &[("||", Op::Or)],
+    &[("&&", Op::And)],
+    &[("==", Op::Eq), ("!=", Op::Ne)],
+    &[("<=", Op::Le), (">=", Op::Ge), ("<", Op::Lt), (">", Op::Gt)],
+    &[("+", Op::Add), ("-", Op::Sub)],
+    &[("*", Op::Mul), ("/", Op::Div), ("%", Op::Rem)],
+];
+
+impl FromStr for PluralForms {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut nplurals = None;
+        let mut plural = None;
+
+        for part in s.split(';').map(str::trim).filter(|p| !p.is_empty()) {
+            match part.split_once('=').map(|(k, v)| (k.trim(), v)) {
+                Some(("nplurals", v)) => nplurals = Some(v.trim().parse().map_err(|_| ())?),
+                Some(("plural", v)) => plural = Some(v),
+                _ if plural.is_none() => plural = Some(part),
+                _ => return Err(()),
+            }
+        }
+
+        let plural = plural.ok_or(())?;
+        if plural.len() > MAX_LENGTH {
+            return Err(());
+        }
+
+        let mut parser = Parser {
+            input: plural.as_bytes(),
+            pos: 0,
+            depth: 0,
+        };
+        let plural = parser.ternary()?;
+
+        parser.skip_whitespace();
+        if parser.pos != parser.input.len() {
+            return Err(());
+        }
+
+        Ok(PluralForms { nplurals, plural })
+    }
+}
+
+impl PluralForms {
+    /// Index of the form to use for `n`, limited to `nplurals - 1` when declared.
+    pub(crate) fn index(&self, n: u64) -> usize {
+        let index = self.plural.eval(n) as usize;
+
+        match self.nplurals {
+            Some(nplurals) => index.min(nplurals.saturating_sub(1)),
+            None => index,
+        }
+    }
+}
+
+impl Expr {
+    fn eval(&self, n: u64) -> u64 {
+        match self {
+            Expr::N => n,
+            Expr::Number(value) => *value,
+            Expr::Not(e) => (e.eval(n) == 0) as u64,
+            Expr::Ternary(c, a, b) => {
+                if c.eval(n) != 0 {
+                    a.eval(n)
+                } else {
+                    b.eval(n)
+                }
+            }
+            Expr::Binary(a, Op::Or, b) => (a.eval(n) != 0 || b.eval(n) != 0) as u64,
+            Expr::Binary(a, Op::And, b) => (a.eval(n) != 0 && b.eval(n) != 0) as u64,
+            Expr::Binary(a, op, b) => {
+                let (a, b) = (a.eval(n), b.eval(n));
+
+                match op {
+                    Op::Eq => (a == b) as u64,
+                    Op::Ne => (a != b) as u64,
+                    Op::Lt => (a < b) as u64,
+                    Op::Le => (a <= b) as u64,
+                    Op::Gt => (a > b) as u64,
+                    Op::Ge => (a >= b) as u64,
+                    Op::Add => a.wrapping_add(b),
+                    Op::Sub => a.wrapping_sub(b),
+                    Op::Mul => a.wrapping_mul(b),
+                    Op::Div => a.checked_div(b).unwrap_or(0),
+                    Op::Rem => a.checked_rem(b).unwrap_or(0),
+                    Op::Or | Op::And => unreachable!(),
+                }
+            }
+        }
+    }
+}
+
+/// Recursive descent parser for plural expressions
+struct Parser<'a> {
+    input: &'a [u8],
+    pos: usize,
+    depth: usize,
+}
+
+impl Parser<'_> {
+    /// Run `parse` one nesting level deeper, failing past [`MAX_DEPTH`].
+    fn nested(&mut self, parse: fn(&mut Self) -> Result<Expr, ()>) -> Result<Expr, ()> {
+        if self.depth >= MAX_DEPTH {
+            return Err(());
+        }
+
+        self.depth += 1;
+        let expr = parse(self);
+        self.depth -= 1;
+
+        expr
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self
+            .input
+            .get(self.pos)
+            .is_some_and(u8::is_ascii_whitespace)
+        {
+            self.pos += 1;
+        }
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+
+        if self.input[self.pos..].starts_with(token.as_bytes()) {
+            self.pos += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn ternary(&mut self) -> Result<Expr, ()> {
+        let condition = self.binary(0)?;
+
+        if !self.eat("?") {
+            return Ok(condition);
+        }
+
+        let then = self.nested(Self::ternary)?;
+
+        if !self.eat(":") {
+            return Err(());
+        }
+
+        let otherwise = self.nested(Self::ternary)?;
+
+        Ok(Expr::Ternary(
+            Box::new(condition),
+            Box::new(then),
+            Box::new(otherwise),
+        ))
+    }
+
+    fn binary(&mut self, level: usize) -> Result<Expr, ()> {
+        let Some(operators) = PRECEDENCE.get(level) else {
+            return self.unary();
+        };
+
+        let mut lhs = self.binary(level + 1)?;
+
+        'operators: loop {
+            for (token, op) in operators.iter() {
+                // Longer tokens come first in the table, so `<` never eats the start of `<=`.
+                if self.eat(token) {
+                    let rhs = self.binary(level + 1)?;
+                    lhs = Expr::Binary(Box::new(lhs), *op, Box::new(rhs));
+                    continue 'operators;
+                }
+            }
+
+            return Ok(lhs);
+        }
+    }
+
+    fn unary(&mut self) -> Result<Expr, ()> {
+        if self.eat("!") {
+            return Ok(Expr::Not(Box::new(self.nested(Self::unary)?)));
+        }
+
+        if self.eat("(") {
+            let expr = self.nested(Self::ternary)?;
+
+            return if self.eat(")") { Ok(expr) } else { Err(()) };
+        }
+
+        if self.eat("n") {
+            return Ok(Expr::N);
+        }
+
+        let start = self.pos;
+        while self.input.get(self.pos).is_some_and(u8::is_ascii_digit) {
+            self.pos += 1;
+        }
+
+        std::str::from_utf8(&self.input[start..self.pos])
+            .ok()
+            .and_then(|digits| digits.parse().ok())
+            .map(Expr::Number)
+            .ok_or(())
+    }
+}
+
+#[derive(Clone, Copy)]
+/// Gettext plural forms helper for handlebars-rust
+///
+/// # Registration
+///
+/// ```
+/// use handlebars::Handlebars;
+/// use handlebars_inflector::HandlebarsPluralForms;
+///
+/// let mut h = Handlebars::new();
+/// h.register_helper("plural_forms", Box::new(HandlebarsPluralForms));
+///
+/// let rule = "nplurals=3; plural=(n==1 ? 0 : n%10>=2 && n%10<=4 && (n%100<10 || n%100>=20) ? 1 : 2);";
+///
+/// assert_eq!(h.render_template(r#"{{plural_forms 22 rule "plik" "pliki" "plików"}}"#, &serde_json::json!({"rule": rule})).expect("Render error"), "pliki");
+/// ```
+///
+/// # Arguments
+///
+/// * `count` - A number, a numeric string, an array or an object whose length is used as count
+/// * `rule` - A gettext `Plural-Forms` header value or a bare `plural` expression
+/// * `forms...` - The translated forms, in catalog order
+///
+/// The expression selects the form by index. An index past the last form given selects the last
+/// form. Fractional counts are truncated, as gettext only deals with unsigned integers.
+///
+/// # Example usage:
+///
+/// `
+/// {{plural_forms count catalog.plural_forms "файл" "файла" "файлов"}}
+/// `
+///
+pub struct HandlebarsPluralForms;
+
+impl HelperDef for HandlebarsPluralForms {
+    fn call<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        r: &'reg Handlebars,
+        _ctx: &'rc Context,
+        _rc: &mut RenderContext<'reg, 'rc>,
+        out: &mut dyn Output,
+    ) -> HelperResult {
+        let (count, rule) = match (h.param(0), h.param(1)) {
+            (Some(count), Some(rule)) => (count, rule),
+            (count, _) => {
+                if r.strict_mode() {
+                    let index = if count.is_some() { 1 } else { 0 };
+                    return Err(
+                        RenderErrorReason::ParamNotFoundForIndex("plural_forms", index).into(),
+                    );
+                }
+
+                return Ok(());
+            }
+        };
+
+        let count = if let Some(count) = count_of(count.value()) {
+            count.abs().trunc() as u64
+        } else {
+            if r.strict_mode() {
+                return Err(RenderErrorReason::ParamTypeMismatchForName(
+                    "plural_forms",
+                    "0".to_string(),
+                    "number or array".to_string(),
+                )
+                .into());
+            }
+
+            return Ok(());
+        };
+
+        let rule = if let Some(rule) = rule
+            .value()
+            .as_str()
+            .and_then(|s| s.parse::<PluralForms>().ok())
+        {
+            rule
+        } else {
+            if r.strict_mode() {
+                return Err(RenderErrorReason::ParamTypeMismatchForName(
+                    "plural_forms",
+                    "1".to_string(),
+                    "Plural-Forms expression".to_string(),
+                )
+                .into());
+            }
+
+            return Ok(());
+        };
+
+        let forms = &h.params()[2..];
+        let index = rule.index(count).min(forms.len().saturating_sub(1));
+
+        match forms.get(index) {
+            Some(form) => out.write(&form.value().render())?,
+            None if r.strict_mode() => {
+                return Err(RenderErrorReason::ParamNotFoundForIndex("plural_forms", 2).into())
+            }
+            None => {}
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use handlebars::Handlebars;
+
+    #[test]
+    fn it_evaluates_plural_forms() {
+        let russian = "nplurals=3; plural=(n%10==1 && n%100!=11 ? 0 : n%10>=2 && n%10<=4 && (n%100<10 || n%100>=20) ? 1 : 2);";
+        let arabic = "nplurals=6; plural=n==0 ? 0 : n==1 ? 1 : n==2 ? 2 : n%100>=3 && n%100<=10 ? 3 : n%100>=11 ? 4 : 5;";
+
+        for (rule, counts) in [
+            ("n != 1", &[(0, 1), (1, 0), (2, 1)][..]),
+            ("nplurals=2; plural=n>1;", &[(0, 0), (1, 0), (2, 1)][..]),
+            ("nplurals=1; plural=0;", &[(0, 0), (5, 0)][..]),
+            (
+                russian,
+                &[(1, 0), (11, 2), (21, 0), (22, 1), (25, 2), (112, 2)][..],
+            ),
+            (arabic, &[(0, 0), (2, 2), (103, 3), (111, 4), (100, 5)][..]),
+            ("nplurals=2; plural=n+5;", &[(1, 1)][..]),
+            ("!(n <= 1)", &[(1, 0), (3, 1)][..]),
+        ] {
+            let forms: PluralForms = rule.parse().expect("Invalid rule");
+
+            for (n, expected) in counts {
+                assert_eq!(
+                    forms.index(*n),
+                    *expected,
+                    "Failed to evaluate {} for {}",
+                    rule,
+                    n
+                );
+            }
+        }
+
+        for rule in [
+            "",
+            "n ==",
+            "(n",
+            "n ? 1",
+            "n % 10 x",
+            "nplurals=x; plural=n;",
+        ] {
+            assert!(
+                rule.parse::<PluralForms>().is_err(),
+                "Failed to reject {}",
+                rule
+            );
+        }
+
+        let nested = |open: &str, close: &str, depth: usize| {
+            format!("{}n{}", open.repeat(depth), close.repeat(depth))
+        };
+
+        assert!(
+            nested("(", ")", MAX_DEPTH).parse::<PluralForms>().is_ok(),
+            "Failed to accept nesting up to the limit"
+        );
+
+        for rule in [
+            nested("(", ")", 100_000),
+            nested("!", "", 100_000),
+            nested("n ? 1 : ", "", 100_000),
+            nested("(", ")", MAX_DEPTH + 1),
+            "n+".repeat(100_000) + "n",
+        ] {
+            assert!(
+                rule.parse::<PluralForms>().is_err(),
+                "Failed to reject {}...",
+                &rule[..10]
+            );
+        }
+    }
+
+    #[test]
+    fn it_works() {
+        let mut h = Handlebars::new();
+        h.register_helper("plural_forms", Box::new(HandlebarsPluralForms));
+
+        let template = r#"{{plural_forms this "n%10==1 && n%100!=11 ? 0 : n%10>=2 && n%10<=4 && (n%100<10 || n%100>=20) ? 1 : 2" "файл" "файла" "файлов"}}"#;
+
+        for (count, expected) in [(1, "файл"), (3, "файла"), (11, "файлов"), (101, "файл")]
+        {
+            assert_eq!(
+                h.render_template(template, &count).expect("Render error"),
+                expected,
+                "Failed to test plural_forms with {}",
+                count
+            );
+        }
+
+        assert_eq!(
+            h.render_template(r#"{{plural_forms 3 "n" "one" "other"}}"#, &())
+                .expect("Render error"),
+            "other",
+            "Failed to test plural_forms with an index past the last form"
+        );
+    }
+}
@@ -0,0 +1,455 @@
+use std::str::FromStr;
+
+use handlebars::{
+    Context, Handlebars, Helper, HelperDef, JsonValue, RenderContext, RenderError,
+    RenderErrorReason, ScopedJson,
+};
+use unicode_ident::{is_xid_continue, is_xid_start};
+
+use crate::case::capitalize;
+use crate::numbers::digit_word;
+
+/// Identifier profile selected with the `profile` hash option
+///
+/// All profiles build on the UAX #31 default identifier syntax (`XID_Start XID_Continue*`) and
+/// add the extra characters the target language allows.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) enum Profile {
+    /// Plain UAX #31 default identifiers
+    #[default]
+    Default,
+    /// Rust: may start with `_`, but `_` alone is not an identifier
+    Rust,
+    /// Python: may start with `_`
+    Python,
+    /// Go: may start with `_`
+    Go,
+    /// JavaScript: may start with `_` or `$` and contain `$`, ZWNJ and ZWJ
+    JavaScript,
+}
+
+impl Profile {
+    /// Names accepted by the `profile` hash option
+    pub(crate) const NAMES: &'static [&'static str] =
+        &["default", "rust", "python", "go", "javascript"];
+}
+
+impl FromStr for Profile {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "default" | "uax31" => Ok(Profile::Default),
+            "rust" => Ok(Profile::Rust),
+            "python" => Ok(Profile::Python),
+            "go" => Ok(Profile::Go),
+            "javascript" | "js" => Ok(Profile::JavaScript),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Profile {
+    fn is_start(self, c: char) -> bool {
+        is_xid_start(c)
+            || match self {
+                Profile::Default => false,
+                Profile::Rust | Profile::Python | Profile::Go => c == '_',
+                Profile::JavaScript => c == '_' || c == '$',
+            }
+    }
+
+    fn is_continue(self, c: char) -> bool {
+        is_xid_continue(c)
+            || (self == Profile::JavaScript && matches!(c, '$' | '\u{200C}' | '\u{200D}'))
+    }
+
+    fn allows_leading_underscore(self) -> bool {
+        self != Profile::Default
+    }
+}
+
+/// What to do when an identifier would start with a digit, selected with the `leading_digit`
+/// hash option
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum LeadingDigit {
+    /// Prefix with `_`: `_2fa_enabled`
+    Underscore,
+    /// Prefix with a word: `n2fa_enabled`
+    Prefix,
+    /// Spell the leading digits out: `two_fa_enabled`
+    Spell,
+    /// Fail in strict mode
+    Error,
+}
+
+impl FromStr for LeadingDigit {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "underscore" => Ok(LeadingDigit::Underscore),
+            "prefix" => Ok(LeadingDigit::Prefix),
+            "spell" => Ok(LeadingDigit::Spell),
+            "error" => Ok(LeadingDigit::Error),
+            _ => Err(()),
+        }
+    }
+}
+
+impl LeadingDigit {
+    /// Rewrite `input` if it starts with an ASCII digit.
+    ///
+    /// `Error` leaves the input untouched, the caller decides how to fail.
+    pub(crate) fn apply(self, input: &str, prefix: &str) -> String {
+        if !input.starts_with(|c: char| c.is_ascii_digit()) {
+            return input.to_string();
+        }
+
+        match self {
+            LeadingDigit::Underscore => format!("_{}", input),
+            LeadingDigit::Prefix => format!("{}{}", prefix, input),
+            LeadingDigit::Spell => spell_leading_digits(input),
+            LeadingDigit::Error => input.to_string(),
+        }
+    }
+}
+
+/// Replace the leading digits of `input` with their names, following its convention.
+///
+/// Snake-like input gets `_` separated words in the case of the rest (`2fa_enabled` to
+/// `two_fa_enabled`, `2FA_CODE` to `TWO_FA_CODE`), anything else is joined in camel humps,
+/// capitalized when the original continues in uppercase (`2FaEnabled` to `TwoFaEnabled`,
+/// `3dModel` to `threeDModel`).
+fn spell_leading_digits(input: &str) -> String {
+    let split = input
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(input.len());
+    let (digits, rest) = input.split_at(split);
+    let words = digits.chars().filter_map(digit_word);
+
+    if input.contains('_') {
+        let mut output = words.collect::<Vec<_>>().join("_");
+
+        if !rest.is_empty() && !rest.starts_with('_') {
+            output.push('_');
+        }
+
+        let letters = rest.chars().filter(|c| c.is_alphabetic());
+        let upper =
+            rest.chars().any(char::is_alphabetic) && letters.clone().all(char::is_uppercase);
+
+        if upper {
+            output = output.to_uppercase();
+        } else if rest.trim_start_matches('_').starts_with(char::is_uppercase) {
+            output = output
+                .split('_')
+                .map(capitalize)
+                .collect::<Vec<_>>()
+                .join("_");
+        }
+
+        return output + rest;
+    }
+
+    let capitalized = rest.starts_with(char::is_uppercase);
+    let mut output = String::new();
+
+    for (i, word) in words.enumerate() {
+        if i == 0 && !capitalized {
+            output.push_str(word);
+        } else {
+            output.push_str(&capitalize(word));
+        }
+    }
+
+    let mut rest = rest.chars();
+    output.extend(rest.next().map(|c| c.to_uppercase().collect::<String>()));
+    output.extend(rest);
+
+    output
+}
+
+/// Whether `input` is a valid identifier under `profile`.
+pub(crate) fn is_identifier(input: &str, profile: Profile) -> bool {
+    let mut chars = input.chars();
+
+    let valid =
+        chars.next().is_some_and(|c| profile.is_start(c)) && chars.all(|c| profile.is_continue(c));
+
+    valid && !(profile == Profile::Rust && input == "_")
+}
+
+/// Coerce `input` into a valid identifier under `profile`.
+///
+/// Runs of invalid characters become a single `_`. When the result doesn't start with a valid
+/// start character it is prefixed with `_` if the profile allows it, otherwise a leading digit is
+/// prefixed with `n` and any other offending leading characters are dropped.
+pub(crate) fn to_identifier(input: &str, profile: Profile) -> String {
+    let mut output = String::with_capacity(input.len());
+
+    for c in input.chars() {
+        if profile.is_continue(c) {
+            output.push(c);
+        } else if !output.is_empty() && !output.ends_with('_') {
+            output.push('_');
+        }
+    }
+
+    while output.len() > 1 && output.ends_with('_') && !input.ends_with('_') {
+        output.pop();
+    }
+
+    if output.chars().next().is_some_and(|c| !profile.is_start(c)) {
+        if profile.allows_leading_underscore() {
+            output.insert(0, '_');
+        } else if output.starts_with(char::is_numeric) {
+            output.insert(0, 'n');
+        } else {
+            let start = output
+                .char_indices()
+                .find(|(_, c)| profile.is_start(*c))
+                .map(|(i, _)| i)
+                .unwrap_or(output.len());
+            output.drain(..start);
+        }
+    }
+
+    if profile == Profile::Rust && output == "_" {
+        output.push('_');
+    }
+
+    output
+}
+
+/// Rust strict and reserved keywords of the 2024 edition, all usable as raw identifiers
+const RUST_KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl", "in", "let",
+    "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return",
+    "static", "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use",
+    "virtual", "where", "while", "yield",
+];
+
+/// Rust keywords that can't be raw identifiers
+const RUST_PATH_KEYWORDS: &[&str] = &["crate", "self", "Self", "super", "_"];
+
+/// Whether `input` is a Rust keyword, including the ones that can't be raw identifiers.
+pub(crate) fn is_rust_keyword(input: &str) -> bool {
+    RUST_KEYWORDS.contains(&input) || RUST_PATH_KEYWORDS.contains(&input)
+}
+
+/// Escape `input` if it is a Rust keyword: `type` to `r#type`.
+///
+/// `crate`, `self`, `Self`, `super` and `_` can't be raw identifiers and get a `_` appended
+/// instead. Anything else is returned as it is.
+pub(crate) fn to_raw_identifier(input: &str) -> String {
+    if RUST_KEYWORDS.contains(&input) {
+        format!("r#{}", input)
+    } else if RUST_PATH_KEYWORDS.contains(&input) {
+        format!("{}_", input)
+    } else {
+        input.to_string()
+    }
+}
+
+#[derive(Clone, Copy)]
+/// Identifier validation helper for handlebars-rust
+///
+/// Returns a boolean, so it is meant to be used in subexpressions.
+///
+/// # Registration
+///
+/// ```
+/// use handlebars::Handlebars;
+/// use handlebars_inflector::HandlebarsIsIdentifier;
+///
+/// let mut h = Handlebars::new();
+/// h.register_helper("is_identifier", Box::new(HandlebarsIsIdentifier));
+///
+/// assert_eq!(h.render_template(r#"{{#if (is_identifier this profile="rust")}}ok{{/if}}"#, &String::from("größe")).expect("Render error"), "ok");
+/// ```
+///
+/// # Arguments
+///
+/// * `param` - A string value to be validated
+///
+/// # Hash options
+///
+/// `profile`: `default` (UAX #31), `rust`, `python`, `go` or `javascript`
+///
+pub struct HandlebarsIsIdentifier;
+
+impl HelperDef for HandlebarsIsIdentifier {
+    fn call_inner<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        r: &'reg Handlebars,
+        _ctx: &'rc Context,
+        _rc: &mut RenderContext<'reg, 'rc>,
+    ) -> Result<ScopedJson<'rc>, RenderError> {
+        let profile = match h.hash_get("profile").and_then(|v| v.value().as_str()) {
+            Some(profile) => match profile.parse() {
+                Ok(profile) => profile,
+                Err(_) if r.strict_mode() => {
+                    return Err(RenderErrorReason::HashTypeMismatchForName(
+                        "is_identifier",
+                        "profile".to_string(),
+                        "identifier profile".to_string(),
+                    )
+                    .into())
+                }
+                Err(_) => Profile::Default,
+            },
+            None => Profile::Default,
+        };
+
+        let valid = match h.param(0).map(|v| v.value()) {
+            Some(JsonValue::String(input)) => is_identifier(input, profile),
+            Some(_) => false,
+            None => {
+                if r.strict_mode() {
+                    return Err(RenderErrorReason::ParamNotFoundForIndex("is_identifier", 0).into());
+                }
+
+                false
+            }
+        };
+
+        Ok(ScopedJson::Derived(JsonValue::Bool(valid)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use handlebars::Handlebars;
+
+    #[test]
+    fn it_parses_profile_names() {
+        for name in Profile::NAMES {
+            assert!(name.parse::<Profile>().is_ok(), "Failed to test {}", name);
+        }
+    }
+
+    #[test]
+    fn it_handles_leading_digits() {
+        for (input, strategy, expected) in [
+            ("2fa_enabled", LeadingDigit::Underscore, "_2fa_enabled"),
+            ("2fa_enabled", LeadingDigit::Prefix, "n2fa_enabled"),
+            ("2fa_enabled", LeadingDigit::Spell, "two_fa_enabled"),
+            ("24_hours", LeadingDigit::Spell, "two_four_hours"),
+            ("2_FA", LeadingDigit::Spell, "TWO_FA"),
+            ("2_Fa", LeadingDigit::Spell, "Two_Fa"),
+            ("2faEnabled", LeadingDigit::Spell, "twoFaEnabled"),
+            ("3DModel", LeadingDigit::Spell, "ThreeDModel"),
+            ("42", LeadingDigit::Spell, "fourTwo"),
+            ("2fa_enabled", LeadingDigit::Error, "2fa_enabled"),
+            ("fa2", LeadingDigit::Spell, "fa2"),
+        ] {
+            assert_eq!(
+                strategy.apply(input, "n"),
+                expected,
+                "Failed to handle leading digits of {} with {:?}",
+                input,
+                strategy
+            );
+        }
+    }
+
+    #[test]
+    fn it_validates() {
+        for (input, profile, expected) in [
+            ("größe", Profile::Default, true),
+            ("変数", Profile::Default, true),
+            ("_private", Profile::Default, false),
+            ("_private", Profile::Rust, true),
+            ("_", Profile::Rust, false),
+            ("_", Profile::Python, true),
+            ("$scope", Profile::JavaScript, true),
+            ("$scope", Profile::Go, false),
+            ("2fa", Profile::Rust, false),
+            ("user-name", Profile::Python, false),
+            ("", Profile::Default, false),
+        ] {
+            assert_eq!(
+                is_identifier(input, profile),
+                expected,
+                "Failed to validate {} with {:?}",
+                input,
+                profile
+            );
+        }
+    }
+
+    #[test]
+    fn it_coerces() {
+        for (input, profile, expected) in [
+            ("user-name", Profile::Rust, "user_name"),
+            ("2fa enabled", Profile::Python, "_2fa_enabled"),
+            ("2fa enabled", Profile::Default, "n2fa_enabled"),
+            ("\u{301}name", Profile::Default, "name"),
+            ("price ($)", Profile::Go, "price"),
+            ("price ($)", Profile::JavaScript, "price_$"),
+            ("größe", Profile::Default, "größe"),
+            ("_", Profile::Rust, "__"),
+        ] {
+            assert_eq!(
+                to_identifier(input, profile),
+                expected,
+                "Failed to coerce {} with {:?}",
+                input,
+                profile
+            );
+        }
+    }
+
+    #[test]
+    fn it_escapes_rust_keywords() {
+        for (input, expected) in [
+            ("type", "r#type"),
+            ("match", "r#match"),
+            ("async", "r#async"),
+            ("gen", "r#gen"),
+            ("self", "self_"),
+            ("Self", "Self_"),
+            ("_", "__"),
+            ("Type", "Type"),
+            ("r#type", "r#type"),
+            ("types", "types"),
+            ("", ""),
+        ] {
+            assert_eq!(
+                to_raw_identifier(input),
+                expected,
+                "Failed to test {}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn it_works() {
+        let mut h = Handlebars::new();
+        h.register_helper("is_identifier", Box::new(HandlebarsIsIdentifier));
+
+        assert_eq!(
+            h.render_template(r#"{{is_identifier "user_name"}}"#, &())
+                .expect("Render error"),
+            "true",
+            "Failed to test valid identifier"
+        );
+        assert_eq!(
+            h.render_template(r#"{{is_identifier "user name"}}"#, &())
+                .expect("Render error"),
+            "false",
+            "Failed to test invalid identifier"
+        );
+        assert_eq!(
+            h.render_template(r#"{{is_identifier 42}}"#, &())
+                .expect("Render error"),
+            "false",
+            "Failed to test non-string param"
+        );
+    }
+}
@@ -0,0 +1,192 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Common irregular English nouns as singular and plural forms, which Inflector mangles when
+/// inflected again (`person` to `personople`)
+const IRREGULARS: &[(&str, &str)] = &[
+    ("person", "people"),
+    ("child", "children"),
+    ("man", "men"),
+    ("woman", "women"),
+    ("mouse", "mice"),
+    ("louse", "lice"),
+    ("goose", "geese"),
+    ("tooth", "teeth"),
+    ("foot", "feet"),
+    ("ox", "oxen"),
+    ("datum", "data"),
+    ("criterion", "criteria"),
+    ("medium", "media"),
+    ("phenomenon", "phenomena"),
+];
+
+/// Custom irregular and uncountable nouns of an `inflect` helper
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Nouns {
+    /// Irregular nouns as singular to plural forms
+    pub(crate) irregulars: BTreeMap<String, String>,
+    /// Nouns without a plural form, in lowercase
+    pub(crate) uncountable: BTreeSet<String>,
+}
+
+impl Nouns {
+    pub(crate) const fn new() -> Self {
+        Nouns {
+            irregulars: BTreeMap::new(),
+            uncountable: BTreeSet::new(),
+        }
+    }
+
+    /// Inflect the last word of `input` when it is a custom noun.
+    ///
+    /// An uncountable last word leaves `input` untouched. An irregular one is matched
+    /// case-insensitively in either form and replaced with the plural or singular form, following
+    /// its casing. Returns `None` when the last word is not a custom noun.
+    pub(crate) fn inflect(&self, input: &str, plural: bool) -> Option<String> {
+        if self.irregulars.is_empty() && self.uncountable.is_empty() {
+            return None;
+        }
+
+        let (head, word) = input.split_at(last_word_start(input));
+        let lower = word.to_lowercase();
+
+        if self.uncountable.contains(&lower) {
+            return Some(input.to_string());
+        }
+
+        let (one, other) = self
+            .irregulars
+            .iter()
+            .find(|(one, other)| one.to_lowercase() == lower || other.to_lowercase() == lower)?;
+        let form = if plural { other } else { one };
+
+        Some(format!("{}{}", head, match_case(word, form)))
+    }
+}
+
+/// Inflect the last word of `input` when it is a common irregular noun in either form.
+///
+/// Returns `None` when the last word is not one of them, leaving it to Inflector.
+pub(crate) fn inflect_irregular(input: &str, plural: bool) -> Option<String> {
+    let (head, word) = input.split_at(last_word_start(input));
+    let lower = word.to_lowercase();
+
+    let (one, other) = IRREGULARS
+        .iter()
+        .find(|(one, other)| *one == lower || *other == lower)?;
+    let form = if plural { other } else { one };
+
+    Some(format!("{}{}", head, match_case(word, form)))
+}
+
+/// Byte offset of the last word of `input`: its trailing letters, starting at the last case hump.
+pub(crate) fn last_word_start(input: &str) -> usize {
+    let mut start = input.len();
+    let mut previous: Option<char> = None;
+
+    for (index, c) in input.char_indices().rev() {
+        if !c.is_alphabetic() {
+            break;
+        }
+
+        if previous.is_some_and(char::is_uppercase) && c.is_lowercase() {
+            break;
+        }
+
+        start = index;
+
+        if c.is_uppercase() && previous.is_some_and(char::is_lowercase) {
+            break;
+        }
+
+        previous = Some(c);
+    }
+
+    start
+}
+
+/// Apply the casing of `template` (all caps or capitalized) to `word`.
+pub(crate) fn match_case(template: &str, word: &str) -> String {
+    if template.chars().count() > 1 && template.chars().all(|c| !c.is_lowercase()) {
+        word.to_uppercase()
+    } else if template.chars().next().is_some_and(char::is_uppercase) {
+        let mut chars = word.chars();
+        chars
+            .next()
+            .map(|c| c.to_uppercase().chain(chars).collect())
+            .unwrap_or_default()
+    } else {
+        word.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_finds_the_last_word() {
+        for (input, expected) in [
+            ("product_octopus", "octopus"),
+            ("ProductOctopus", "Octopus"),
+            ("product octopus", "octopus"),
+            ("OCTOPUS", "OCTOPUS"),
+            ("v2", ""),
+        ] {
+            assert_eq!(
+                &input[last_word_start(input)..],
+                expected,
+                "Failed to test {}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn it_inflects_custom_nouns() {
+        let nouns = Nouns {
+            irregulars: BTreeMap::from([("octopus".to_string(), "octopuses".to_string())]),
+            uncountable: BTreeSet::from(["equipment".to_string()]),
+        };
+
+        for (input, plural, expected) in [
+            ("octopus", true, Some("octopuses")),
+            ("octopuses", true, Some("octopuses")),
+            ("Octopuses", false, Some("Octopus")),
+            ("product_octopus", true, Some("product_octopuses")),
+            ("SEA_OCTOPUS", true, Some("SEA_OCTOPUSES")),
+            ("MiningEquipment", true, Some("MiningEquipment")),
+            ("octopi", true, None),
+        ] {
+            assert_eq!(
+                nouns.inflect(input, plural).as_deref(),
+                expected,
+                "Failed to test {}",
+                input
+            );
+        }
+
+        assert_eq!(Nouns::new().inflect("octopus", true), None);
+    }
+
+    #[test]
+    fn it_inflects_irregular_nouns() {
+        for (input, plural, expected) in [
+            ("person", true, Some("people")),
+            ("people", true, Some("people")),
+            ("People", false, Some("Person")),
+            ("user_child", true, Some("user_children")),
+            ("SalesPERSON", true, Some("SalesPEOPLE")),
+            ("data", false, Some("datum")),
+            ("mice", true, Some("mice")),
+            ("human", true, None),
+            ("status", true, None),
+        ] {
+            assert_eq!(
+                inflect_irregular(input, plural).as_deref(),
+                expected,
+                "Failed to test {}",
+                input
+            );
+        }
+    }
+}
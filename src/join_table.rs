@@ -0,0 +1,164 @@
+use handlebars::{
+    Context, Handlebars, Helper, HelperDef, HelperResult, Output, RenderContext, RenderErrorReason,
+};
+use inflector::Inflector;
+
+use crate::{ensure_plural, flag};
+
+#[derive(Clone, Copy)]
+/// Join table name helper for handlebars-rust
+///
+/// Produces Rails-style `has_and_belongs_to_many` join table names: both model names are turned
+/// into table names, sorted alphabetically and joined. Like ActiveRecord, a common prefix of the
+/// table names is only kept once, so `admin_users` and `admin_roles` give `admin_roles_users`.
+///
+/// # Registration
+///
+/// ```
+/// use handlebars::Handlebars;
+/// use handlebars_inflector::HandlebarsJoinTable;
+///
+/// let mut h = Handlebars::new();
+/// h.register_helper("join_table", Box::new(HandlebarsJoinTable));
+///
+/// assert_eq!(h.render_template(r#"{{join_table "User" "Product"}}"#, &()).expect("Render error"), "products_users");
+/// ```
+///
+/// # Arguments
+///
+/// * `model` - The first model name, in any case convention, singular or plural
+/// * `other` - The second model name
+///
+/// # Hash options
+///
+/// `separator`: separator between the two table names, defaults to `_`
+///
+/// `pluralize`: set to `false` to use the model names as they are, only converted to snake case
+///
+/// # Example usage:
+///
+/// `
+/// create_table :{{join_table model association separator="_to_"}}
+/// `
+///
+pub struct HandlebarsJoinTable;
+
+impl HelperDef for HandlebarsJoinTable {
+    fn call<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        r: &'reg Handlebars,
+        _ctx: &'rc Context,
+        _rc: &mut RenderContext<'reg, 'rc>,
+        out: &mut dyn Output,
+    ) -> HelperResult {
+        let mut models = Vec::with_capacity(2);
+
+        for index in 0..2 {
+            match h.param(index).and_then(|v| v.value().as_str()) {
+                Some(model) => models.push(model),
+                None => {
+                    if r.strict_mode() {
+                        return Err(
+                            RenderErrorReason::ParamNotFoundForIndex("join_table", index).into(),
+                        );
+                    }
+
+                    return Ok(());
+                }
+            }
+        }
+
+        let separator = h
+            .hash_get("separator")
+            .and_then(|v| v.value().as_str())
+            .unwrap_or("_");
+        let pluralize = h.hash_get("pluralize").is_none() || flag(h, "pluralize");
+
+        out.write(&join_table(models[0], models[1], separator, pluralize))?;
+
+        Ok(())
+    }
+}
+
+/// Join table name for two models.
+fn join_table(model: &str, other: &str, separator: &str, pluralize: bool) -> String {
+    let table = |model: &str| {
+        let snake = model.to_snake_case();
+
+        if pluralize {
+            ensure_plural(snake)
+        } else {
+            snake
+        }
+    };
+
+    let mut tables = [table(model), table(other)];
+    tables.sort();
+
+    let [first, second] = tables;
+    let prefix = common_prefix(&first, &second);
+
+    format!(
+        "{}{}{}{}",
+        prefix,
+        &first[prefix.len()..],
+        separator,
+        &second[prefix.len()..]
+    )
+}
+
+/// Longest common prefix ending in `_` that leaves a non-empty remainder of both names.
+fn common_prefix<'a>(first: &'a str, second: &str) -> &'a str {
+    first
+        .rmatch_indices('_')
+        .map(|(index, _)| &first[..=index])
+        .find(|prefix| {
+            second.starts_with(prefix) && first.len() > prefix.len() && second.len() > prefix.len()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use handlebars::Handlebars;
+
+    #[test]
+    fn it_works() {
+        let mut h = Handlebars::new();
+        h.register_helper("join_table", Box::new(HandlebarsJoinTable));
+
+        for (template, expected) in [
+            (r#"{{join_table "User" "Product"}}"#, "products_users"),
+            (r#"{{join_table "products" "users"}}"#, "products_users"),
+            (r#"{{join_table "Post" "Category"}}"#, "categories_posts"),
+            (
+                r#"{{join_table "ProductCategory" "Product"}}"#,
+                "product_categories_products",
+            ),
+            (
+                r#"{{join_table "AdminUser" "AdminRole"}}"#,
+                "admin_roles_users",
+            ),
+            (
+                r#"{{join_table "Tag" "Article" separator="__"}}"#,
+                "articles__tags",
+            ),
+            (r#"{{join_table "person" "Category"}}"#, "categories_people"),
+            (r#"{{join_table "Child" "Toy"}}"#, "children_toys"),
+            (r#"{{join_table "people" "Team"}}"#, "people_teams"),
+            (
+                r#"{{join_table "tag" "article" pluralize=false}}"#,
+                "article_tag",
+            ),
+        ] {
+            assert_eq!(
+                h.render_template(template, &()).expect("Render error"),
+                expected,
+                "Failed to test {}",
+                template
+            );
+        }
+    }
+}
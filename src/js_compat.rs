@@ -0,0 +1,226 @@
+use handlebars::{
+    Context, Handlebars, Helper, HelperDef, JsonValue, RenderContext, RenderError, ScopedJson,
+};
+
+use crate::case::upper_first;
+
+/// String helpers of the JavaScript `handlebars-helpers` package
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum JsHelper {
+    Lowercase,
+    Uppercase,
+    Capitalize,
+    CapitalizeAll,
+    Dashcase,
+    Dotcase,
+    Pathcase,
+    Sentence,
+}
+
+impl JsHelper {
+    const ALL: [JsHelper; 8] = [
+        JsHelper::Lowercase,
+        JsHelper::Uppercase,
+        JsHelper::Capitalize,
+        JsHelper::CapitalizeAll,
+        JsHelper::Dashcase,
+        JsHelper::Dotcase,
+        JsHelper::Pathcase,
+        JsHelper::Sentence,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            JsHelper::Lowercase => "lowercase",
+            JsHelper::Uppercase => "uppercase",
+            JsHelper::Capitalize => "capitalize",
+            JsHelper::CapitalizeAll => "capitalizeAll",
+            JsHelper::Dashcase => "dashcase",
+            JsHelper::Dotcase => "dotcase",
+            JsHelper::Pathcase => "pathcase",
+            JsHelper::Sentence => "sentence",
+        }
+    }
+
+    fn apply(self, input: &str) -> String {
+        match self {
+            JsHelper::Lowercase => input.to_lowercase(),
+            JsHelper::Uppercase => input.to_uppercase(),
+            JsHelper::Capitalize => upper_first(input),
+            JsHelper::CapitalizeAll => capitalize_all(input),
+            JsHelper::Dashcase => change_case(input, '-'),
+            JsHelper::Dotcase => change_case(input, '.'),
+            JsHelper::Pathcase => change_case(input, '/'),
+            JsHelper::Sentence => sentence(input),
+        }
+    }
+}
+
+impl HelperDef for JsHelper {
+    fn call_inner<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        _r: &'reg Handlebars,
+        _ctx: &'rc Context,
+        _rc: &mut RenderContext<'reg, 'rc>,
+    ) -> Result<ScopedJson<'rc>, RenderError> {
+        // Like the JavaScript helpers, anything but a string renders as an empty string
+        let output = h
+            .param(0)
+            .and_then(|v| v.value().as_str())
+            .map(|input| self.apply(input))
+            .unwrap_or_default();
+
+        Ok(ScopedJson::Derived(JsonValue::String(output)))
+    }
+}
+
+/// Registers the string helpers of the JavaScript `handlebars-helpers` package
+///
+/// Templates shared with a Node service can keep using `lowercase`, `uppercase`, `capitalize`,
+/// `capitalizeAll`, `dashcase`, `dotcase`, `pathcase` and `sentence`. The helpers reproduce the
+/// JavaScript behavior, quirks included: `dashcase` and friends lowercase the input before
+/// splitting it, so camelCase humps are not word boundaries, and only ASCII letters and digits
+/// count as word characters. Anything but a string renders as an empty string.
+///
+/// ```
+/// use handlebars::Handlebars;
+/// use handlebars_inflector::register_js_helpers;
+///
+/// let mut h = Handlebars::new();
+/// register_js_helpers(&mut h);
+///
+/// assert_eq!(h.render_template(r#"{{capitalizeAll "product images"}} {{dashcase "Product Images"}}"#, &()).expect("Render error"), "Product Images product-images");
+/// ```
+pub fn register_js_helpers(registry: &mut Handlebars) {
+    for helper in JsHelper::ALL {
+        registry.register_helper(helper.name(), Box::new(helper));
+    }
+}
+
+/// Whether `c` matches `\w` of JavaScript regular expressions
+fn is_word(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+/// Capitalizes every run of non-whitespace that starts with a word character
+fn capitalize_all(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+    let mut in_word = false;
+
+    for c in input.chars() {
+        if c.is_whitespace() {
+            in_word = false;
+            output.push(c);
+        } else if !in_word && is_word(c) {
+            in_word = true;
+            output.extend(c.to_uppercase());
+        } else {
+            output.push(c);
+        }
+    }
+
+    output
+}
+
+/// Lowercases `input`, trims separators and collapses inner separator runs into `separator`
+fn change_case(input: &str, separator: char) -> String {
+    if input.chars().count() == 1 {
+        return input.to_lowercase();
+    }
+
+    let is_separator = |c: char| !c.is_ascii_alphanumeric();
+    let lower = input.trim_matches(is_separator).to_lowercase();
+    let mut output = String::with_capacity(lower.len());
+    let mut pending = false;
+
+    for c in lower.chars() {
+        if is_separator(c) {
+            pending = true;
+        } else {
+            if pending {
+                output.push(separator);
+                pending = false;
+            }
+            output.push(c);
+        }
+    }
+
+    output
+}
+
+/// Capitalizes the first character of every sentence and lowercases the rest
+fn sentence(input: &str) -> String {
+    let is_end = |c: char| matches!(c, '.' | '?' | '!');
+    let mut output = String::with_capacity(input.len());
+    let mut chars = input.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c.is_whitespace() {
+            output.push(c);
+            continue;
+        }
+
+        output.extend(c.to_uppercase());
+
+        while let Some(c) = chars.next_if(|&c| !is_end(c)) {
+            output.extend(c.to_lowercase());
+        }
+        while let Some(c) = chars.next_if(|&c| is_end(c)) {
+            output.push(c);
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_matches_handlebars_helpers() {
+        let mut h = Handlebars::new();
+        register_js_helpers(&mut h);
+
+        let tests = [
+            (r#"{{lowercase "ABC Def"}}"#, "abc def"),
+            (r#"{{uppercase "abc Def"}}"#, "ABC DEF"),
+            (r#"{{capitalize "product images"}}"#, "Product images"),
+            (r#"{{capitalize "productImages"}}"#, "ProductImages"),
+            (
+                r#"{{capitalizeAll "product images (new)"}}"#,
+                "Product Images (New)",
+            ),
+            (r#"{{{capitalizeAll "it's a-b"}}}"#, "It's A-b"),
+            (r#"{{dashcase "a-b-c d_e"}}"#, "a-b-c-d-e"),
+            (r#"{{dashcase "--Product Images!"}}"#, "product-images"),
+            (r#"{{dashcase "productImages"}}"#, "productimages"),
+            (r#"{{dotcase "a-b-c d_e"}}"#, "a.b.c.d.e"),
+            (r#"{{pathcase "a-b-c d_e"}}"#, "a/b/c/d/e"),
+            (r#"{{dashcase "-"}}"#, "-"),
+            (
+                r#"{{sentence "hello WORLD. goodbye world! ok?"}}"#,
+                "Hello world. Goodbye world! Ok?",
+            ),
+            (r#"{{lowercase 42}}"#, ""),
+            (r#"{{capitalize}}"#, ""),
+        ];
+
+        for (template, expected) in tests {
+            assert_eq!(
+                h.render_template(template, &()).expect("Render error"),
+                expected,
+                "Failed to test {}",
+                template
+            );
+        }
+
+        assert_eq!(
+            h.render_template(r#"{{dashcase (capitalize "a b")}}"#, &())
+                .expect("Render error"),
+            "a-b",
+            "Failed to test subexpressions"
+        );
+    }
+}
@@ -0,0 +1,257 @@
+use std::borrow::Cow;
+
+use handlebars::{
+    Context, Handlebars, Helper, HelperDef, HelperResult, JsonValue, Output, RenderContext,
+    RenderError, RenderErrorReason, ScopedJson, StringOutput,
+};
+use serde_json::Map;
+
+use crate::{flag, HandlebarsInflector, Pipeline, PipelineError};
+
+#[derive(Clone, Default)]
+/// Object key helper for handlebars-rust
+///
+/// Returns a copy of an object with every key run through the `inflect` operations requested in
+/// the hash, e.g. to emit camelCase JSON from snake_case data. Used on its own it writes the
+/// result as JSON, as a subexpression it passes the object on. Items of an array input are
+/// converted one by one; other input is returned unchanged.
+///
+/// # Registration
+///
+/// ```
+/// use handlebars::Handlebars;
+/// use handlebars_inflector::HandlebarsInflectKeys;
+/// use serde_json::json;
+///
+/// let mut h = Handlebars::new();
+/// h.register_helper("inflect_keys", Box::new(HandlebarsInflectKeys));
+///
+/// assert_eq!(h.render_template(r#"{{inflect_keys this to_camel_case=true}}"#, &json!({ "user_id": 1 })).expect("Render error"), r#"{"userId":1}"#);
+/// ```
+///
+/// # Arguments
+///
+/// * `object` - An object, or an array of objects
+///
+/// # Hash options
+///
+/// `deep`: also convert the keys of nested objects, including objects in arrays  
+/// Any operation or option of the `inflect` helper
+///
+/// # Example usage:
+///
+/// `
+/// {{inflect_keys payload to_camel_case=true deep=true}}
+/// `
+///
+pub struct HandlebarsInflectKeys {
+    inflector: HandlebarsInflector,
+}
+
+/// The `inflect_keys` helper without any configuration, equal to [`HandlebarsInflectKeys::new`]
+#[allow(non_upper_case_globals)]
+pub const HandlebarsInflectKeys: HandlebarsInflectKeys = HandlebarsInflectKeys::new();
+
+impl HandlebarsInflectKeys {
+    /// Create a helper inflecting with the `inflect` helper without any configuration
+    pub const fn new() -> Self {
+        HandlebarsInflectKeys {
+            inflector: HandlebarsInflector::new(),
+        }
+    }
+
+    /// Inflect with a configured `inflect` helper instead of the default one
+    pub fn with_inflector(mut self, inflector: HandlebarsInflector) -> Self {
+        self.inflector = inflector;
+        self
+    }
+}
+
+/// Copy `value` with every object key replaced by `inflect(key)`, descending into nested values
+/// with `deep`. Items of an array are converted at the same depth as the array itself.
+pub(crate) fn map_keys<E, F>(value: &JsonValue, deep: bool, inflect: &mut F) -> Result<JsonValue, E>
+where
+    F: FnMut(&str) -> Result<String, E>,
+{
+    match value {
+        JsonValue::Object(object) => {
+            let mut converted = Map::with_capacity(object.len());
+
+            for (key, value) in object {
+                let value = if deep {
+                    map_keys(value, deep, inflect)?
+                } else {
+                    value.clone()
+                };
+
+                converted.insert(inflect(key)?, value);
+            }
+
+            Ok(JsonValue::Object(converted))
+        }
+        JsonValue::Array(items) => items
+            .iter()
+            .map(|item| map_keys(item, deep, inflect))
+            .collect::<Result<_, _>>()
+            .map(JsonValue::Array),
+        value => Ok(value.clone()),
+    }
+}
+
+/// Copy `value` with the keys of all objects in it, however deeply nested, run through
+/// `pipeline`.
+///
+/// ```
+/// use handlebars_inflector::{inflect_json_keys, Pipeline};
+/// use serde_json::json;
+///
+/// let pipeline = Pipeline::new().with_op("camel").expect("Unknown operation");
+/// let payload = json!({ "user_id": 1, "roles": [{ "role_name": "admin" }] });
+///
+/// assert_eq!(inflect_json_keys(payload, &pipeline).expect("Inflect error"), json!({ "userId": 1, "roles": [{ "roleName": "admin" }] }));
+/// ```
+pub fn inflect_json_keys(
+    value: JsonValue,
+    pipeline: &Pipeline,
+) -> Result<JsonValue, PipelineError> {
+    map_keys(&value, true, &mut |key| pipeline.apply(key))
+}
+
+impl HelperDef for HandlebarsInflectKeys {
+    fn call_inner<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        r: &'reg Handlebars,
+        _ctx: &'rc Context,
+        rc: &mut RenderContext<'reg, 'rc>,
+    ) -> Result<ScopedJson<'rc>, RenderError> {
+        let Some(value) = h.param(0) else {
+            if r.strict_mode() {
+                return Err(RenderErrorReason::ParamNotFoundForIndex("inflect_keys", 0).into());
+            }
+
+            return Ok(ScopedJson::Missing);
+        };
+
+        let deep = flag(h, "deep");
+
+        let converted = map_keys(value.value(), deep, &mut |key| {
+            let mut inflected = StringOutput::new();
+            self.inflector
+                .inflect_input(h, r, rc, Cow::Borrowed(key), &mut inflected)?;
+
+            Ok::<_, RenderError>(inflected.into_string().unwrap_or_default())
+        })?;
+
+        Ok(ScopedJson::Derived(converted))
+    }
+
+    fn call<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        r: &'reg Handlebars,
+        ctx: &'rc Context,
+        rc: &mut RenderContext<'reg, 'rc>,
+        out: &mut dyn Output,
+    ) -> HelperResult {
+        let converted = self.call_inner(h, r, ctx, rc)?;
+
+        if converted.is_missing() {
+            return Ok(());
+        }
+
+        let json = serde_json::to_string(converted.as_json())
+            .map_err(|e| RenderErrorReason::Other(e.to_string()))?;
+
+        out.write(&json)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn it_converts_json_keys() {
+        let pipeline = Pipeline::new().with_op("snake").expect("Unknown operation");
+
+        assert_eq!(
+            inflect_json_keys(
+                json!([{ "userId": 1, "Roles": [{ "roleName": "admin" }] }, "userId"]),
+                &pipeline
+            ),
+            Ok(json!([{ "user_id": 1, "roles": [{ "role_name": "admin" }] }, "userId"]))
+        );
+        assert!(inflect_json_keys(
+            json!({ "userId": 1 }),
+            &Pipeline::new()
+                .with_op("to=nope")
+                .expect("Unknown operation")
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn it_converts_keys() {
+        let mut h = Handlebars::new();
+        h.register_escape_fn(handlebars::no_escape);
+        h.register_helper("inflect_keys", Box::new(HandlebarsInflectKeys));
+
+        let data = json!({
+            "user": { "first_name": "Ada", "roles": [{ "role_id": 1 }] },
+            "users": [{ "last_name": "Lovelace" }],
+            "name": "plain",
+        });
+
+        for (template, expected) in [
+            (
+                r#"{{inflect_keys user to_camel_case=true}}"#,
+                r#"{"firstName":"Ada","roles":[{"role_id":1}]}"#,
+            ),
+            (
+                r#"{{inflect_keys user to_camel_case=true deep=true}}"#,
+                r#"{"firstName":"Ada","roles":[{"roleId":1}]}"#,
+            ),
+            (
+                r#"{{inflect_keys users to_kebab_case=true}}"#,
+                r#"[{"last-name":"Lovelace"}]"#,
+            ),
+            (
+                r#"{{lookup (inflect_keys user to_pascal_case=true) "FirstName"}}"#,
+                "Ada",
+            ),
+            (r#"{{inflect_keys name to_camel_case=true}}"#, r#""plain""#),
+        ] {
+            assert_eq!(
+                h.render_template(template, &data).expect("Render error"),
+                expected,
+                "Failed to test {}",
+                template
+            );
+        }
+    }
+
+    #[test]
+    fn it_errors_in_strict_mode() {
+        let mut h = Handlebars::new();
+        h.register_helper("inflect_keys", Box::new(HandlebarsInflectKeys));
+
+        assert_eq!(
+            h.render_template(r#"{{inflect_keys to_camel_case=true}}"#, &())
+                .expect("Render error"),
+            "",
+            "Failed to test lenient missing parameter"
+        );
+
+        h.set_strict_mode(true);
+
+        assert!(
+            h.render_template(r#"{{inflect_keys to_camel_case=true}}"#, &())
+                .is_err(),
+            "Failed to test strict missing parameter"
+        );
+    }
+}
@@ -1,10 +1,124 @@
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet};
+use std::str::FromStr;
+
 use handlebars::{
-    Context, Handlebars, Helper, HelperDef, HelperResult, JsonRender, Output, RenderContext,
-    RenderErrorReason,
+    Context, Handlebars, Helper, HelperDef, HelperResult, JsonRender, JsonValue, Output,
+    RenderContext, RenderError, RenderErrorReason, ScopedJson, StringOutput,
 };
 use inflector::Inflector;
 
-#[derive(Clone, Copy)]
+mod agreement;
+mod anchor;
+mod article;
+mod block;
+mod breadcrumbs;
+mod builder;
+mod case;
+mod case_spec;
+mod confusables;
+mod count;
+mod date_slug;
+mod db_names;
+mod dns;
+mod each;
+mod enum_variants;
+mod env_var;
+mod escape;
+mod ext;
+mod filename;
+#[cfg(feature = "fluent")]
+mod fluent;
+#[cfg(feature = "articles")]
+mod gender;
+mod gettext;
+mod identifier;
+mod irregular;
+mod join_table;
+mod js_compat;
+mod keys;
+#[cfg(feature = "i18n")]
+mod locale;
+mod numbers;
+mod op;
+#[cfg(feature = "phonetic")]
+mod phonetic;
+mod pipeline;
+mod plural;
+mod pluralize;
+mod polymorphic;
+#[cfg(feature = "python")]
+mod python;
+mod registration;
+mod reserved;
+mod rules;
+mod scope;
+mod sql;
+#[cfg(feature = "stemming")]
+mod stem;
+mod target;
+mod transliterate;
+#[cfg(feature = "wasm")]
+mod wasm;
+
+use article::indefinite_article;
+use block::{render_block, render_body, render_inverse};
+use case::{
+    initials, lower_first, split_words, swap_case, upper_first, Boundaries, Convention,
+    Punctuation, TitleStyle,
+};
+use confusables::to_ascii_skeleton;
+use count::{count_of, is_singular};
+use dns::{max_dns_length, to_dns_label, to_dns_name};
+use env_var::to_env_var;
+use escape::Escape;
+use filename::to_filename;
+use identifier::{is_rust_keyword, to_identifier, to_raw_identifier, LeadingDigit, Profile};
+use irregular::{inflect_irregular, match_case, Nouns};
+use numbers::{from_roman, number_to_words, ordinal_words, parse_integer, to_roman};
+#[cfg(feature = "phonetic")]
+use phonetic::{phonetic_key, Phonetic};
+use scope::{Occurrences, RenderScoped};
+use sql::{quote_identifier, Dialect};
+#[cfg(feature = "stemming")]
+use stem::{stem, StemLanguage};
+use target::{Kind, Target};
+use transliterate::{parameterize, transliterate, Scheme};
+
+pub use agreement::HandlebarsVerbAgreement;
+pub use anchor::HandlebarsHeadingAnchor;
+pub use breadcrumbs::HandlebarsBreadcrumbs;
+pub use builder::HandlebarsInflectorBuilder;
+pub use case::WordCase;
+pub use case_spec::{Acronyms, CaseSpec, Digits};
+pub use date_slug::HandlebarsDateSlug;
+pub use db_names::{HandlebarsConstraintName, HandlebarsIndexName};
+pub use each::HandlebarsInflectEach;
+pub use enum_variants::HandlebarsEnumVariants;
+pub use ext::HandlebarsInflectorExt;
+#[cfg(feature = "fluent")]
+pub use fluent::HandlebarsCldrPlural;
+#[cfg(feature = "articles")]
+pub use gender::{
+    Gender, GenderDictionary, GenderDictionaryError, HandlebarsGenderedArticle, Language,
+};
+pub use gettext::HandlebarsPluralForms;
+pub use identifier::HandlebarsIsIdentifier;
+pub use join_table::HandlebarsJoinTable;
+pub use js_compat::register_js_helpers;
+pub use keys::{inflect_json_keys, HandlebarsInflectKeys};
+#[cfg(feature = "i18n")]
+pub use locale::Locale;
+pub use op::{inflect_str, Op};
+pub use pipeline::{Pipeline, PipelineError};
+pub use plural::HandlebarsPluralSelect;
+pub use pluralize::HandlebarsPluralize;
+pub use polymorphic::HandlebarsPolymorphic;
+pub use registration::{OnConflict, Registration, RegistrationError};
+pub use reserved::{ReservedStrategy, ReservedWords};
+pub use rules::{Rules, RulesError};
+
+#[derive(Clone, Default)]
 /// Inflector helper for handlebars-rust
 ///
 /// # Registration
@@ -53,6 +167,27 @@ use inflector::Inflector;
 ///
 /// List of possible operations in the order of execution:
 ///
+/// `transliterate`: `北京大学` to `bei jing da xue` with `scheme="pinyin"`
+///
+/// `normalize_confusables`: `Ρаураl` (Greek and Cyrillic look-alikes) to `Paypal`
+///
+/// `normalize`: `Product-image_URL list` to `product image url list`
+///
+/// `abbreviate`: `CustomerIdentifier` to `CustomerId` with `identifier` registered by
+/// [`with_abbreviations`](HandlebarsInflector::with_abbreviations)
+///
+/// `from_roman`: `XIV` or `xiv` to `14`, anything but a canonical numeral is kept
+///
+/// `number_to_words`: `42` to `forty-two`, integers above the `words_max` hash option are kept
+///
+/// `ordinalize_words`: `23` or `23rd` to `twenty-third`, integers above `words_max` to `23rd`
+///
+/// `to_roman`: `14` to `XIV`, integers from 1 to 3999 only
+///
+/// `to`: `ABTest` to `ab_test` with `from="pascal" to="snake"`
+///
+/// `target`: `maxRetries` to `MAX_RETRIES` with `target="rust" kind="constant"`
+///
 /// `to_camel_case`: `product_images` to `productImages`
 ///
 /// `to_pascal_case`: `product_images` to `ProductImages`
@@ -61,334 +196,5627 @@ use inflector::Inflector;
 ///
 /// `to_screaming_snake_case`: `ProductImages` to `PRODUCT_IMAGES`
 ///
+/// `to_env_var`: `db.pool-size` to `MYAPP_DB_POOL_SIZE` with `env_prefix="myapp"`, characters not
+/// allowed in environment variable names replaced
+///
 /// `to_kebab_case`: `product_images` to `product-images`
 ///
 /// `to_train_case`: `product_images` to `Product-Images`
 ///
+/// `to_dot_case`: `serverMaxConnections` to `server.max.connections`
+///
+/// `to_path_case`: `ProductImages` to `product/images`
+///
 /// `to_sentence_case`: `product_images` to `Product images`
 ///
-/// `to_title_case`: `product_images` to `Product Images`
+/// `to_title_case`: `product_images` to `Product Images`; with `style="ap"` or
+/// `style="chicago"`, `the_lord_of_the_rings` to `The Lord of the Rings`
+///
+/// `humanize`: `employee_salary_id` to `Employee salary`
+///
+/// `parameterize`: `Crème Brûlée!` to `creme-brulee`, words joined with the `separator` hash option
+///
+/// `to_acronym`: `Portable Network Graphics` to `PNG`, or `P.N.G.` with `dotted=true`
 ///
 /// `ordinalize`: `July 1` to `July 1st`
 ///
 /// `deordinalize`: `July 1st` to `July 1`
 ///
-/// `to_foreign_key`: `Product image` to `product_image_id`
+/// `to_foreign_key`: `Product image` to `product_image_id`; `key_suffix="_uuid"` replaces the
+/// `_id` suffix and `key_case="camel"` writes `productImageId`
 ///
-/// `demodulize`: `std::io` to `Io`
+/// `demodulize`: `std::io` to `Io`, `com.acme.Invoice` to `Invoice` with
+/// `namespace_separator="."`
 ///
-/// `deconstantize`: `std::io` to `Std`
+/// `deconstantize`: `std::io` to `Std`, `App\Http\Kernel` to `Http` with
+/// `namespace_separator="\\"`
 ///
-/// `to_class_case`: `product_images` to `ProductImage`
+/// `to_class_case`: `product_images` to `ProductImage`, `app_invoices` to `Invoice` with
+/// `table_prefix="app_"`
 ///
-/// `to_table_case`: `ProductImage` to `product_images`
+/// `to_table_case`: `ProductImage` to `product_images`, `Invoice` to `app_invoices` with
+/// `table_prefix="app_"`
 ///
 /// `to_plural`: `ProductImage` to `ProductImages`
 ///
 /// `to_singular`: `product_images` to `product_image`
 ///
+/// `ensure_plural`: `status` to `statuses`, `statuses` stays `statuses`
+///
+/// `ensure_singular`: `statuses` to `status`, `status` stays `status`
+///
+/// `count`: `item` to `items` with `count=3`, `items` to `item` with `count=1`; arrays count
+/// their length
+///
+/// `agree_count`: `5 box` to `5 boxes`, `1 boxes` to `1 box`
+///
+/// `indefinite_article`: `hour` to `an hour`, `user` to `a user`, `API` to `an API`
+///
+/// `swap_case`: `hELLO wORLD` to `Hello World`
+///
+/// `capitalize`: `productImages` to `ProductImages`, the rest of the input left as it is
+///
+/// `decapitalize`: `ProductImages` to `productImages`, the rest of the input left as it is
+///
 /// `to_upper_case`: `product_images` to `PRODUCT_IMAGES`
 ///
 /// `to_lower_case`: `ProductImages` to `productimages`
 ///
-/// Note that some combinations might not be compatible with one another.
-/// If you still absolutely need to do such type of combination you could nest the operations like:
+/// `truncate_words`: `The quick brown fox` to `The quick...` with `max=14` or
+/// `truncate_words=14`, cutting between words; the `omission` hash option replaces `...`
 ///
-/// `Bars::Foos` to `Bar`
+/// `to_identifier`: `2fa enabled` to `_2fa_enabled` with `profile="rust"`
+///
+/// `to_raw_identifier`: `type` to `r#type`, `self` to `self_`, other names left as they are
+///
+/// `quote_identifier`: `user` to `"user"`, `product_images` left as it is; the `dialect` hash
+/// option selects `postgres`, `mysql` or `mssql` quoting
+///
+/// `to_filename`: `Reports/Q1: "Sales"` to `Reports_Q1_ _Sales`, dropping control characters and
+/// trimming edge dots and spaces; `max_length` cuts the name, `keep_extension=true` keeps the
+/// extension when cutting
+///
+/// `to_dns_label`: `Product Images` to `product-images`, a lowercase RFC 1123 label cut to 63
+/// characters; `subdomain=true` keeps dots between labels and cuts at 253 characters, `max_length`
+/// cuts shorter
+///
+/// # Punctuation
+///
+/// The `apostrophes`, `periods` and `hyphens` hash options control whether these characters act
+/// as word boundaries:
+///
+/// `split`: a word boundary (default)
+///
+/// `keep`: part of the word when between two letters or digits, so `v1.2` stays intact
+///
+/// `strip`: removed before any other operation, so `don't stop` becomes `dont_stop`
+///
+/// Stripping applies to every operation, keeping affects the word splitting of `normalize`, `to`
+/// and the case operations like `to_snake_case`:
 ///
 /// `
-/// {{inflect (inflect param deconstantize=true) to_singular=true}}
+/// {{inflect param apostrophes="strip" periods="keep" to="snake"}}
 /// `
 ///
-pub struct HandlebarsInflector;
-
-impl HelperDef for HandlebarsInflector {
-    fn call<'reg: 'rc, 'rc>(
-        &self,
-        h: &Helper<'rc>,
-        r: &'reg Handlebars,
-        _ctx: &'rc Context,
-        _rc: &mut RenderContext<'reg, 'rc>,
-        out: &mut dyn Output,
-    ) -> HelperResult {
-        let input = if let Some(input) = h.param(0) {
-            input
-        } else {
-            if r.strict_mode() {
-                return Err(RenderErrorReason::ParamNotFoundForIndex("inflect", 0).into());
-            }
-
-            return Ok(());
-        };
-
-        if !input.value().is_string() {
-            if r.strict_mode() {
-                return Err(RenderErrorReason::ParamTypeMismatchForName(
-                    "inflect",
-                    "0".to_string(),
-                    "string".to_string(),
-                )
-                .into());
-            }
-
-            return Ok(());
-        }
-
-        let mut output = input.value().render();
-
-        if h.hash_get("to_camel_case").is_some() {
-            output = output.to_camel_case();
-        }
-
-        if h.hash_get("to_pascal_case").is_some() {
-            output = output.to_pascal_case();
-        }
-
-        if h.hash_get("to_snake_case").is_some() {
-            output = output.to_snake_case();
-        }
-
-        if h.hash_get("to_screaming_snake_case").is_some() {
-            output = output.to_screaming_snake_case();
-        }
-
-        if h.hash_get("to_kebab_case").is_some() {
-            output = output.to_kebab_case();
-        }
-
-        if h.hash_get("to_train_case").is_some() {
-            output = output.to_train_case();
-        }
-
-        if h.hash_get("to_sentence_case").is_some() {
-            output = output.to_sentence_case();
-        }
-
-        if h.hash_get("to_title_case").is_some() {
-            output = output.to_title_case();
-        }
-
-        if h.hash_get("ordinalize").is_some() {
-            output = output.ordinalize();
-        }
-
-        if h.hash_get("deordinalize").is_some() {
-            output = output.deordinalize();
-        }
-
-        if h.hash_get("to_foreign_key").is_some() {
-            output = output.to_foreign_key();
-        }
-
-        if h.hash_get("demodulize").is_some() {
-            output = output.demodulize();
-        }
-
-        if h.hash_get("deconstantize").is_some() {
-            output = output.deconstantize();
-        }
-
-        if h.hash_get("to_class_case").is_some() {
-            output = output.to_class_case();
-        }
-
-        if h.hash_get("to_table_case").is_some() {
-            output = output.to_table_case();
-        }
-
-        if h.hash_get("to_plural").is_some() {
-            output = output.to_plural();
-        }
-
-        if h.hash_get("to_singular").is_some() {
-            output = output.to_singular();
-        }
-
-        if h.hash_get("to_upper_case").is_some() {
-            output = output.to_uppercase();
-        }
-
-        if h.hash_get("to_lower_case").is_some() {
-            output = output.to_lowercase();
-        }
-
-        out.write(&output)?;
-
-        Ok(())
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use handlebars::Handlebars;
-
-    #[test]
-    fn it_works() {
-        let mut h = Handlebars::new();
-        h.register_helper("inflect", Box::new(HandlebarsInflector));
-
-        assert_eq!(
-            h.render_template(
-                r#"{{inflect this to_camel_case=true}}"#,
-                &String::from("this is a test")
-            )
-            .expect("Render error"),
-            "thisIsATest",
-            "Failed to test to_camel_case"
-        );
-        assert_eq!(
-            h.render_template(
-                r#"{{inflect this to_pascal_case=true}}"#,
-                &String::from("product_images")
-            )
-            .expect("Render error"),
-            "ProductImages",
-            "Failed to test to_pascal_case"
-        );
-        assert_eq!(
-            h.render_template(
-                r#"{{inflect this to_snake_case=true}}"#,
-                &String::from("ProductImages")
-            )
-            .expect("Render error"),
-            "product_images",
-            "Failed to test to_snake_case"
-        );
-        assert_eq!(
-            h.render_template(
-                r#"{{inflect this to_screaming_snake_case=true}}"#,
-                &String::from("ProductImages")
-            )
-            .expect("Render error"),
-            "PRODUCT_IMAGES",
-            "Failed to test to_screaming_snake_case"
-        );
-        assert_eq!(
-            h.render_template(
-                r#"{{inflect this to_kebab_case=true}}"#,
-                &String::from("product_images")
-            )
-            .expect("Render error"),
-            "product-images",
-            "Failed to test to_kebab_case"
-        );
-        assert_eq!(
-            h.render_template(
-                r#"{{inflect this to_sentence_case=true}}"#,
-                &String::from("product_images")
-            )
-            .expect("Render error"),
-            "Product images",
-            "Failed to test to_sentence_case"
-        );
-        assert_eq!(
-            h.render_template(
-                r#"{{inflect this to_title_case=true}}"#,
-                &String::from("product_images")
-            )
-            .expect("Render error"),
-            "Product Images",
-            "Failed to test to_title_case"
-        );
-        assert_eq!(
-            h.render_template(
-                r#"{{inflect this ordinalize=true}}"#,
-                &String::from("July 1")
-            )
-            .expect("Render error"),
-            "July 1st",
-            "Failed to test ordinalize"
-        );
-        assert_eq!(
-            h.render_template(
-                r#"{{inflect this deordinalize=true}}"#,
-                &String::from("July 1st")
-            )
-            .expect("Render error"),
-            "July 1",
-            "Failed to test deordinalize"
-        );
-        assert_eq!(
-            h.render_template(
-                r#"{{inflect this to_foreign_key=true}}"#,
-                &String::from("ProductImage")
-            )
-            .expect("Render error"),
-            "product_image_id",
-            "Failed to test to_foreign_key"
-        );
-        assert_eq!(
-            h.render_template(
-                r#"{{inflect this demodulize=true}}"#,
-                &String::from("Foo::Bar")
-            )
-            .expect("Render error"),
-            "Bar",
-            "Failed to test demodulize"
-        );
-        assert_eq!(
-            h.render_template(
-                r#"{{inflect this deconstantize=true}}"#,
-                &String::from("Foo::Bar")
-            )
-            .expect("Render error"),
-            "Foo",
-            "Failed to test deconstantize"
-        );
-        assert_eq!(
-            h.render_template(
-                r#"{{inflect this to_class_case=true}}"#,
-                &String::from("product_images")
-            )
-            .expect("Render error"),
-            "ProductImage",
-            "Failed to test to_class_case"
-        );
-        assert_eq!(
-            h.render_template(
-                r#"{{inflect this to_table_case=true}}"#,
-                &String::from("ProductImage")
-            )
-            .expect("Render error"),
-            "product_images",
-            "Failed to test to_table_case"
-        );
-        assert_eq!(
-            h.render_template(
-                r#"{{inflect this to_plural=true}}"#,
+/// With `hyphens="keep"`, title case keeps hyphenated compounds intact and capitalizes their
+/// parts, keeping minor words like `of` and `the` lowercase. `to="title"` does the same when
+/// converting `from="sentence"`. Otherwise `to_title_case` splits at hyphens like at any other
+/// word boundary, so `state-of-the-art` becomes `State Of The Art`:
+///
+/// `State-of-the-Art Design`:
+///
+/// `
+/// {{inflect "state-of-the-art design" hyphens="keep" to_title_case=true}}
+/// `
+///
+/// # Digits
+///
+/// Whether digits start a new word differs between operations. The `digits` hash option makes it
+/// a project convention for the case operations, `to` and `normalize`:
+///
+/// `attach`: digits stay with the preceding letters, `OAuth2Token` to `o_auth2_token`
+///
+/// `separate`: every run of digits is a word of its own, `OAuth2Token` to `o_auth_2_token`
+///
+/// `
+/// {{inflect "address1" digits="separate" to_snake_case=true}}
+/// `
+///
+/// # Explicit conversions
+///
+/// The `to` hash option converts between case conventions with a dedicated word splitter:
+/// `camel`, `pascal`, `snake`, `screaming_snake`, `kebab`, `train`, `sentence`, `title`, `dot`
+/// and `path`. Declaring the source convention with `from` makes the split unambiguous: separated
+/// conventions only split on their separator, camel and pascal case only on case humps, so
+/// digits and acronyms never introduce guessed boundaries. Without `from` any non-alphanumeric
+/// character and case hump starts a new word.
+///
+/// `v2_id`:
+///
+/// `
+/// {{inflect param from="camel" to="snake"}}
+/// `
+///
+/// Conventions of your own are defined as a [`CaseSpec`] and registered under a name with
+/// [`with_case`](HandlebarsInflector::with_case), making them available to `to` as well.
+///
+/// # Target languages
+///
+/// The `target` hash option applies the standard naming convention of a language to the `kind`
+/// of name, so code generators for several languages don't spell out conventions per template.
+/// Targets are `rust`, `go`, `java`, `python`, `csharp`, `graphql` and `protobuf`, kinds are
+/// `type`, `function`, `variable` (or `field`), `constant` and `enum_value`. Go names are written
+/// in their exported form. Protobuf enum values share the namespace of their package, so with
+/// `enum_name` they are prefixed with the name of their enum. `from` declares the source
+/// convention like for `to`:
+///
+/// `MAX_RETRY_COUNT`:
+///
+/// `
+/// {{inflect "maxRetryCount" target="rust" kind="constant"}}
+/// `
+///
+/// Go names keep the initialisms listed by `golint` (`ID`, `HTTP`, `URL`, ...) in a consistent
+/// case, on top of the acronyms registered with
+/// [`with_acronyms`](HandlebarsInflector::with_acronyms). Since visibility is part of a Go name,
+/// `exported=true` writes the exported `PascalCase` form and `exported=false` the unexported
+/// `camelCase` form of any kind of name:
+///
+/// `ServeHTTP userID`:
+///
+/// `
+/// {{inflect "serve_http" target="go" exported=true}} {{inflect "user_id" target="go" exported=false}}
+/// `
+///
+/// # Normalization
+///
+/// Real-world input often mixes spaces, underscores, hyphens and camel humps. The `normalize`
+/// operation runs before any case operation and rewrites the input into a canonical sequence of
+/// lowercase words separated by single spaces, so every case operation sees the same words:
+///
+/// `product_image_url`:
+///
+/// `
+/// {{inflect param normalize=true to_snake_case=true}}
+/// `
+///
+/// # Stemming
+///
+/// With the `stemming` feature the `stem` operation reduces every word to its Snowball stem
+/// for search and index keys. It runs after `normalize` and before the case operations. The
+/// `stemmer` hash option selects the language by name or ISO 639-1 code, English by default:
+///
+/// `run_shoe`:
+///
+/// `
+/// {{inflect "Running Shoes" stem=true to_snake_case=true}}
+/// `
+///
+/// # Phonetic keys
+///
+/// With the `phonetic` feature the `phonetic` hash option encodes every word with a phonetic
+/// algorithm for data matching and deduplication: `soundex`, `metaphone`, `double_metaphone`
+/// or `double_metaphone_alternate`. Codes are separated by a space and the option runs right
+/// after `stem`:
+///
+/// `R163 S530`:
+///
+/// `
+/// {{inflect "Robert Smith" phonetic="soundex"}}
+/// `
+///
+/// # Leading and trailing underscores
+///
+/// Case operations drop leading and trailing underscores. With `preserve_edges=true` the
+/// underscores of the input are put back after the case operations, keeping private-field
+/// markers:
+///
+/// `_internalField`:
+///
+/// `
+/// {{inflect "_internal_field" to_camel_case=true preserve_edges=true}}
+/// `
+///
+/// # Skipping conformant input
+///
+/// With `skip_if_matching=true` the input is written untouched when it already is in the last
+/// case convention requested, so pre-normalized data is never transformed twice:
+///
+/// `
+/// {{inflect param to_camel_case=true skip_if_matching=true}}
+/// `
+///
+/// # Identifiers
+///
+/// The `to_identifier` operation coerces the result into a valid identifier following
+/// [UAX #31](https://www.unicode.org/reports/tr31/). Runs of invalid characters become a single
+/// `_`. The `profile` hash option selects language specific rules: `default` (plain UAX #31),
+/// `rust`, `python`, `go` or `javascript`. To only check whether a string is a valid identifier
+/// use [`HandlebarsIsIdentifier`].
+///
+/// The `to_raw_identifier` operation escapes Rust keywords as raw identifiers, so generated
+/// fields and functions compile whatever they are named: `type` becomes `r#type`. `crate`,
+/// `self`, `Self` and `super` can't be raw identifiers and get a `_` appended instead.
+///
+/// `r#type: String`:
+///
+/// `
+/// {{inflect "Type" to_snake_case=true to_raw_identifier=true}}: String
+/// `
+///
+/// The `quote_identifier` operation quotes SQL identifiers, but only when they need it: reserved
+/// words and names with characters an unquoted identifier can't contain. The `dialect` hash
+/// option selects `postgres` (`"user"`, the default, also quoting uppercase letters PostgreSQL
+/// would fold), `mysql` (`` `user` ``) or `mssql` (`[user]`). Helper output isn't HTML escaped,
+/// so the quotes come out as they are:
+///
+/// `"user"`:
+///
+/// `
+/// {{inflect "User" to_snake_case=true quote_identifier=true}}
+/// `
+///
+/// The `leading_digit` hash option selects what happens to a result starting with a digit:
+/// `underscore` prefixes `_` (for profiles allowing it), `prefix` prefixes the word given in
+/// `digit_prefix` (`n` by default), `spell` spells the leading digits out and `error` fails in
+/// strict mode. Otherwise the profile decides, prefixing `_` where allowed and `n` elsewhere, so
+/// no digits are lost.
+///
+/// `two_fa_enabled`:
+///
+/// `
+/// {{inflect "2fa enabled" to_snake_case=true to_identifier=true leading_digit="spell"}}
+/// `
+///
+/// # Unique names
+///
+/// With `unique=true` every name the helper writes during a render is recorded, and a name
+/// that was already written gets the lowest free `_2`, `_3`, ... suffix. In strict mode a
+/// duplicate is an error instead. Setting `unique` to a string tracks names in a separate group,
+/// e.g. one per generated struct:
+///
+/// `
+/// {{#each fields}}{{inflect name to_snake_case=true unique=../name}}: String,{{/each}}
+/// `
+///
+/// # Escaping
+///
+/// The `escape` hash option escapes the final output for the format it is embedded in. With
+/// `escape="markdown"` the characters that open emphasis, code spans, links, tables and
+/// strikethrough (`\`, `` ` ``, `*`, `_`, `[`, `]`, `<`, `>`, `|`, `~`) are backslash-escaped,
+/// so inflected names never break generated Markdown:
+///
+/// `product\_image\_url`:
+///
+/// `
+/// | {{inflect param to_snake_case=true escape="markdown"}} |
+/// `
+///
+/// # Transliteration
+///
+/// The `transliterate` operation converts text to latin script using the scheme given in the
+/// `scheme` hash option. Schemes are available behind optional features:
+///
+/// `pinyin`: Chinese to plain Hanyu Pinyin, one word per syllable
+///
+/// `romaji`: Japanese hiragana and katakana to Hepburn romaji (kanji are left untouched)
+///
+/// `
+/// {{inflect title transliterate=true scheme="pinyin" to_kebab_case=true}}
+/// `
+///
+/// # Subexpressions
+///
+/// As a subexpression the helper always returns a string, so results like `1` or `true` are
+/// never mistaken for numbers or booleans by the helpers receiving them:
+///
+/// `
+/// {{#if (eq (inflect name to_snake_case=true) "user_id")}}...{{/if}}
+/// `
+///
+/// # Block form
+///
+/// Used as a block helper, the block is rendered with the inflected result as its context, and
+/// the `{{else}}` block is rendered instead when the input is null or not a string and isn't
+/// coerced, even in strict mode:
+///
+/// `
+/// {{#inflect name to_title_case=true}}<h1>{{this}}</h1>{{else}}<h1>Untitled</h1>{{/inflect}}
+/// `
+///
+/// Without an input parameter the block is rendered first and its output is inflected, so the
+/// output of partials and other helpers can be converted:
+///
+/// `
+/// {{#inflect to_kebab_case=true}}{{> page_title}}{{/inflect}}
+/// `
+///
+/// # Reserved names
+///
+/// Names registered with [`with_reserved`](HandlebarsInflector::with_reserved) are never
+/// written: a result equal to one gets the suffix set with
+/// [`with_reserved_suffix`](HandlebarsInflector::with_reserved_suffix) (`_` by default)
+/// appended, or fails the render in strict mode.
+///
+/// ```
+/// use handlebars::Handlebars;
+/// use handlebars_inflector::HandlebarsInflector;
+///
+/// let mut h = Handlebars::new();
+/// h.register_helper("inflect", Box::new(HandlebarsInflector::new().with_reserved(["type", "self"])));
+///
+/// assert_eq!(h.render_template(r#"{{inflect this to_snake_case=true}}"#, &String::from("Type")).expect("Render error"), "type_");
+/// ```
+///
+/// Reserved words that depend on the target language are registered as named
+/// [`ReservedWords`] lists with [`with_reserved_words`](HandlebarsInflector::with_reserved_words)
+/// and selected with the `reserved` hash option. A result found in the selected list is escaped
+/// with the [`ReservedStrategy`] of the list: a prefix, a suffix, quotes, or an error in strict
+/// mode. Unknown list names fail the render in strict mode.
+///
+/// `"order"`:
+///
+/// `
+/// {{inflect "Order" to_snake_case=true reserved="sql"}}
+/// `
+///
+/// # Strictness
+///
+/// By default the helper reports missing or invalid input and options as errors only when the
+/// registry is in strict mode. [`with_strictness`](HandlebarsInflector::with_strictness)
+/// decouples it from the registry, e.g. to get strict inflection errors in a registry that must
+/// stay lenient for other helpers:
+///
+/// ```
+/// use handlebars::Handlebars;
+/// use handlebars_inflector::{HandlebarsInflector, Strictness};
+///
+/// let mut h = Handlebars::new();
+/// h.register_helper("inflect", Box::new(HandlebarsInflector::new().with_strictness(Strictness::Always)));
+///
+/// assert!(h.render_template(r#"{{inflect 42}}"#, &()).is_err());
+/// ```
+///
+/// # Overrides
+///
+/// Hand-curated exceptions registered with
+/// [`with_override`](HandlebarsInflector::with_override) always win over heuristics: an input
+/// matching a source name exactly is written as its target without running any operation.
+/// Overrides apply whatever operations a template requests, so register them on a helper
+/// dedicated to one convention:
+///
+/// ```
+/// use handlebars::Handlebars;
+/// use handlebars_inflector::HandlebarsInflector;
+///
+/// let mut h = Handlebars::new();
+/// h.register_helper("type_name", Box::new(HandlebarsInflector::new().with_override("oauth2_token", "OAuth2Token")));
+///
+/// assert_eq!(h.render_template(r#"{{type_name this to_pascal_case=true}}"#, &String::from("oauth2_token")).expect("Render error"), "OAuth2Token");
+/// ```
+///
+/// # Irregular and uncountable nouns
+///
+/// Domain words the built-in rules get wrong are registered as `(singular, plural)` pairs with
+/// [`with_rules`](HandlebarsInflector::with_rules). They win over the built-in rules whenever the
+/// last word of the input is one of their forms, in `to_plural`, `to_singular`, `to_class_case`,
+/// `to_table_case`, `ensure_plural`, `ensure_singular` and `count`. Nouns without a plural form
+/// registered with [`with_uncountable`](HandlebarsInflector::with_uncountable) are left untouched
+/// by these operations:
+///
+/// ```
+/// use handlebars::Handlebars;
+/// use handlebars_inflector::HandlebarsInflector;
+///
+/// let mut h = Handlebars::new();
+/// h.register_helper("inflect", Box::new(HandlebarsInflector::new().with_rules([("octopus", "octopuses")]).with_uncountable(["equipment"])));
+///
+/// assert_eq!(h.render_template(r#"{{inflect this to_table_case=true}}"#, &String::from("SeaOctopus")).expect("Render error"), "sea_octopuses");
+/// assert_eq!(h.render_template(r#"{{inflect this to_table_case=true}}"#, &String::from("MiningEquipment")).expect("Render error"), "mining_equipment");
+/// ```
+///
+/// # Locales
+///
+/// With the `i18n` feature, `to_plural`, `to_singular`, `ensure_plural`, `ensure_singular` and
+/// `count` follow the regular noun rules of Spanish or German instead of English. The language is
+/// chosen with [`with_locale`](HandlebarsInflector::with_locale) or per call with the `locale`
+/// hash option (`es`, `de`, `en`, region subtags ignored). The rules cover regular endings only,
+/// so irregular nouns should be registered with `with_rules`:
+///
+/// `canciones`:
+///
+/// `
+/// {{inflect "canción" to_plural=true locale="es"}}
+/// `
+///
+/// # Acronyms
+///
+/// Acronyms registered with [`with_acronyms`](HandlebarsInflector::with_acronyms) stay in
+/// uppercase wherever a word is capitalized, and words are split on case humps around them, so
+/// `to_pascal_case` turns `api_key` into `APIKey` and `to_snake_case` turns `HTTPServer` into
+/// `http_server`. They apply to `to_camel_case`, `to_pascal_case`, `to_snake_case`, `to`,
+/// `humanize` and `to_title_case` with `hyphens="keep"`:
+///
+/// ```
+/// use handlebars::Handlebars;
+/// use handlebars_inflector::HandlebarsInflector;
+///
+/// let mut h = Handlebars::new();
+/// h.register_helper("inflect", Box::new(HandlebarsInflector::new().with_acronyms(["API", "ID"])));
+///
+/// assert_eq!(h.render_template(r#"{{inflect this to_pascal_case=true}}"#, &String::from("api_user_id")).expect("Render error"), "APIUserID");
+/// ```
+///
+/// # Abbreviations
+///
+/// The `abbreviate` operation replaces words registered with
+/// [`with_abbreviations`](HandlebarsInflector::with_abbreviations) by their short form, keeping
+/// separators and the case of the word, which makes short column and variable names out of
+/// verbose business terms:
+///
+/// ```
+/// use handlebars::Handlebars;
+/// use handlebars_inflector::HandlebarsInflector;
+///
+/// let mut h = Handlebars::new();
+/// h.register_helper("inflect", Box::new(HandlebarsInflector::new().with_abbreviations([("identifier", "id"), ("number", "num")])));
+///
+/// assert_eq!(h.render_template(r#"{{inflect this abbreviate=true to_snake_case=true}}"#, &String::from("Order Number Identifier")).expect("Render error"), "order_num_id");
+/// ```
+///
+/// # Sharing rules
+///
+/// Reserved names, overrides, custom nouns, acronyms and abbreviations added at runtime can be captured with
+/// [`rules`](HandlebarsInflector::rules), written to a TOML or YAML file with [`Rules::save`]
+/// (behind the `toml` and `yaml` features), committed, and loaded into another helper with
+/// [`Rules::load`] and [`with_rule_set`](HandlebarsInflector::with_rule_set), keeping services
+/// and build-time generators in sync.
+///
+/// # Arrays
+///
+/// An array input has every item inflected on its own, joined with the `join` hash option or
+/// `, `. Items other than strings are skipped, or fail the render in strict mode:
+///
+/// `id, first_name, created_at`:
+///
+/// `
+/// {{inflect columns to_snake_case=true}}
+/// `
+///
+/// # Defaults
+///
+/// The `default` hash option replaces a missing, null or empty parameter, and goes through the
+/// requested operations like any other input:
+///
+/// `Untitled`:
+///
+/// `
+/// {{inflect title default="untitled" to_pascal_case=true}}
+/// `
+///
+/// # Prefixes and suffixes
+///
+/// The `strip_prefix` and `strip_suffix` hash options remove a literal from the input before any
+/// operation runs, when the input starts or ends with it. The `prefix` and `suffix` hash options
+/// are added to the result once all operations ran, and only when the result isn't empty:
+///
+/// `users_controller`:
+///
+/// `
+/// {{inflect "User" to_snake_case=true to_plural=true suffix="_controller"}}
+/// `
+///
+/// `ProductImages`:
+///
+/// `
+/// {{inflect "tbl_product_image" strip_prefix="tbl_" to_pascal_case=true to_plural=true}}
+/// `
+///
+/// Legacy databases prefix every table or view with one of several markers. Prefixes registered
+/// with [`with_vendor_prefixes`](HandlebarsInflector::with_vendor_prefixes) are removed, ignoring
+/// case, right before `to_class_case`, `to_table_case`, `to_singular` or `ensure_singular` runs.
+/// The `vendor_prefixes` hash option replaces them with a `|` separated list:
+///
+/// ```
+/// use handlebars::Handlebars;
+/// use handlebars_inflector::HandlebarsInflector;
+///
+/// let mut h = Handlebars::new();
+/// h.register_helper("inflect", Box::new(HandlebarsInflector::new().with_vendor_prefixes(["tbl_", "vw_", "sp_"])));
+///
+/// assert_eq!(h.render_template(r#"{{inflect this to_class_case=true}}"#, &String::from("tbl_users")).expect("Render error"), "User");
+/// ```
+///
+/// # Selecting a word
+///
+/// The `word` hash option keeps a single word of the input before any operation runs, counting
+/// from 0, or from the end with negative indices. Out of range indices render nothing and skip
+/// the operations, or fail the render in strict mode:
+///
+/// `tokens`:
+///
+/// `
+/// {{inflect "user access token" word=-1 to_plural=true}}
+/// `
+///
+/// # Path segments
+///
+/// With `segments=true` the input is split on `/` and the operations run on every segment on
+/// its own, so the structure of a path survives case conversions. A string value selects another
+/// separator:
+///
+/// `api/productImages/byId`:
+///
+/// `
+/// {{inflect "api/product_images/by_id" to_camel_case=true segments=true}}
+/// `
+///
+/// # Rails aliases
+///
+/// The ActiveSupport names `underscore`, `dasherize`, `camelize`, `classify`, `tableize`,
+/// `titleize`, `foreign_key`, `pluralize` and `singularize` are accepted as flags and in `ops`
+/// for `to_snake_case`, `to_kebab_case`, `to_pascal_case`, `to_class_case`, `to_table_case`,
+/// `to_title_case`, `to_foreign_key`, `to_plural` and `to_singular`:
+///
+/// `product_images`:
+///
+/// `
+/// {{inflect param underscore=true pluralize=true}}
+/// `
+///
+/// # Coercion
+///
+/// Input other than a string renders nothing, or fails the render in strict mode. With the
+/// `coerce` hash option, or [`with_coercion`](HandlebarsInflector::with_coercion) for every call,
+/// numbers and booleans are converted to strings first and null to an empty string. Numbers are
+/// always accepted by `ordinalize`, `number_to_words`, `ordinalize_words` and `to_roman`:
+///
+/// `1st`:
+///
+/// `
+/// {{inflect 1 ordinalize=true coerce=true}}
+/// `
+///
+/// # Ordered operations
+///
+/// The `ops` hash option lists operations separated by `|` and applies them in the order
+/// written instead of the order above. Boolean flags in the hash are ignored then, other
+/// options like `scheme` or `profile` still apply. Value operations take their value inline or
+/// from the hash. Unknown operations are skipped, or fail the render in strict mode:
+///
+/// `ProductImage`:
+///
+/// `
+/// {{inflect param ops="to_singular|to_pascal_case"}}
+/// `
+///
+/// `product-images`:
+///
+/// `
+/// {{inflect param ops="to_plural|to=kebab"}}
+/// `
+///
+/// Flags undoing each other, like `to_upper_case` and `to_lower_case` or `to_plural` and
+/// `to_singular`, fail the render in strict mode and log a warning otherwise.
+///
+/// The order of boolean flags in the hash can't be used instead: handlebars passes hash options
+/// to helpers sorted by name, so the order they were written in is lost before the helper runs.
+///
+/// Combinations repeated across templates can be registered once with
+/// [`with_pipeline`](HandlebarsInflector::with_pipeline) and referred to by name with the
+/// `pipeline` hash option. Unknown names fall back to the boolean flags, or fail the render in
+/// strict mode:
+///
+/// ```
+/// use handlebars::Handlebars;
+/// use handlebars_inflector::HandlebarsInflector;
+///
+/// let mut h = Handlebars::new();
+/// h.register_helper("inflect", Box::new(HandlebarsInflector::new().with_pipeline("table_name", "to_snake_case|to_plural")));
+///
+/// assert_eq!(h.render_template(r#"{{inflect this pipeline="table_name"}}"#, &String::from("ProductImage")).expect("Render error"), "product_images");
+/// ```
+///
+/// Note that some combinations might not be compatible with one another.
+/// If you still absolutely need to do such type of combination you could nest the operations like:
+///
+/// `Bars::Foos` to `Bar`
+///
+/// `
+/// {{inflect (inflect param deconstantize=true) to_singular=true}}
+/// `
+///
+pub struct HandlebarsInflector {
+    reserved: BTreeSet<String>,
+    reserved_suffix: Option<String>,
+    reserved_words: BTreeMap<String, ReservedWords>,
+    overrides: BTreeMap<String, String>,
+    nouns: Nouns,
+    acronyms: BTreeSet<String>,
+    abbreviations: BTreeMap<String, String>,
+    table_prefix: Option<String>,
+    vendor_prefixes: Vec<String>,
+    namespace_separator: Option<String>,
+    cases: BTreeMap<String, CaseSpec>,
+    default_ops: Option<String>,
+    coerce: bool,
+    pipelines: BTreeMap<String, String>,
+    #[cfg(feature = "i18n")]
+    locale: Locale,
+    strictness: Strictness,
+}
+
+/// When the `inflect` helper reports invalid input and options as errors
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Strictness {
+    /// Follow `Handlebars::strict_mode` of the registry
+    #[default]
+    Registry,
+    /// Always fail the render, even in a lenient registry
+    Always,
+    /// Never fail the render, even in a strict registry
+    Never,
+}
+
+impl Strictness {
+    fn is_strict(self, registry: &Handlebars) -> bool {
+        match self {
+            Strictness::Registry => registry.strict_mode(),
+            Strictness::Always => true,
+            Strictness::Never => false,
+        }
+    }
+}
+
+/// The `inflect` helper without any configuration, equal to [`HandlebarsInflector::new`]
+#[allow(non_upper_case_globals)]
+pub const HandlebarsInflector: HandlebarsInflector = HandlebarsInflector::new();
+
+impl HandlebarsInflector {
+    /// Name the helper is registered under by [`register`](HandlebarsInflector::register)
+    pub const DEFAULT_HELPER_NAME: &'static str = "inflect";
+
+    /// Start configuring a helper, see [`HandlebarsInflectorBuilder`]
+    pub fn builder() -> HandlebarsInflectorBuilder {
+        HandlebarsInflectorBuilder::new()
+    }
+
+    /// Create a helper without any configuration
+    pub const fn new() -> Self {
+        HandlebarsInflector {
+            reserved: BTreeSet::new(),
+            reserved_suffix: None,
+            reserved_words: BTreeMap::new(),
+            overrides: BTreeMap::new(),
+            nouns: Nouns::new(),
+            acronyms: BTreeSet::new(),
+            abbreviations: BTreeMap::new(),
+            table_prefix: None,
+            vendor_prefixes: Vec::new(),
+            namespace_separator: None,
+            cases: BTreeMap::new(),
+            default_ops: None,
+            coerce: false,
+            pipelines: BTreeMap::new(),
+            #[cfg(feature = "i18n")]
+            locale: Locale::English,
+            strictness: Strictness::Registry,
+        }
+    }
+
+    /// Add names that results must never equal, like keywords or existing symbols
+    pub fn with_reserved<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.reserved.extend(names.into_iter().map(Into::into));
+        self
+    }
+
+    /// Suffix appended to results equal to a reserved name, `_` by default
+    pub fn with_reserved_suffix<S: Into<String>>(mut self, suffix: S) -> Self {
+        self.reserved_suffix = Some(suffix.into());
+        self
+    }
+
+    /// Register a list of reserved words under `name`, selected with the `reserved` hash option
+    pub fn with_reserved_words<S: Into<String>>(mut self, name: S, words: ReservedWords) -> Self {
+        self.reserved_words.insert(name.into(), words);
+        self
+    }
+
+    /// Always write `target` for the input `source`, skipping all operations
+    pub fn with_override<S: Into<String>, T: Into<String>>(mut self, source: S, target: T) -> Self {
+        self.overrides.insert(source.into(), target.into());
+        self
+    }
+
+    /// Add several overrides at once, see [`with_override`](HandlebarsInflector::with_override)
+    pub fn with_overrides<I, S, T>(mut self, overrides: I) -> Self
+    where
+        I: IntoIterator<Item = (S, T)>,
+        S: Into<String>,
+        T: Into<String>,
+    {
+        self.overrides.extend(
+            overrides
+                .into_iter()
+                .map(|(source, target)| (source.into(), target.into())),
+        );
+        self
+    }
+
+    /// Add irregular nouns as `(singular, plural)` pairs, taking precedence over the built-in
+    /// rules of `to_plural`, `to_singular`, `to_class_case` and `to_table_case`
+    pub fn with_rules<I, S, T>(mut self, rules: I) -> Self
+    where
+        I: IntoIterator<Item = (S, T)>,
+        S: Into<String>,
+        T: Into<String>,
+    {
+        self.nouns.irregulars.extend(
+            rules
+                .into_iter()
+                .map(|(singular, plural)| (singular.into(), plural.into())),
+        );
+        self
+    }
+
+    /// Add nouns without a plural form, left untouched by the operations changing grammatical
+    /// number
+    pub fn with_uncountable<I, S>(mut self, nouns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.nouns
+            .uncountable
+            .extend(nouns.into_iter().map(|noun| noun.into().to_lowercase()));
+        self
+    }
+
+    /// Add acronyms like `API` or `HTTP`, kept in uppercase by `to_camel_case`, `to_pascal_case`
+    /// and `to` and never split by `to_snake_case`
+    pub fn with_acronyms<I, S>(mut self, acronyms: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.acronyms.extend(
+            acronyms
+                .into_iter()
+                .map(|acronym| acronym.into().to_uppercase()),
+        );
+        self
+    }
+
+    /// Add word abbreviations like `identifier` to `id`, applied by the `abbreviate` operation
+    pub fn with_abbreviations<I, S, T>(mut self, abbreviations: I) -> Self
+    where
+        I: IntoIterator<Item = (S, T)>,
+        S: Into<String>,
+        T: Into<String>,
+    {
+        self.abbreviations.extend(
+            abbreviations
+                .into_iter()
+                .map(|(word, abbreviation)| (word.into().to_lowercase(), abbreviation.into())),
+        );
+        self
+    }
+
+    /// Prefix added by `to_table_case` and removed by `to_class_case`, like `app_`, unless the
+    /// `table_prefix` hash option says otherwise
+    pub fn with_table_prefix<S: Into<String>>(mut self, prefix: S) -> Self {
+        self.table_prefix = Some(prefix.into());
+        self
+    }
+
+    /// Add legacy prefixes like `tbl_` or `vw_`, removed before `to_class_case`,
+    /// `to_table_case`, `to_singular` and `ensure_singular` run, unless the `vendor_prefixes`
+    /// hash option says otherwise
+    pub fn with_vendor_prefixes<I, S>(mut self, prefixes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.vendor_prefixes
+            .extend(prefixes.into_iter().map(Into::into));
+        self
+    }
+
+    /// Separator between namespace segments for `demodulize` and `deconstantize`, `::` by
+    /// default, unless the `namespace_separator` hash option says otherwise
+    pub fn with_namespace_separator<S: Into<String>>(mut self, separator: S) -> Self {
+        self.namespace_separator = Some(separator.into());
+        self
+    }
+
+    /// Make `spec` available to the `to` hash option as `name`, taking precedence over a
+    /// built-in convention of the same name
+    pub fn with_case<S: Into<String>>(mut self, name: S, spec: CaseSpec) -> Self {
+        self.cases.insert(name.into(), spec);
+        self
+    }
+
+    /// Follow the noun rules of `locale` when changing grammatical number, unless the `locale`
+    /// hash option says otherwise
+    #[cfg(feature = "i18n")]
+    pub fn with_locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Operations applied in the order written, like the `ops` hash option, when a call names
+    /// neither `ops` nor any operation
+    pub fn with_default_ops<S: Into<String>>(mut self, ops: S) -> Self {
+        self.default_ops = Some(ops.into());
+        self
+    }
+
+    /// Name a list of operations separated by `|`, applied in the order written when a call
+    /// refers to it with the `pipeline` hash option
+    pub fn with_pipeline<S: Into<String>, T: Into<String>>(mut self, name: S, ops: T) -> Self {
+        self.register_pipeline(name, ops);
+        self
+    }
+
+    /// Add a named pipeline to an existing helper, see
+    /// [`with_pipeline`](HandlebarsInflector::with_pipeline)
+    pub fn register_pipeline<S: Into<String>, T: Into<String>>(&mut self, name: S, ops: T) {
+        self.pipelines.insert(name.into(), ops.into());
+    }
+
+    /// Convert numbers, booleans and null input to strings instead of rejecting it, unless the
+    /// `coerce` hash option says otherwise
+    pub fn with_coercion(mut self, coerce: bool) -> Self {
+        self.coerce = coerce;
+        self
+    }
+
+    /// Decide on strictness independently of the registry's strict mode
+    pub fn with_strictness(mut self, strictness: Strictness) -> Self {
+        self.strictness = strictness;
+        self
+    }
+
+    /// Register a copy of this helper in `registry` as
+    /// [`DEFAULT_HELPER_NAME`](HandlebarsInflector::DEFAULT_HELPER_NAME)
+    ///
+    /// ```
+    /// use handlebars::Handlebars;
+    /// use handlebars_inflector::HandlebarsInflector;
+    ///
+    /// let mut h = Handlebars::new();
+    /// HandlebarsInflector.register(&mut h);
+    ///
+    /// assert_eq!(h.render_template(r#"{{inflect this to_plural=true}}"#, &String::from("box")).expect("Render error"), "boxes");
+    /// ```
+    pub fn register(&self, registry: &mut Handlebars) {
+        self.register_as(registry, Self::DEFAULT_HELPER_NAME);
+    }
+
+    /// Register a copy of this helper in `registry` as `name`
+    pub fn register_as(&self, registry: &mut Handlebars, name: &str) {
+        registry.register_helper(name, Box::new(self.clone()));
+    }
+
+    /// Capture the custom rules of this helper, e.g. to save them with [`Rules::save`]
+    pub fn rules(&self) -> Rules {
+        Rules {
+            reserved: self.reserved.clone(),
+            reserved_suffix: self.reserved_suffix.clone(),
+            overrides: self.overrides.clone(),
+            irregulars: self.nouns.irregulars.clone(),
+            uncountable: self.nouns.uncountable.clone(),
+            acronyms: self.acronyms.clone(),
+            abbreviations: self.abbreviations.clone(),
+        }
+    }
+
+    /// Add previously captured or loaded rules to the rules of this helper
+    pub fn with_rule_set(mut self, rules: Rules) -> Self {
+        self.reserved.extend(rules.reserved);
+        self.reserved_suffix = rules.reserved_suffix.or(self.reserved_suffix);
+        self.overrides.extend(rules.overrides);
+        self.nouns.irregulars.extend(rules.irregulars);
+        self.nouns.uncountable.extend(
+            rules
+                .uncountable
+                .into_iter()
+                .map(|noun| noun.to_lowercase()),
+        );
+        self.acronyms.extend(
+            rules
+                .acronyms
+                .into_iter()
+                .map(|acronym| acronym.to_uppercase()),
+        );
+        self.abbreviations.extend(
+            rules
+                .abbreviations
+                .into_iter()
+                .map(|(word, abbreviation)| (word.to_lowercase(), abbreviation)),
+        );
+        self
+    }
+}
+
+impl HelperDef for HandlebarsInflector {
+    fn call_inner<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        r: &'reg Handlebars,
+        ctx: &'rc Context,
+        rc: &mut RenderContext<'reg, 'rc>,
+    ) -> Result<ScopedJson<'rc>, RenderError> {
+        let mut inflected = StringOutput::new();
+        self.call(h, r, ctx, rc, &mut inflected)?;
+
+        let inflected = inflected.into_string().unwrap_or_default();
+        Ok(ScopedJson::Derived(JsonValue::String(inflected)))
+    }
+
+    fn call<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        r: &'reg Handlebars,
+        ctx: &'rc Context,
+        rc: &mut RenderContext<'reg, 'rc>,
+        out: &mut dyn Output,
+    ) -> HelperResult {
+        if !h.is_block() {
+            return self.inflect(h, r, rc, out);
+        }
+
+        if h.params().is_empty() {
+            let mut body = StringOutput::new();
+            render_body(h, r, ctx, rc, &mut body)?;
+
+            let body = body.into_string().unwrap_or_default();
+            return self.inflect_input(h, r, rc, Cow::Owned(body), out);
+        }
+
+        if self.has_input(h) {
+            let mut inflected = StringOutput::new();
+            self.inflect(h, r, rc, &mut inflected)?;
+
+            let inflected = inflected.into_string().unwrap_or_default();
+            render_block(h, r, ctx, rc, out, JsonValue::String(inflected))
+        } else {
+            render_inverse(h, r, ctx, rc, out)
+        }
+    }
+}
+
+impl HandlebarsInflector {
+    /// Apply the requested operations to the positional parameter and write the result.
+    fn inflect(
+        &self,
+        h: &Helper,
+        r: &Handlebars,
+        rc: &mut RenderContext,
+        out: &mut dyn Output,
+    ) -> HelperResult {
+        let strict = self.strictness.is_strict(r);
+
+        if is_blank(h) {
+            if let Some(default) = h.hash_get("default").and_then(|v| v.value().as_str()) {
+                return self.inflect_input(h, r, rc, Cow::Borrowed(default), out);
+            }
+        }
+
+        if let Some(JsonValue::Array(items)) = h.param(0).map(|input| input.value()) {
+            return self.inflect_items(h, r, rc, items, out);
+        }
+
+        let input = if let Some(input) = h.param(0) {
+            input
+        } else {
+            if strict {
+                return Err(RenderErrorReason::ParamNotFoundForIndex("inflect", 0).into());
+            }
+
+            return Ok(());
+        };
+
+        let Some(input) = self.input_of(h, input.value()) else {
+            if strict {
+                return Err(RenderErrorReason::ParamTypeMismatchForName(
+                    "inflect",
+                    "0".to_string(),
+                    "string".to_string(),
+                )
+                .into());
+            }
+
+            return Ok(());
+        };
+
+        self.inflect_input(h, r, rc, input, out)
+    }
+
+    /// Whether the positional parameter, an item of an array parameter or the `default` hash
+    /// option gives `inflect` something to write.
+    fn has_input(&self, h: &Helper) -> bool {
+        if is_blank(h) && h.hash_get("default").is_some_and(|v| v.value().is_string()) {
+            return true;
+        }
+
+        match h.param(0).map(|input| input.value()) {
+            Some(JsonValue::Array(items)) => {
+                items.iter().any(|item| self.input_of(h, item).is_some())
+            }
+            Some(input) => self.input_of(h, input).is_some(),
+            None => false,
+        }
+    }
+
+    /// Inflect every item of an array input and write them joined with the `join` hash option.
+    fn inflect_items(
+        &self,
+        h: &Helper,
+        r: &Handlebars,
+        rc: &mut RenderContext,
+        items: &[JsonValue],
+        out: &mut dyn Output,
+    ) -> HelperResult {
+        let strict = self.strictness.is_strict(r);
+        let separator = h
+            .hash_get("join")
+            .and_then(|v| v.value().as_str())
+            .unwrap_or(", ");
+
+        let mut inflected = Vec::with_capacity(items.len());
+
+        for item in items {
+            let Some(input) = self.input_of(h, item) else {
+                if strict {
+                    return Err(RenderErrorReason::ParamTypeMismatchForName(
+                        "inflect",
+                        "0".to_string(),
+                        "array of strings".to_string(),
+                    )
+                    .into());
+                }
+
+                continue;
+            };
+
+            let mut item = StringOutput::new();
+            self.inflect_input(h, r, rc, input, &mut item)?;
+            inflected.push(item.into_string().unwrap_or_default());
+        }
+
+        out.write(&inflected.join(separator))?;
+
+        Ok(())
+    }
+
+    /// The input `value` as a string, with numbers, booleans and null converted when coercion is
+    /// enabled with the `coerce` hash option or [`with_coercion`](Self::with_coercion). Numbers
+    /// are always converted for the operations on numbers. Strings are borrowed, not copied.
+    fn input_of<'a>(&self, h: &Helper, value: &'a JsonValue) -> Option<Cow<'a, str>> {
+        let coerce = match h.hash_get("coerce") {
+            Some(_) => flag(h, "coerce"),
+            None => self.coerce,
+        };
+
+        let numeric = || {
+            steps(h, false, self).is_ok_and(|steps| {
+                steps
+                    .iter()
+                    .any(|(name, _)| NUMERIC_OPERATIONS.contains(name))
+            })
+        };
+
+        match value {
+            JsonValue::String(input) => Some(Cow::Borrowed(input)),
+            JsonValue::Number(_) if coerce || numeric() => Some(Cow::Owned(value.render())),
+            JsonValue::Bool(_) if coerce => Some(Cow::Owned(value.render())),
+            JsonValue::Null if coerce => Some(Cow::Borrowed("")),
+            _ => None,
+        }
+    }
+
+    /// Apply the requested operations to `output` and write the result.
+    ///
+    /// `output` stays borrowed until a step changes it, so an input left as it is gets written
+    /// without being copied.
+    fn inflect_input(
+        &self,
+        h: &Helper,
+        r: &Handlebars,
+        rc: &mut RenderContext,
+        output: Cow<'_, str>,
+        out: &mut dyn Output,
+    ) -> HelperResult {
+        let strict = self.strictness.is_strict(r);
+
+        let literal = |name| h.hash_get(name).and_then(|v| v.value().as_str());
+
+        let mut output = strip_affixes(output, literal("strip_prefix"), literal("strip_suffix"));
+
+        let boundaries = Boundaries {
+            apostrophes: hash_option::<Punctuation>(
+                h,
+                strict,
+                "apostrophes",
+                "split, keep or strip",
+            )?
+            .unwrap_or_default(),
+            periods: hash_option::<Punctuation>(h, strict, "periods", "split, keep or strip")?
+                .unwrap_or_default(),
+            hyphens: hash_option::<Punctuation>(h, strict, "hyphens", "split, keep or strip")?
+                .unwrap_or_default(),
+            digits: hash_option::<Digits>(h, strict, "digits", "attach or separate")?,
+        };
+
+        if let Some(index) = h.hash_get("word") {
+            match select_word(&output, index.value(), boundaries, strict)? {
+                Some(word) => output = Cow::Owned(word),
+                None => return Ok(()),
+            }
+        }
+
+        if let Some(target) = self.overrides.get(output.as_ref()) {
+            return self.finish(h, r, rc, Cow::Borrowed(target), out);
+        }
+
+        let steps = steps(h, strict, self)?;
+
+        if h.hash_get("from").is_some()
+            && strict
+            && !steps
+                .iter()
+                .any(|(name, _)| matches!(*name, "to" | "target"))
+        {
+            return Err(
+                RenderErrorReason::ParamNotFoundForName("inflect", "to".to_string()).into(),
+            );
+        }
+
+        let separator = match h.hash_get("segments").map(|v| v.value()) {
+            Some(JsonValue::String(separator)) if !separator.is_empty() => Some(separator.as_str()),
+            Some(JsonValue::Bool(false)) | None => None,
+            Some(_) => Some("/"),
+        };
+
+        let output = match separator {
+            Some(separator) => Cow::Owned(
+                output
+                    .split(separator)
+                    .map(|segment| {
+                        self.inflect_segment(h, strict, boundaries, &steps, Cow::Borrowed(segment))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?
+                    .join(separator),
+            ),
+            None => self.inflect_segment(h, strict, boundaries, &steps, output)?,
+        };
+
+        self.finish(h, r, rc, output, out)
+    }
+
+    /// Run `steps` on `segment`, the whole input or one path segment of it.
+    ///
+    /// Steps known to leave `segment` as it is are skipped, keeping it borrowed.
+    fn inflect_segment<'a>(
+        &self,
+        h: &Helper,
+        strict: bool,
+        boundaries: Boundaries,
+        steps: &[(&str, Option<&str>)],
+        segment: Cow<'a, str>,
+    ) -> Result<Cow<'a, str>, RenderError> {
+        let mut output = segment;
+
+        let mut edges = flag(h, "preserve_edges").then(|| underscore_edges(&output));
+
+        if flag(h, "skip_if_matching") && matches_target_case(steps, &output) {
+            return Ok(output);
+        }
+
+        // Punctuation is stripped once the script has been normalized, before any operation
+        // working on words.
+        let mut stripped = boundaries == Boundaries::default();
+
+        for &(name, value) in steps {
+            if !stripped && !matches!(name, "transliterate" | "normalize_confusables") {
+                output = Cow::Owned(boundaries.strip(&output));
+                stripped = true;
+            }
+
+            if name == "to_identifier" {
+                if let Some((leading, trailing)) = edges.take() {
+                    output = Cow::Owned(format!(
+                        "{}{}{}",
+                        leading,
+                        output.trim_matches('_'),
+                        trailing
+                    ));
+                }
+            }
+
+            if !leaves_unchanged(name, &output) {
+                output = Cow::Owned(self.apply_op(
+                    h,
+                    strict,
+                    boundaries,
+                    name,
+                    value,
+                    output.into_owned(),
+                )?);
+            }
+        }
+
+        if !stripped {
+            output = Cow::Owned(boundaries.strip(&output));
+        }
+
+        if let Some((leading, trailing)) = edges {
+            output = Cow::Owned(format!(
+                "{}{}{}",
+                leading,
+                output.trim_matches('_'),
+                trailing
+            ));
+        }
+
+        Ok(output)
+    }
+
+    /// Apply the operation `name` to `output`, taking its value from `value` or the hash.
+    fn apply_op(
+        &self,
+        h: &Helper,
+        strict: bool,
+        boundaries: Boundaries,
+        name: &str,
+        value: Option<&str>,
+        mut output: String,
+    ) -> Result<String, RenderError> {
+        if matches!(
+            name,
+            "to_class_case" | "to_table_case" | "to_singular" | "ensure_singular"
+        ) {
+            output = self.strip_vendor_prefix(h, output);
+        }
+
+        match name {
+            "transliterate" => {
+                match hash_option::<Scheme>(
+                    h,
+                    strict,
+                    "scheme",
+                    "supported transliteration scheme",
+                )? {
+                    Some(scheme) => output = transliterate(&output, scheme),
+                    None if strict => {
+                        return Err(RenderErrorReason::ParamNotFoundForName(
+                            "inflect",
+                            "scheme".to_string(),
+                        )
+                        .into());
+                    }
+                    None => {}
+                }
+            }
+            "normalize_confusables" => output = to_ascii_skeleton(&output),
+            "normalize" => {
+                output = split_words(&output, boundaries)
+                    .iter()
+                    .map(|word| word.to_lowercase())
+                    .collect::<Vec<_>>()
+                    .join(" ");
+            }
+            "abbreviate" => output = self.abbreviate(&output, boundaries),
+            #[cfg(feature = "stemming")]
+            "stem" => {
+                let language =
+                    hash_option::<StemLanguage>(h, strict, "stemmer", "stemmer language")?;
+
+                output = stem(&output, language.unwrap_or_default());
+            }
+            #[cfg(feature = "phonetic")]
+            "phonetic" => {
+                if let Some(algorithm) =
+                    op_value::<Phonetic>(h, strict, "phonetic", value, "phonetic algorithm")?
+                {
+                    output = phonetic_key(&output, algorithm);
+                }
+            }
+            "from_roman" => {
+                if let Some(n) = from_roman(&output) {
+                    output = n.to_string();
+                }
+            }
+            "number_to_words" => {
+                if let Some(n) = parse_integer(&output) {
+                    if spells_out(h, strict, n)? {
+                        output = number_to_words(n);
+                    }
+                }
+            }
+            "ordinalize_words" => {
+                let number = output
+                    .trim_end_matches(|c: char| c.is_ascii_alphabetic())
+                    .to_string();
+
+                if let Some(n) = parse_integer(&number) {
+                    output = if spells_out(h, strict, n)? {
+                        ordinal_words(n)
+                    } else {
+                        number.ordinalize()
+                    };
+                }
+            }
+            "to_roman" => {
+                if let Some(roman) = parse_integer(&output).and_then(to_roman) {
+                    output = roman;
+                }
+            }
+            "to" => {
+                let words = split_input(h, strict, &output, boundaries)?;
+
+                let spec = value
+                    .or_else(|| h.hash_get("to").and_then(|v| v.value().as_str()))
+                    .and_then(|to| self.cases.get(to));
+
+                if let Some(spec) = spec {
+                    output = spec.join(&words);
+                } else if let Some(to) =
+                    op_value::<Convention>(h, strict, "to", value, "case convention")?
+                {
+                    output = to.join_with_acronyms(&words, &self.acronyms);
+                }
+            }
+            "target" => {
+                let target = op_value::<Target>(h, strict, "target", value, "target language")?;
+                let kind = hash_option::<Kind>(h, strict, "kind", "kind of name")?;
+                let exported = h.hash_get("exported").map(|_| flag(h, "exported"));
+
+                let Some(target) = target else {
+                    return Ok(output);
+                };
+
+                let convention = match (exported, kind) {
+                    (Some(exported), kind) => target
+                        .visibility_convention(exported)
+                        .or_else(|| kind.map(|kind| target.convention(kind))),
+                    (None, kind) => kind.map(|kind| target.convention(kind)),
+                };
+
+                match convention {
+                    Some(convention) => {
+                        let mut acronyms = self.acronyms.clone();
+                        acronyms.extend(target.initialisms().iter().map(|s| s.to_string()));
+
+                        let words = split_input(h, strict, &output, boundaries)?;
+                        output = convention.join_with_acronyms(&words, &acronyms);
+
+                        let enum_name = h.hash_get("enum_name").and_then(|v| v.value().as_str());
+                        if let (Some(Kind::EnumValue), Some(enum_name)) = (kind, enum_name) {
+                            output = target.prefix_enum_value(output, enum_name);
+                        }
+                    }
+                    None if strict => {
+                        return Err(RenderErrorReason::ParamNotFoundForName(
+                            "inflect",
+                            "kind".to_string(),
+                        )
+                        .into());
+                    }
+                    None => {}
+                }
+            }
+            "to_camel_case" if !self.acronyms.is_empty() || boundaries.is_custom() => {
+                output = self.join_acronyms(Convention::Camel, &output, boundaries);
+            }
+            "to_camel_case" => output = output.to_camel_case(),
+            "to_pascal_case" if !self.acronyms.is_empty() || boundaries.is_custom() => {
+                output = self.join_acronyms(Convention::Pascal, &output, boundaries);
+            }
+            "to_pascal_case" => output = output.to_pascal_case(),
+            "to_snake_case" if !self.acronyms.is_empty() || boundaries.is_custom() => {
+                output = self.join_acronyms(Convention::Snake, &output, boundaries);
+            }
+            "to_snake_case" => output = output.to_snake_case(),
+            "to_screaming_snake_case" if boundaries.is_custom() => {
+                output = self.join_acronyms(Convention::ScreamingSnake, &output, boundaries);
+            }
+            "to_screaming_snake_case" => output = output.to_screaming_snake_case(),
+            "to_env_var" => {
+                let prefix = h
+                    .hash_get("env_prefix")
+                    .and_then(|v| v.value().as_str())
+                    .unwrap_or_default();
+
+                output = to_env_var(&output, prefix, boundaries);
+            }
+            "to_kebab_case" if boundaries.is_custom() => {
+                output = self.join_acronyms(Convention::Kebab, &output, boundaries);
+            }
+            "to_kebab_case" => output = output.to_kebab_case(),
+            "to_train_case" if boundaries.is_custom() => {
+                output = self.join_acronyms(Convention::Train, &output, boundaries);
+            }
+            "to_train_case" => output = output.to_train_case(),
+            "to_dot_case" => output = self.join_acronyms(Convention::Dot, &output, boundaries),
+            "to_path_case" => output = self.join_acronyms(Convention::Path, &output, boundaries),
+            "to_sentence_case" if boundaries.is_custom() => {
+                output = self.join_acronyms(Convention::Sentence, &output, boundaries);
+            }
+            "to_sentence_case" => output = output.to_sentence_case(),
+            "to_title_case" => {
+                let style = hash_option::<TitleStyle>(h, strict, "style", "title style")?;
+
+                output = if boundaries.is_custom() {
+                    self.join_acronyms(Convention::Title, &output, boundaries)
+                } else {
+                    output.to_title_case()
+                };
+
+                if let Some(style) = style {
+                    output = style.apply(&output);
+                }
+            }
+            "humanize" => output = self.humanize(&output),
+            "parameterize" => {
+                let separator = h
+                    .hash_get("separator")
+                    .and_then(|v| v.value().as_str())
+                    .unwrap_or("-");
+
+                output = parameterize(&output, separator);
+            }
+            "to_acronym" => {
+                let dotted = flag(h, "dotted");
+
+                output = initials(&split_words(&output, boundaries), dotted);
+            }
+            "ordinalize" => output = output.ordinalize(),
+            "deordinalize" => output = output.deordinalize(),
+            "to_foreign_key"
+                if h.hash_get("key_suffix").is_some() || h.hash_get("key_case").is_some() =>
+            {
+                let suffix = h
+                    .hash_get("key_suffix")
+                    .and_then(|v| v.value().as_str())
+                    .unwrap_or("_id");
+                let case = hash_option::<Convention>(h, strict, "key_case", "case convention")?
+                    .unwrap_or(Convention::Snake);
+
+                output = self.foreign_key(&output, suffix, case, boundaries);
+            }
+            "to_foreign_key" => output = output.to_foreign_key(),
+            "demodulize" => output = self.with_rust_namespaces(h, &output).demodulize(),
+            "deconstantize" => output = self.with_rust_namespaces(h, &output).deconstantize(),
+            "to_class_case" => {
+                let prefix = self.table_prefix(h);
+                let name = strip_table_prefix(&output, prefix).unwrap_or(&output);
+
+                output = match self.nouns.inflect(name, false) {
+                    Some(singular) => singular.to_pascal_case(),
+                    None => name.to_class_case(),
+                };
+            }
+            "to_table_case" => {
+                output = match self.nouns.inflect(&output.to_snake_case(), true) {
+                    Some(plural) => plural,
+                    None => output.to_table_case(),
+                };
+
+                let prefix = self.table_prefix(h);
+                if strip_table_prefix(&output, prefix).is_none() {
+                    output.insert_str(0, prefix);
+                }
+            }
+            "to_plural" => output = self.to_number(h, strict, output, true, false)?,
+            "to_singular" => output = self.to_number(h, strict, output, false, false)?,
+            "ensure_plural" => output = self.to_number(h, strict, output, true, true)?,
+            "ensure_singular" => output = self.to_number(h, strict, output, false, true)?,
+            "count" => {
+                let count = match value {
+                    Some(value) => count_of(&JsonValue::String(value.to_string())),
+                    None => h.hash_get("count").and_then(|v| count_of(v.value())),
+                };
+
+                match count {
+                    Some(count) => {
+                        output = self.to_number(h, strict, output, !is_singular(count), true)?;
+                    }
+                    None if strict => {
+                        return Err(RenderErrorReason::HashTypeMismatchForName(
+                            "inflect",
+                            "count".to_string(),
+                            "number or array".to_string(),
+                        )
+                        .into());
+                    }
+                    None => {}
+                }
+            }
+            "agree_count" => output = agree_with_count(output),
+            "indefinite_article" if !output.trim().is_empty() => {
+                output = format!("{} {}", indefinite_article(&output), output);
+            }
+            "swap_case" => output = swap_case(&output),
+            "capitalize" => output = upper_first(&output),
+            "decapitalize" => output = lower_first(&output),
+            "to_upper_case" => output = output.to_uppercase(),
+            "to_lower_case" => output = output.to_lowercase(),
+            "truncate_words" => {
+                // `truncate_words=20` works like `truncate_words=true max=20`
+                let max = match value {
+                    Some(value) => value.parse::<u64>().ok(),
+                    None => ["truncate_words", "max"].iter().find_map(|name| {
+                        let value = h.hash_get(name)?.value();
+                        value
+                            .as_u64()
+                            .or_else(|| value.as_str().and_then(|s| s.parse().ok()))
+                    }),
+                };
+                let omission = h
+                    .hash_get("omission")
+                    .and_then(|v| v.value().as_str())
+                    .unwrap_or("...");
+
+                match max {
+                    Some(max) => output = truncate_words(output, max as usize, omission),
+                    None if strict => {
+                        return Err(RenderErrorReason::HashTypeMismatchForName(
+                            "inflect",
+                            "max".to_string(),
+                            "non-negative integer".to_string(),
+                        )
+                        .into());
+                    }
+                    None => {}
+                }
+            }
+            "to_identifier" => {
+                let profile = hash_option::<Profile>(h, strict, "profile", "identifier profile")?;
+
+                let leading_digit = hash_option::<LeadingDigit>(
+                    h,
+                    strict,
+                    "leading_digit",
+                    "leading digit strategy",
+                )?;
+
+                if let Some(leading_digit) = leading_digit {
+                    if leading_digit == LeadingDigit::Error
+                        && strict
+                        && output.starts_with(|c: char| c.is_ascii_digit())
+                    {
+                        return Err(RenderErrorReason::Other(format!(
+                            "inflect: `{}` starts with a digit",
+                            output
+                        ))
+                        .into());
+                    }
+
+                    let prefix = h
+                        .hash_get("digit_prefix")
+                        .and_then(|v| v.value().as_str())
+                        .unwrap_or("n");
+
+                    output = leading_digit.apply(&output, prefix);
+                }
+
+                output = to_identifier(&output, profile.unwrap_or_default());
+            }
+            "to_raw_identifier" => output = to_raw_identifier(&output),
+            "quote_identifier" => {
+                let dialect = hash_option::<Dialect>(h, strict, "dialect", "SQL dialect")?;
+
+                output = quote_identifier(&output, dialect.unwrap_or_default());
+            }
+            "to_filename" => {
+                let max_length = max_length(h, strict)?;
+                let keep_extension = flag(h, "keep_extension");
+
+                output = to_filename(&output, max_length, keep_extension);
+            }
+            "to_dns_label" => {
+                let subdomain = flag(h, "subdomain");
+                let max_length = max_length(h, strict)?.unwrap_or(max_dns_length(subdomain));
+
+                output = if subdomain {
+                    to_dns_name(&output, max_length)
+                } else {
+                    to_dns_label(&output, max_length)
+                };
+            }
+            _ => {}
+        }
+
+        Ok(output)
+    }
+
+    /// Split `output` into words and join them in `convention`, keeping known acronyms intact.
+    fn join_acronyms(
+        &self,
+        convention: Convention,
+        output: &str,
+        boundaries: Boundaries,
+    ) -> String {
+        convention.join_with_acronyms(&split_words(output, boundaries), &self.acronyms)
+    }
+
+    /// Replace words with their registered abbreviation, keeping separators and the case of
+    /// the original word.
+    fn abbreviate(&self, output: &str, boundaries: Boundaries) -> String {
+        let mut abbreviated = String::with_capacity(output.len());
+        let mut end = 0;
+
+        for word in split_words(output, boundaries) {
+            // Words are slices of `output`, so their position follows from their address
+            let start = word.as_ptr() as usize - output.as_ptr() as usize;
+
+            abbreviated.push_str(&output[end..start]);
+            match self.abbreviations.get(&word.to_lowercase()) {
+                Some(abbreviation) => abbreviated.push_str(&match_case(word, abbreviation)),
+                None => abbreviated.push_str(word),
+            }
+            end = start + word.len();
+        }
+
+        abbreviated.push_str(&output[end..]);
+        abbreviated
+    }
+
+    /// `output` with its namespace separators replaced by `::`, the separator of `demodulize`
+    /// and `deconstantize`.
+    fn with_rust_namespaces(&self, h: &Helper, output: &str) -> String {
+        let separator = h
+            .hash_get("namespace_separator")
+            .and_then(|v| v.value().as_str())
+            .or(self.namespace_separator.as_deref())
+            .filter(|separator| !separator.is_empty());
+
+        match separator {
+            Some(separator) => output.replace(separator, "::"),
+            None => output.to_string(),
+        }
+    }
+
+    /// Table prefix from the `table_prefix` hash option or the helper's configuration.
+    fn table_prefix<'a>(&'a self, h: &'a Helper) -> &'a str {
+        h.hash_get("table_prefix")
+            .and_then(|v| v.value().as_str())
+            .or(self.table_prefix.as_deref())
+            .unwrap_or_default()
+    }
+
+    /// Remove the first vendor prefix `output` starts with, ignoring ASCII case. Prefixes come
+    /// from the `|` separated `vendor_prefixes` hash option or the helper's configuration, and
+    /// are kept when nothing would be left of the name.
+    fn strip_vendor_prefix(&self, h: &Helper, output: String) -> String {
+        let prefix = match h
+            .hash_get("vendor_prefixes")
+            .and_then(|v| v.value().as_str())
+        {
+            Some(prefixes) => prefixes
+                .split('|')
+                .find(|prefix| has_prefix(&output, prefix)),
+            None => self
+                .vendor_prefixes
+                .iter()
+                .map(String::as_str)
+                .find(|prefix| has_prefix(&output, prefix)),
+        };
+
+        match prefix {
+            Some(prefix) => output[prefix.len()..].to_string(),
+            None => output,
+        }
+    }
+
+    /// Foreign key column for the type `output` with a custom id `suffix`, written in `case`.
+    ///
+    /// Like `to_foreign_key`, only the last `::` segment counts and the suffix isn't repeated.
+    fn foreign_key(
+        &self,
+        output: &str,
+        suffix: &str,
+        case: Convention,
+        boundaries: Boundaries,
+    ) -> String {
+        let name = output.rsplit("::").next().unwrap_or(output);
+        let mut words = split_words(name, boundaries);
+        let suffix_words = split_words(suffix, boundaries);
+
+        let has_suffix = words.len() > suffix_words.len()
+            && words[words.len() - suffix_words.len()..]
+                .iter()
+                .zip(&suffix_words)
+                .all(|(word, suffix)| word.eq_ignore_ascii_case(suffix));
+
+        if !has_suffix {
+            words.extend(suffix_words);
+        }
+
+        case.join_with_acronyms(&words, &self.acronyms)
+    }
+
+    /// Rails-style humanization: drop a trailing `_id`, replace underscores with spaces and
+    /// capitalize the first word, keeping known acronyms in uppercase.
+    fn humanize(&self, output: &str) -> String {
+        let output = output.strip_suffix("_id").unwrap_or(output);
+
+        let words: Vec<String> = output
+            .split('_')
+            .filter(|word| !word.is_empty())
+            .flat_map(str::split_whitespace)
+            .map(|word| match word.to_uppercase() {
+                acronym if self.acronyms.contains(&acronym) => acronym,
+                _ => word.to_lowercase(),
+            })
+            .collect();
+
+        let humanized = words.join(" ");
+        let mut chars = humanized.chars();
+
+        match chars.next() {
+            Some(first) => first.to_uppercase().chain(chars).collect(),
+            None => humanized,
+        }
+    }
+
+    /// Put `output` in plural or singular form, unless it already is with `ensure`.
+    ///
+    /// Custom nouns take precedence over the rules of the locale.
+    #[cfg_attr(not(feature = "i18n"), allow(unused_variables))]
+    fn to_number(
+        &self,
+        h: &Helper,
+        strict: bool,
+        output: String,
+        plural: bool,
+        ensure: bool,
+    ) -> Result<String, RenderError> {
+        if let Some(output) = self.nouns.inflect(&output, plural) {
+            return Ok(output);
+        }
+
+        #[cfg(feature = "i18n")]
+        {
+            let locale = hash_option::<Locale>(h, strict, "locale", "supported locale")?
+                .unwrap_or(self.locale);
+
+            if locale != Locale::English {
+                return Ok(locale.inflect(&output, plural, ensure));
+            }
+        }
+
+        Ok(match (plural, ensure) {
+            (true, false) => output.to_plural(),
+            (false, false) => output.to_singular(),
+            (true, true) => ensure_plural(output),
+            (false, true) => ensure_singular(output),
+        })
+    }
+
+    /// Apply the options concerning the final output of the `inflect` helper and write it.
+    fn finish(
+        &self,
+        h: &Helper,
+        r: &Handlebars,
+        rc: &mut RenderContext,
+        mut output: Cow<'_, str>,
+        out: &mut dyn Output,
+    ) -> HelperResult {
+        let strict = self.strictness.is_strict(r);
+
+        if !output.is_empty() {
+            let literal = |name| h.hash_get(name).and_then(|v| v.value().as_str());
+
+            if let Some(prefix) = literal("prefix") {
+                output.to_mut().insert_str(0, prefix);
+            }
+            if let Some(suffix) = literal("suffix") {
+                output.to_mut().push_str(suffix);
+            }
+        }
+
+        if self.reserved.contains(output.as_ref()) {
+            if strict {
+                return Err(RenderErrorReason::Other(format!(
+                    "inflect: `{}` is a reserved name",
+                    output
+                ))
+                .into());
+            }
+
+            let suffix = self
+                .reserved_suffix
+                .as_deref()
+                .filter(|s| !s.is_empty())
+                .unwrap_or("_");
+
+            while self.reserved.contains(output.as_ref()) {
+                output.to_mut().push_str(suffix);
+            }
+        }
+
+        if let Some(name) = h.hash_get("reserved").and_then(|v| v.value().as_str()) {
+            match self.reserved_words.get(name) {
+                Some(words) if words.contains(&output) => {
+                    if strict && *words.strategy() == ReservedStrategy::Error {
+                        return Err(RenderErrorReason::Other(format!(
+                            "inflect: `{}` is a reserved {} word",
+                            output, name
+                        ))
+                        .into());
+                    }
+
+                    output = Cow::Owned(words.strategy().apply(output.into_owned()));
+                }
+                Some(_) => {}
+                None if strict => {
+                    return Err(RenderErrorReason::HashTypeMismatchForName(
+                        "inflect",
+                        "reserved".to_string(),
+                        "registered reserved word list".to_string(),
+                    )
+                    .into());
+                }
+                None => {}
+            }
+        }
+
+        let group = match h.hash_get("unique").map(|v| v.value()) {
+            None | Some(JsonValue::Bool(false)) => None,
+            Some(JsonValue::String(group)) => Some(group.as_str()),
+            Some(_) => Some(""),
+        };
+
+        if let Some(group) = group {
+            let output = output.to_mut();
+            let duplicate = EMITTED.with(rc, |emitted| {
+                let emitted = emitted.entry(group.to_string()).or_default();
+
+                if !emitted.contains_key(output.as_str()) {
+                    emitted.insert(output.clone(), 1);
+                    return None;
+                }
+
+                if strict {
+                    return Some(output.clone());
+                }
+
+                let mut candidate = output.clone();
+
+                while emitted.contains_key(&candidate) {
+                    let count = emitted.entry(output.clone()).or_default();
+                    *count += 1;
+                    candidate = format!("{}_{}", output, count);
+                }
+
+                emitted.insert(candidate.clone(), 1);
+                *output = candidate;
+
+                None
+            });
+
+            if let Some(duplicate) = duplicate {
+                return Err(RenderErrorReason::Other(format!(
+                    "inflect: `{}` was already emitted in this render",
+                    duplicate
+                ))
+                .into());
+            }
+        }
+
+        if let Some(escape) = hash_option::<Escape>(h, strict, "escape", "supported escape mode")? {
+            output = Cow::Owned(escape.apply(&output));
+        }
+
+        out.write(&output)?;
+
+        Ok(())
+    }
+}
+
+/// Names emitted with the `unique` option, per group
+static EMITTED: RenderScoped<Occurrences> = RenderScoped::new("__handlebars_inflector_unique");
+
+/// Leading and trailing underscores of `input`, empty when it consists of underscores only.
+fn underscore_edges(input: &str) -> (String, String) {
+    let body = input.trim_matches('_');
+
+    if body.is_empty() {
+        return (String::new(), String::new());
+    }
+
+    let leading = input.len() - input.trim_start_matches('_').len();
+    let trailing = input.len() - input.trim_end_matches('_').len();
+
+    ("_".repeat(leading), "_".repeat(trailing))
+}
+
+/// Words of `input` for the `to` and `target` operations, split by the `from` convention if
+/// given.
+fn split_input<'a>(
+    h: &Helper,
+    strict: bool,
+    input: &'a str,
+    boundaries: Boundaries,
+) -> Result<Vec<&'a str>, RenderError> {
+    let from = hash_option::<Convention>(h, strict, "from", "case convention")?;
+
+    Ok(match from {
+        Some(from) => from.split(input, boundaries),
+        None => split_words(input, boundaries),
+    })
+}
+
+/// The word of `input` at `index`, counted from the end when negative, for the `word` hash option.
+///
+/// Out of range indices select `None`, or fail the render in strict mode.
+fn select_word(
+    input: &str,
+    index: &JsonValue,
+    boundaries: Boundaries,
+    strict: bool,
+) -> Result<Option<String>, RenderError> {
+    let Some(index) = index.as_i64() else {
+        if strict {
+            return Err(RenderErrorReason::HashTypeMismatchForName(
+                "inflect",
+                "word".to_string(),
+                "integer".to_string(),
+            )
+            .into());
+        }
+
+        return Ok(Some(input.to_string()));
+    };
+
+    let words = split_words(input, boundaries);
+    let position = if index < 0 {
+        words.len().checked_sub(index.unsigned_abs() as usize)
+    } else {
+        Some(index as usize)
+    };
+
+    match position.and_then(|i| words.get(i)) {
+        Some(word) => Ok(Some(word.to_string())),
+        None if strict => Err(RenderErrorReason::Other(format!(
+            "inflect: `{}` has no word {}",
+            input, index
+        ))
+        .into()),
+        None => Ok(None),
+    }
+}
+
+/// Whether `n` is within the `words_max` hash option, spelled out by `number_to_words` and
+/// `ordinalize_words`.
+fn spells_out(h: &Helper, strict: bool, n: i64) -> Result<bool, RenderError> {
+    let Some(max) = h.hash_get("words_max") else {
+        return Ok(true);
+    };
+
+    match count_of(max.value()) {
+        Some(max) => Ok(n.unsigned_abs() as f64 <= max),
+        None if strict => Err(RenderErrorReason::HashTypeMismatchForName(
+            "inflect",
+            "words_max".to_string(),
+            "number".to_string(),
+        )
+        .into()),
+        None => Ok(true),
+    }
+}
+
+/// Whether the boolean hash option `name` is set: present with any value but `false`.
+pub(crate) fn flag(h: &Helper, name: &str) -> bool {
+    h.hash_get(name)
+        .is_some_and(|v| v.value().as_bool() != Some(false))
+}
+
+/// Parse the hash option `name` of the `inflect` helper.
+///
+/// Returns `None` when the option is absent, or when its value is invalid outside of strict mode.
+fn hash_option<T: FromStr>(
+    h: &Helper,
+    strict: bool,
+    name: &str,
+    expected: &str,
+) -> Result<Option<T>, RenderError> {
+    hash_option_for("inflect", h, strict, name, expected)
+}
+
+/// Parse the hash option `name` of the helper `helper`; see [`hash_option`].
+pub(crate) fn hash_option_for<T: FromStr>(
+    helper: &'static str,
+    h: &Helper,
+    strict: bool,
+    name: &str,
+    expected: &str,
+) -> Result<Option<T>, RenderError> {
+    let Some(value) = h.hash_get(name) else {
+        return Ok(None);
+    };
+
+    match value.value().as_str().and_then(|s| s.parse().ok()) {
+        Some(value) => Ok(Some(value)),
+        None if strict => Err(RenderErrorReason::HashTypeMismatchForName(
+            helper,
+            name.to_string(),
+            expected.to_string(),
+        )
+        .into()),
+        None => Ok(None),
+    }
+}
+
+/// Whether the positional parameter is missing, null or empty, so the `default` hash option
+/// applies.
+fn is_blank(h: &Helper) -> bool {
+    match h.param(0).map(|input| input.value()) {
+        None | Some(JsonValue::Null) => true,
+        Some(JsonValue::String(input)) => input.is_empty(),
+        Some(_) => false,
+    }
+}
+
+/// Remove `prefix` and then `suffix` from `input`, slicing a borrowed input instead of copying
+/// it.
+fn strip_affixes<'a>(
+    input: Cow<'a, str>,
+    prefix: Option<&str>,
+    suffix: Option<&str>,
+) -> Cow<'a, str> {
+    let start = prefix
+        .filter(|prefix| input.starts_with(prefix))
+        .map_or(0, str::len);
+    let end = input.len()
+        - suffix
+            .filter(|suffix| input[start..].ends_with(suffix))
+            .map_or(0, str::len);
+
+    match input {
+        Cow::Borrowed(input) => Cow::Borrowed(&input[start..end]),
+        Cow::Owned(input) if start == 0 && end == input.len() => Cow::Owned(input),
+        Cow::Owned(input) => Cow::Owned(input[start..end].to_string()),
+    }
+}
+
+/// Whether the operation `name` is known to return `input` as it is, so it can be skipped.
+fn leaves_unchanged(name: &str, input: &str) -> bool {
+    let lowercase = |c: char| c.to_lowercase().eq([c]);
+    let uppercase = |c: char| c.to_uppercase().eq([c]);
+
+    match name {
+        "to_lower_case" => input.chars().all(lowercase),
+        "to_upper_case" => input.chars().all(uppercase),
+        "capitalize" => input.chars().next().is_none_or(uppercase),
+        "decapitalize" => input.chars().next().is_none_or(lowercase),
+        "to_raw_identifier" => !is_rust_keyword(input),
+        _ => false,
+    }
+}
+
+/// `input` without the table `prefix`, when a separator or a new word follows it.
+///
+/// `app_` is stripped from `app_invoices`, `app` from `app_invoices` and `appInvoices`, but not
+/// from `apples`.
+fn strip_table_prefix<'a>(input: &'a str, prefix: &str) -> Option<&'a str> {
+    if prefix.is_empty() {
+        return Some(input);
+    }
+
+    let rest = input.strip_prefix(prefix).filter(|rest| !rest.is_empty())?;
+
+    let boundary = prefix.ends_with(|c: char| !c.is_alphanumeric())
+        || rest.starts_with(|c: char| !c.is_alphanumeric())
+        || (prefix.ends_with(char::is_lowercase) && rest.starts_with(char::is_uppercase));
+
+    boundary.then_some(rest)
+}
+
+/// Whether `input` starts with `prefix` ignoring ASCII case and has more to it.
+fn has_prefix(input: &str, prefix: &str) -> bool {
+    !prefix.is_empty()
+        && input.len() > prefix.len()
+        && input.is_char_boundary(prefix.len())
+        && input[..prefix.len()].eq_ignore_ascii_case(prefix)
+}
+
+/// Read the `max_length` hash option, failing in strict mode unless it is a non-negative integer.
+fn max_length(h: &Helper, strict: bool) -> Result<Option<usize>, RenderError> {
+    match h.hash_get("max_length").map(|v| v.value()) {
+        Some(value) => match value.as_u64() {
+            Some(max_length) => Ok(Some(max_length as usize)),
+            None if strict => Err(RenderErrorReason::HashTypeMismatchForName(
+                "inflect",
+                "max_length".to_string(),
+                "non-negative integer".to_string(),
+            )
+            .into()),
+            None => Ok(None),
+        },
+        None => Ok(None),
+    }
+}
+
+/// Operations of the `inflect` helper in their default order of execution
+pub(crate) const OPERATIONS: &[&str] = &[
+    "transliterate",
+    "normalize_confusables",
+    "normalize",
+    "abbreviate",
+    "stem",
+    "phonetic",
+    "from_roman",
+    "number_to_words",
+    "ordinalize_words",
+    "to_roman",
+    "to",
+    "target",
+    "to_camel_case",
+    "to_pascal_case",
+    "to_snake_case",
+    "to_screaming_snake_case",
+    "to_env_var",
+    "to_kebab_case",
+    "to_train_case",
+    "to_dot_case",
+    "to_path_case",
+    "to_sentence_case",
+    "to_title_case",
+    "humanize",
+    "parameterize",
+    "to_acronym",
+    "ordinalize",
+    "deordinalize",
+    "to_foreign_key",
+    "demodulize",
+    "deconstantize",
+    "to_class_case",
+    "to_table_case",
+    "to_plural",
+    "to_singular",
+    "ensure_plural",
+    "ensure_singular",
+    "count",
+    "agree_count",
+    "indefinite_article",
+    "swap_case",
+    "capitalize",
+    "decapitalize",
+    "to_upper_case",
+    "to_lower_case",
+    "truncate_words",
+    "to_identifier",
+    "to_raw_identifier",
+    "quote_identifier",
+    "to_filename",
+    "to_dns_label",
+];
+
+/// Operations taking a value instead of a boolean flag
+pub(crate) const VALUE_OPERATIONS: &[&str] =
+    &["to", "target", "phonetic", "count", "truncate_words"];
+
+/// Operations requested by the helper call, with an inline value for value operations.
+///
+/// With the `ops` hash option the operations are the ones listed there, separated by `|`, in the
+/// given order. Value operations may carry their value inline (`to=snake`), otherwise it is read
+/// from the hash. Without `ops` every operation enabled in the hash runs in the default order.
+fn steps<'a>(
+    h: &'a Helper,
+    strict: bool,
+    inflector: &'a HandlebarsInflector,
+) -> Result<Vec<(&'a str, Option<&'a str>)>, RenderError> {
+    let Some(ops) = h.hash_get("ops") else {
+        if let Some(name) = h.hash_get("pipeline") {
+            let pipeline = name
+                .value()
+                .as_str()
+                .and_then(|name| inflector.pipelines.get(name));
+
+            match pipeline {
+                Some(ops) => return parse_ops(ops, strict),
+                None if strict => {
+                    return Err(RenderErrorReason::Other(format!(
+                        "inflect: unknown pipeline `{}`",
+                        name.value().render()
+                    ))
+                    .into());
+                }
+                None => {}
+            }
+        }
+
+        let requested = |name: &str| {
+            h.hash_get(name).is_some()
+                || ALIASES
+                    .iter()
+                    .any(|(alias, target)| *target == name && h.hash_get(alias).is_some())
+        };
+
+        let flags: Vec<_> = OPERATIONS
+            .iter()
+            .filter(|name| requested(name))
+            .map(|name| (*name, None))
+            .collect();
+
+        if let Some((first, second)) = CONFLICTS
+            .iter()
+            .find(|(first, second)| requested(first) && requested(second))
+        {
+            let message = format!("inflect: `{}` conflicts with `{}`", first, second);
+
+            if strict {
+                return Err(RenderErrorReason::Other(message).into());
+            }
+
+            log::warn!("{}", message);
+        }
+
+        return match inflector.default_ops.as_deref() {
+            Some(ops) if flags.is_empty() => parse_ops(ops, strict),
+            _ => Ok(flags),
+        };
+    };
+
+    let Some(ops) = ops.value().as_str() else {
+        if strict {
+            return Err(RenderErrorReason::HashTypeMismatchForName(
+                "inflect",
+                "ops".to_string(),
+                "string".to_string(),
+            )
+            .into());
+        }
+
+        return Ok(Vec::new());
+    };
+
+    parse_ops(ops, strict)
+}
+
+/// Operations accepting numbers as input without coercion
+const NUMERIC_OPERATIONS: &[&str] = &[
+    "ordinalize",
+    "number_to_words",
+    "ordinalize_words",
+    "to_roman",
+];
+
+/// Rails (ActiveSupport) names accepted in place of the operations' own names
+pub(crate) const ALIASES: &[(&str, &str)] = &[
+    ("underscore", "to_snake_case"),
+    ("dasherize", "to_kebab_case"),
+    ("camelize", "to_pascal_case"),
+    ("classify", "to_class_case"),
+    ("tableize", "to_table_case"),
+    ("titleize", "to_title_case"),
+    ("foreign_key", "to_foreign_key"),
+    ("pluralize", "to_plural"),
+    ("singularize", "to_singular"),
+];
+
+/// Name of the operation `name` refers to, resolving [`ALIASES`].
+pub(crate) fn canonical(name: &str) -> &str {
+    ALIASES
+        .iter()
+        .find(|(alias, _)| *alias == name)
+        .map_or(name, |(_, target)| target)
+}
+
+/// Flags undoing each other, rejected in strict mode when combined in one call
+const CONFLICTS: &[(&str, &str)] = &[
+    ("to_upper_case", "to_lower_case"),
+    ("capitalize", "decapitalize"),
+    ("to_plural", "to_singular"),
+    ("to_plural", "ensure_singular"),
+    ("ensure_plural", "to_singular"),
+    ("ensure_plural", "ensure_singular"),
+    ("ordinalize", "deordinalize"),
+    ("ordinalize", "ordinalize_words"),
+    ("number_to_words", "ordinalize_words"),
+    ("to_roman", "from_roman"),
+];
+
+/// Parse operations separated by `|`, with an optional inline value after `=`.
+fn parse_ops(ops: &str, strict: bool) -> Result<Vec<(&str, Option<&str>)>, RenderError> {
+    let mut steps = Vec::new();
+
+    for op in ops.split('|').map(str::trim).filter(|op| !op.is_empty()) {
+        let (name, value) = match op.split_once('=') {
+            Some((name, value)) => (name.trim(), Some(value.trim())),
+            None => (op, None),
+        };
+
+        let name = canonical(name);
+
+        let known = match value {
+            Some(_) => VALUE_OPERATIONS.contains(&name),
+            None => OPERATIONS.contains(&name),
+        };
+
+        if known {
+            steps.push((name, value));
+        } else if strict {
+            return Err(
+                RenderErrorReason::Other(format!("inflect: unknown operation `{}`", op)).into(),
+            );
+        }
+    }
+
+    Ok(steps)
+}
+
+/// Value of the operation `name`: the inline `value` if given, the hash option otherwise.
+fn op_value<T: FromStr>(
+    h: &Helper,
+    strict: bool,
+    name: &str,
+    value: Option<&str>,
+    expected: &str,
+) -> Result<Option<T>, RenderError> {
+    let Some(value) = value else {
+        return hash_option(h, strict, name, expected);
+    };
+
+    match value.parse() {
+        Ok(value) => Ok(Some(value)),
+        Err(_) if strict => Err(RenderErrorReason::HashTypeMismatchForName(
+            "inflect",
+            name.to_string(),
+            expected.to_string(),
+        )
+        .into()),
+        Err(_) => Ok(None),
+    }
+}
+
+/// Predicate telling whether a string already is in a given case convention.
+type CasePredicate = fn(&str) -> bool;
+
+/// Case conventions and their predicates in the order of execution.
+const CONVENTIONS: &[(&str, CasePredicate)] = &[
+    ("to_camel_case", |s| s.is_camel_case()),
+    ("to_pascal_case", |s| s.is_pascal_case()),
+    ("to_snake_case", |s| s.is_snake_case()),
+    ("to_screaming_snake_case", |s| s.is_screaming_snake_case()),
+    ("to_kebab_case", |s| s.is_kebab_case()),
+    ("to_train_case", |s| s.is_train_case()),
+    ("to_dot_case", |s| is_lower_separated(s, '.')),
+    ("to_path_case", |s| is_lower_separated(s, '/')),
+    ("to_sentence_case", |s| s.is_sentence_case()),
+    ("to_title_case", |s| s.is_title_case()),
+    ("to_foreign_key", |s| s.is_foreign_key()),
+    ("to_class_case", |s| s.is_class_case()),
+    ("to_table_case", |s| s.is_table_case()),
+    ("capitalize", |s| !s.starts_with(char::is_lowercase)),
+    ("decapitalize", |s| !s.starts_with(char::is_uppercase)),
+    ("to_upper_case", |s| s.to_uppercase() == s),
+    ("to_lower_case", |s| s.to_lowercase() == s),
+];
+
+/// Whether `input` consists of lowercase words joined with `separator`, like `dot.case`.
+fn is_lower_separated(input: &str, separator: char) -> bool {
+    input.split(separator).all(|word| {
+        !word.is_empty() && word.chars().all(|c| c.is_lowercase() || c.is_ascii_digit())
+    })
+}
+
+/// Whether `input` already is in the last case convention requested by the helper call.
+fn matches_target_case(steps: &[(&str, Option<&str>)], input: &str) -> bool {
+    steps
+        .iter()
+        .rev()
+        .find_map(|(name, _)| CONVENTIONS.iter().find(|(op, _)| op == name))
+        .is_some_and(|(_, is_target)| is_target(input))
+}
+
+/// Pluralize `input` unless it already is in plural form.
+///
+/// A word is considered plural when pluralizing it is a no-op (`statuses`, `sheep`) or when
+/// singularizing it changes it (`mice`). Common irregular nouns (`person`, `people`) are looked up
+/// before that, as Inflector inflects them again. Blank input is returned as it is.
+fn ensure_plural(input: String) -> String {
+    if input.trim().is_empty() {
+        return input;
+    }
+
+    if let Some(output) = inflect_irregular(&input, true) {
+        return output;
+    }
+
+    if input.to_plural() == input || input.to_singular() != input {
+        input
+    } else {
+        input.to_plural()
+    }
+}
+
+/// Singularize `input` unless it already is in singular form.
+///
+/// A word is considered singular when pluralizing it changes it (`status`, `analysis`). Common
+/// irregular nouns are looked up first and blank input is returned as it is, like in
+/// [`ensure_plural`].
+fn ensure_singular(input: String) -> String {
+    if input.trim().is_empty() {
+        return input;
+    }
+
+    if let Some(output) = inflect_irregular(&input, false) {
+        return output;
+    }
+
+    if input.to_plural() != input {
+        input
+    } else {
+        input.to_singular()
+    }
+}
+
+/// Make the last word of a `<number> <words>` input agree with the number.
+///
+/// The number may contain thousands separators (`1,000 user`). Input without a leading number
+/// is left untouched. Punctuation after the noun is kept after the inflected noun (`5 box.` to
+/// `5 boxes.`).
+fn agree_with_count(input: String) -> String {
+    let Some(split) = input.find(char::is_whitespace) else {
+        return input;
+    };
+
+    let (number, words) = input.split_at(split);
+
+    let Some(count) = parse_count(number) else {
+        return input;
+    };
+
+    let (words, trailing) =
+        words.split_at(words.trim_end_matches(|c: char| !c.is_alphanumeric()).len());
+    let (head, noun) = words.split_at(words.rfind(char::is_whitespace).map_or(0, |i| i + 1));
+
+    if noun.is_empty() {
+        return input;
+    }
+
+    let noun = if is_singular(count) {
+        ensure_singular(noun.to_string())
+    } else {
+        ensure_plural(noun.to_string())
+    };
+
+    format!("{}{}{}{}", number, head, noun, trailing)
+}
+
+/// Parse a count written as ASCII digits with an optional `-`, `,` thousands separators and
+/// one `.`, so words like `inf` and exponents like `1e3` aren't taken for numbers.
+fn parse_count(token: &str) -> Option<f64> {
+    let digits = token.strip_prefix('-').unwrap_or(token);
+    let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+
+    let valid = integer.starts_with(|c: char| c.is_ascii_digit())
+        && integer.chars().all(|c| c.is_ascii_digit() || c == ',')
+        && fraction.chars().all(|c| c.is_ascii_digit());
+
+    if !valid {
+        return None;
+    }
+
+    token.replace(',', "").parse().ok()
+}
+
+/// Shorten `input` to at most `max` characters including `omission`, cutting between words.
+///
+/// Input that fits is left untouched. Trailing punctuation before the cut is dropped, and when
+/// not even the first word fits only the omission remains, itself cut to `max` characters.
+fn truncate_words(input: String, max: usize, omission: &str) -> String {
+    if input.chars().count() <= max {
+        return input;
+    }
+
+    let omission = omission
+        .char_indices()
+        .nth(max)
+        .map_or(omission, |(i, _)| &omission[..i]);
+
+    let budget = max.saturating_sub(omission.chars().count());
+    let end = input
+        .char_indices()
+        .nth(budget)
+        .map_or(input.len(), |(i, _)| i);
+
+    let cut = if input[end..].starts_with(char::is_whitespace) {
+        end
+    } else {
+        input[..end].rfind(char::is_whitespace).unwrap_or(0)
+    };
+
+    let kept = input[..cut].trim_end_matches(|c: char| c.is_whitespace() || ",;:".contains(c));
+
+    format!("{}{}", kept, omission)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use handlebars::Handlebars;
+    use serde_json::json;
+
+    #[test]
+    fn it_works() {
+        let mut h = Handlebars::new();
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+
+        assert_eq!(
+            h.render_template(
+                r#"{{inflect this to_camel_case=true}}"#,
+                &String::from("this is a test")
+            )
+            .expect("Render error"),
+            "thisIsATest",
+            "Failed to test to_camel_case"
+        );
+        assert_eq!(
+            h.render_template(
+                r#"{{inflect this to_pascal_case=true}}"#,
+                &String::from("product_images")
+            )
+            .expect("Render error"),
+            "ProductImages",
+            "Failed to test to_pascal_case"
+        );
+        assert_eq!(
+            h.render_template(
+                r#"{{inflect this to_snake_case=true}}"#,
+                &String::from("ProductImages")
+            )
+            .expect("Render error"),
+            "product_images",
+            "Failed to test to_snake_case"
+        );
+        assert_eq!(
+            h.render_template(
+                r#"{{inflect this to_screaming_snake_case=true}}"#,
+                &String::from("ProductImages")
+            )
+            .expect("Render error"),
+            "PRODUCT_IMAGES",
+            "Failed to test to_screaming_snake_case"
+        );
+        assert_eq!(
+            h.render_template(
+                r#"{{inflect this to_kebab_case=true}}"#,
+                &String::from("product_images")
+            )
+            .expect("Render error"),
+            "product-images",
+            "Failed to test to_kebab_case"
+        );
+        assert_eq!(
+            h.render_template(
+                r#"{{inflect this to_sentence_case=true}}"#,
+                &String::from("product_images")
+            )
+            .expect("Render error"),
+            "Product images",
+            "Failed to test to_sentence_case"
+        );
+        assert_eq!(
+            h.render_template(
+                r#"{{inflect this to_title_case=true}}"#,
+                &String::from("product_images")
+            )
+            .expect("Render error"),
+            "Product Images",
+            "Failed to test to_title_case"
+        );
+        assert_eq!(
+            h.render_template(
+                r#"{{inflect this ordinalize=true}}"#,
+                &String::from("July 1")
+            )
+            .expect("Render error"),
+            "July 1st",
+            "Failed to test ordinalize"
+        );
+        assert_eq!(
+            h.render_template(
+                r#"{{inflect this deordinalize=true}}"#,
+                &String::from("July 1st")
+            )
+            .expect("Render error"),
+            "July 1",
+            "Failed to test deordinalize"
+        );
+        assert_eq!(
+            h.render_template(
+                r#"{{inflect this to_foreign_key=true}}"#,
+                &String::from("ProductImage")
+            )
+            .expect("Render error"),
+            "product_image_id",
+            "Failed to test to_foreign_key"
+        );
+        assert_eq!(
+            h.render_template(
+                r#"{{inflect this demodulize=true}}"#,
+                &String::from("Foo::Bar")
+            )
+            .expect("Render error"),
+            "Bar",
+            "Failed to test demodulize"
+        );
+        assert_eq!(
+            h.render_template(
+                r#"{{inflect this deconstantize=true}}"#,
+                &String::from("Foo::Bar")
+            )
+            .expect("Render error"),
+            "Foo",
+            "Failed to test deconstantize"
+        );
+        assert_eq!(
+            h.render_template(
+                r#"{{inflect this to_class_case=true}}"#,
+                &String::from("product_images")
+            )
+            .expect("Render error"),
+            "ProductImage",
+            "Failed to test to_class_case"
+        );
+        assert_eq!(
+            h.render_template(
+                r#"{{inflect this to_table_case=true}}"#,
+                &String::from("ProductImage")
+            )
+            .expect("Render error"),
+            "product_images",
+            "Failed to test to_table_case"
+        );
+        assert_eq!(
+            h.render_template(
+                r#"{{inflect this to_plural=true}}"#,
+                &String::from("product image")
+            )
+            .expect("Render error"),
+            "product images",
+            "Failed to test to_plural"
+        );
+        assert_eq!(
+            h.render_template(
+                r#"{{inflect this to_singular=true}}"#,
+                &String::from("product_images")
+            )
+            .expect("Render error"),
+            "product_image",
+            "Failed to test to_singular"
+        );
+        assert_eq!(
+            h.render_template(
+                r#"{{inflect this to_upper_case=true}}"#,
                 &String::from("product image")
             )
             .expect("Render error"),
-            "product images",
-            "Failed to test to_plural"
+            "PRODUCT IMAGE",
+            "Failed to test to_upper_case"
+        );
+        assert_eq!(
+            h.render_template(
+                r#"{{inflect this to_lower_case=true}}"#,
+                &String::from("PRODUCT IMAGE")
+            )
+            .expect("Render error"),
+            "product image",
+            "Failed to test to_lower_case"
+        );
+    }
+
+    #[test]
+    fn it_ensures_grammatical_number() {
+        let mut h = Handlebars::new();
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+
+        for (input, expected) in [
+            ("status", "statuses"),
+            ("statuses", "statuses"),
+            ("product_image", "product_images"),
+            ("product_images", "product_images"),
+            ("mice", "mice"),
+            ("person", "people"),
+            ("people", "people"),
+            ("child", "children"),
+            ("children", "children"),
+            ("datum", "data"),
+            ("data", "data"),
+            ("TeamMember", "TeamMembers"),
+            ("SalesPerson", "SalesPeople"),
+            ("", ""),
+            (" ", " "),
+        ] {
+            assert_eq!(
+                h.render_template(r#"{{inflect this ensure_plural=true}}"#, &input)
+                    .expect("Render error"),
+                expected,
+                "Failed to test ensure_plural"
+            );
+        }
+
+        for (input, expected) in [
+            ("statuses", "status"),
+            ("status", "status"),
+            ("analysis", "analysis"),
+            ("product_images", "product_image"),
+            ("product_image", "product_image"),
+            ("people", "person"),
+            ("person", "person"),
+            ("children", "child"),
+            ("child", "child"),
+            ("data", "datum"),
+            ("datum", "datum"),
+            ("", ""),
+            (" ", " "),
+        ] {
+            assert_eq!(
+                h.render_template(r#"{{inflect this ensure_singular=true}}"#, &input)
+                    .expect("Render error"),
+                expected,
+                "Failed to test ensure_singular"
+            );
+        }
+    }
+
+    #[test]
+    fn it_pluralizes_by_count() {
+        let mut h = Handlebars::new();
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+
+        for (count, expected) in [
+            (serde_json::json!(1), "item"),
+            (serde_json::json!(3), "items"),
+            (serde_json::json!(0), "items"),
+            (serde_json::json!("1"), "item"),
+            (serde_json::json!(["a"]), "item"),
+            (serde_json::json!(["a", "b"]), "items"),
+            (serde_json::json!(null), "item"),
+        ] {
+            assert_eq!(
+                h.render_template(
+                    r#"{{inflect "item" count=n}}"#,
+                    &serde_json::json!({ "n": count })
+                )
+                .expect("Render error"),
+                expected,
+                "Failed to test count={}",
+                count
+            );
+        }
+
+        assert_eq!(
+            h.render_template(r#"{{inflect "items" count=1}}"#, &())
+                .expect("Render error"),
+            "item"
+        );
+        assert_eq!(
+            h.render_template(r#"{{inflect "goose" ops="count=2|to_upper_case"}}"#, &())
+                .expect("Render error"),
+            "GEESE"
+        );
+
+        h.set_strict_mode(true);
+
+        assert!(h
+            .render_template(r#"{{inflect "item" count=true}}"#, &())
+            .is_err());
+    }
+
+    #[test]
+    fn it_agrees_with_counts() {
+        let mut h = Handlebars::new();
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+
+        for (input, expected) in [
+            ("5 box", "5 boxes"),
+            ("1 boxes", "1 box"),
+            ("0 status", "0 statuses"),
+            ("1,000 active user", "1,000 active users"),
+            ("2.5 hour", "2.5 hours"),
+            ("box", "box"),
+            ("5 ", "5 "),
+            ("five box", "five box"),
+            ("inf loop", "inf loop"),
+            ("Infinity stone", "Infinity stone"),
+            ("1e3 box", "1e3 box"),
+            ("NaN box", "NaN box"),
+            ("5 box.", "5 boxes."),
+            ("1 boxes!", "1 box!"),
+            ("-3 degree", "-3 degrees"),
+            ("1.2.3 box", "1.2.3 box"),
+            ("5 ...", "5 ..."),
+        ] {
+            assert_eq!(
+                h.render_template(r#"{{inflect this agree_count=true}}"#, &input)
+                    .expect("Render error"),
+                expected,
+                "Failed to test agree_count"
+            );
+        }
+    }
+
+    #[test]
+    fn it_renders_blocks() {
+        let mut h = Handlebars::new();
+        h.set_strict_mode(true);
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+
+        let template =
+            r#"{{#inflect name to_title_case=true}}<h1>{{this}}</h1>{{else}}Untitled{{/inflect}}"#;
+
+        for (data, expected) in [
+            (
+                serde_json::json!({ "name": "product_images" }),
+                "<h1>Product Images</h1>",
+            ),
+            (serde_json::json!({ "name": null }), "Untitled"),
+            (serde_json::json!({ "name": 42 }), "Untitled"),
+            (
+                serde_json::json!({ "name": ["product_images", "users"] }),
+                "<h1>Product Images, Users</h1>",
+            ),
+            (serde_json::json!({ "name": [] }), "Untitled"),
+        ] {
+            assert_eq!(
+                h.render_template(template, &data).expect("Render error"),
+                expected,
+                "Failed to test {}",
+                data
+            );
+        }
+
+        let template = r#"{{#inflect name default="no_name" to_title_case=true}}<h1>{{this}}</h1>{{else}}Untitled{{/inflect}}"#;
+
+        for (data, expected) in [
+            (serde_json::json!({ "name": null }), "<h1>No Name</h1>"),
+            (serde_json::json!({ "name": "" }), "<h1>No Name</h1>"),
+            (serde_json::json!({}), "<h1>No Name</h1>"),
+            (serde_json::json!({ "name": 42 }), "Untitled"),
+        ] {
+            assert_eq!(
+                h.render_template(template, &data).expect("Render error"),
+                expected,
+                "Failed to test {} with default",
+                data
+            );
+        }
+
+        h.register_partial("title", "{{name}} list")
+            .expect("Partial error");
+
+        assert_eq!(
+            h.render_template(
+                r#"{{#inflect to_kebab_case=true}}{{> title}}{{/inflect}}"#,
+                &serde_json::json!({ "name": "ProductImage" })
+            )
+            .expect("Render error"),
+            "product-image-list"
+        );
+        assert_eq!(
+            h.render_template(
+                r#"{{#each names}}{{#inflect to_snake_case=true}}{{this}}{{/inflect}} {{/each}}"#,
+                &serde_json::json!({ "names": ["ProductImage", "UserID"] })
+            )
+            .expect("Render error"),
+            "product_image user_id "
+        );
+        assert_eq!(
+            h.render_template(r#"{{#inflect 42}}x{{/inflect}}"#, &())
+                .expect("Render error"),
+            ""
+        );
+    }
+
+    #[test]
+    fn it_applies_ordered_ops() {
+        let mut h = Handlebars::new();
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+
+        for (template, input, expected) in [
+            (
+                r#"{{inflect this ops="to_singular|to_pascal_case"}}"#,
+                "product_images",
+                "ProductImage",
+            ),
+            (
+                r#"{{inflect this ops="to_upper_case|to_plural"}}"#,
+                "box",
+                "BOXs",
+            ),
+            (
+                r#"{{inflect this ops="to_plural|to_upper_case"}}"#,
+                "box",
+                "BOXES",
+            ),
+            (
+                r#"{{inflect this ops="to_snake_case | to_upper_case | to=kebab"}}"#,
+                "ProductImage",
+                "product-image",
+            ),
+            (
+                r#"{{inflect this ops="to|to_plural" to="snake"}}"#,
+                "ProductImage",
+                "product_images",
+            ),
+            (
+                r#"{{inflect this ops="to_lower_case" to_upper_case=true}}"#,
+                "ProductImage",
+                "productimage",
+            ),
+            (
+                r#"{{inflect this ops="deconstantize|to_singular"}}"#,
+                "Bars::Foos",
+                "Bar",
+            ),
+            (
+                r#"{{inflect this ops="to_snake_case|nope"}}"#,
+                "ProductImage",
+                "product_image",
+            ),
+        ] {
+            assert_eq!(
+                h.render_template(template, &input).expect("Render error"),
+                expected,
+                "Failed to test {}",
+                template
+            );
+        }
+
+        h.set_strict_mode(true);
+
+        assert!(h
+            .render_template(r#"{{inflect this ops="to_snake_case|nope"}}"#, &"x")
+            .is_err());
+        assert!(h
+            .render_template(r#"{{inflect this ops="to_plural=yes"}}"#, &"x")
+            .is_err());
+        assert!(h
+            .render_template(r#"{{inflect this ops="to=nope"}}"#, &"x")
+            .is_err());
+    }
+
+    #[test]
+    fn it_returns_strings_from_subexpressions() {
+        let mut h = Handlebars::new();
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+
+        for (template, expected) in [
+            (
+                r#"{{#if (eq (inflect this to_snake_case=true) "product_image")}}yes{{/if}}"#,
+                "yes",
+            ),
+            (
+                r#"{{#if (eq (inflect "1st" deordinalize=true) "1")}}yes{{/if}}"#,
+                "yes",
+            ),
+            (r#"{{#if (inflect "true")}}yes{{/if}}"#, "yes"),
+            (r#"{{#if (inflect 42)}}yes{{else}}no{{/if}}"#, "no"),
+        ] {
+            assert_eq!(
+                h.render_template(template, &"ProductImage")
+                    .expect("Render error"),
+                expected,
+                "Failed to test {}",
+                template
+            );
+        }
+    }
+
+    #[test]
+    fn it_applies_custom_irregulars() {
+        let mut h = Handlebars::new();
+        h.register_helper(
+            "inflect",
+            Box::new(
+                HandlebarsInflector::new()
+                    .with_rules([("octopus", "octopuses"), ("schema", "schemas")]),
+            ),
+        );
+
+        for (template, input, expected) in [
+            (r#"{{inflect this to_plural=true}}"#, "octopus", "octopuses"),
+            (
+                r#"{{inflect this to_plural=true}}"#,
+                "ProductSchema",
+                "ProductSchemas",
+            ),
+            (
+                r#"{{inflect this to_singular=true}}"#,
+                "octopuses",
+                "octopus",
+            ),
+            (
+                r#"{{inflect this to_class_case=true}}"#,
+                "sea_octopuses",
+                "SeaOctopus",
+            ),
+            (
+                r#"{{inflect this to_table_case=true}}"#,
+                "SeaOctopus",
+                "sea_octopuses",
+            ),
+            (
+                r#"{{inflect this ensure_plural=true}}"#,
+                "schemas",
+                "schemas",
+            ),
+            (r#"{{inflect this count=1}}"#, "octopuses", "octopus"),
+            (r#"{{inflect this to_plural=true}}"#, "box", "boxes"),
+        ] {
+            assert_eq!(
+                h.render_template(template, &input).expect("Render error"),
+                expected,
+                "Failed to test {} with {}",
+                template,
+                input
+            );
+        }
+
+        assert_eq!(
+            HandlebarsInflector::new()
+                .with_rule_set(
+                    HandlebarsInflector::new()
+                        .with_rules([("octopus", "octopuses")])
+                        .rules()
+                )
+                .nouns
+                .irregulars
+                .get("octopus")
+                .map(String::as_str),
+            Some("octopuses")
+        );
+    }
+
+    #[test]
+    fn it_leaves_uncountable_nouns_alone() {
+        let mut h = Handlebars::new();
+        h.register_helper(
+            "inflect",
+            Box::new(HandlebarsInflector::new().with_uncountable(["Equipment", "information"])),
+        );
+
+        for (template, input, expected) in [
+            (
+                r#"{{inflect this to_plural=true}}"#,
+                "equipment",
+                "equipment",
+            ),
+            (
+                r#"{{inflect this to_plural=true}}"#,
+                "MiningEquipment",
+                "MiningEquipment",
+            ),
+            (
+                r#"{{inflect this to_class_case=true}}"#,
+                "mining_equipment",
+                "MiningEquipment",
+            ),
+            (
+                r#"{{inflect this to_table_case=true}}"#,
+                "MiningEquipment",
+                "mining_equipment",
+            ),
+            (r#"{{inflect this count=3}}"#, "information", "information"),
+            (r#"{{inflect this to_plural=true}}"#, "item", "items"),
+        ] {
+            assert_eq!(
+                h.render_template(template, &input).expect("Render error"),
+                expected,
+                "Failed to test {} with {}",
+                template,
+                input
+            );
+        }
+    }
+
+    #[cfg(feature = "i18n")]
+    #[test]
+    fn it_pluralizes_by_locale() {
+        let mut h = Handlebars::new();
+        h.register_helper(
+            "inflect",
+            Box::new(
+                HandlebarsInflector::new()
+                    .with_locale(Locale::German)
+                    .with_rules([("Haus", "Häuser")]),
+            ),
+        );
+
+        for (template, input, expected) in [
+            (r#"{{inflect this to_plural=true}}"#, "Zeitung", "Zeitungen"),
+            (r#"{{inflect this to_singular=true}}"#, "Blumen", "Blume"),
+            (r#"{{inflect this count=1}}"#, "Autos", "Auto"),
+            (r#"{{inflect this to_plural=true}}"#, "Haus", "Häuser"),
+            (
+                r#"{{inflect this to_plural=true locale="es-MX"}}"#,
+                "canción",
+                "canciones",
+            ),
+            (
+                r#"{{inflect this ensure_plural=true locale="es"}}"#,
+                "ciudades",
+                "ciudades",
+            ),
+            (
+                r#"{{inflect this to_plural=true locale="en"}}"#,
+                "box",
+                "boxes",
+            ),
+            (
+                r#"{{inflect this to_plural=true locale="xx"}}"#,
+                "Tag",
+                "Tage",
+            ),
+        ] {
+            assert_eq!(
+                h.render_template(template, &input).expect("Render error"),
+                expected,
+                "Failed to test {} with {}",
+                template,
+                input
+            );
+        }
+
+        h.set_strict_mode(true);
+
+        assert!(
+            h.render_template(r#"{{inflect this to_plural=true locale="xx"}}"#, &"Tag")
+                .is_err(),
+            "Failed to test unknown locale in strict mode"
+        );
+    }
+
+    #[test]
+    fn it_applies_named_pipelines() {
+        let mut inflector =
+            HandlebarsInflector::new().with_pipeline("class_name", "to_pascal_case");
+        inflector.register_pipeline("table_name", "to_snake_case|to_plural");
+
+        let mut h = Handlebars::new();
+        h.register_helper("inflect", Box::new(inflector));
+
+        for (template, input, expected) in [
+            (
+                r#"{{inflect this pipeline="table_name"}}"#,
+                "ProductImage",
+                "product_images",
+            ),
+            (
+                r#"{{inflect this pipeline="class_name"}}"#,
+                "product_image",
+                "ProductImage",
+            ),
+            (
+                r#"{{inflect this pipeline="table_name" ops="to_kebab_case"}}"#,
+                "ProductImage",
+                "product-image",
+            ),
+            (
+                r#"{{inflect this pipeline="missing" to_kebab_case=true}}"#,
+                "ProductImage",
+                "product-image",
+            ),
+        ] {
+            assert_eq!(
+                h.render_template(template, &input).expect("Render error"),
+                expected,
+                "Failed to test {} with {}",
+                template,
+                input
+            );
+        }
+
+        h.set_strict_mode(true);
+
+        assert!(
+            h.render_template(r#"{{inflect this pipeline="missing"}}"#, &"ProductImage")
+                .is_err(),
+            "Failed to test unknown pipeline in strict mode"
+        );
+    }
+
+    #[test]
+    fn it_rejects_conflicting_flags() {
+        let mut h = Handlebars::new();
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+
+        for template in [
+            r#"{{inflect this to_upper_case=true to_lower_case=true}}"#,
+            r#"{{inflect this to_plural=true to_singular=true}}"#,
+            r#"{{inflect this ensure_plural=true ensure_singular=true}}"#,
+            r#"{{inflect this ordinalize=true deordinalize=true}}"#,
+        ] {
+            h.set_strict_mode(false);
+            assert!(
+                h.render_template(template, &"box").is_ok(),
+                "Failed to test {}",
+                template
+            );
+
+            h.set_strict_mode(true);
+            assert!(
+                h.render_template(template, &"box").is_err(),
+                "Failed to test {} in strict mode",
+                template
+            );
+        }
+
+        assert_eq!(
+            h.render_template(r#"{{inflect this ops="to_plural|to_singular"}}"#, &"box")
+                .expect("Render error"),
+            "box"
+        );
+    }
+
+    #[test]
+    fn it_falls_back_to_defaults() {
+        let mut h = Handlebars::new();
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+
+        for (template, data, expected) in [
+            (
+                r#"{{inflect title default="untitled" to_pascal_case=true}}"#,
+                json!({ "title": null }),
+                "Untitled",
+            ),
+            (
+                r#"{{inflect title default="untitled" to_pascal_case=true}}"#,
+                json!({ "title": "" }),
+                "Untitled",
+            ),
+            (
+                r#"{{inflect title default="untitled" to_pascal_case=true}}"#,
+                json!({}),
+                "Untitled",
+            ),
+            (
+                r#"{{inflect title default="untitled" to_pascal_case=true}}"#,
+                json!({ "title": "first_post" }),
+                "FirstPost",
+            ),
+            (r#"{{inflect default="n/a"}}"#, json!({}), "n/a"),
+            (r#"{{inflect title to_pascal_case=true}}"#, json!({}), ""),
+        ] {
+            assert_eq!(
+                h.render_template(template, &data).expect("Render error"),
+                expected,
+                "Failed to test {} with {}",
+                template,
+                data
+            );
+        }
+    }
+
+    #[test]
+    fn it_coerces_scalars() {
+        let mut h = Handlebars::new();
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+        h.register_helper(
+            "coerce",
+            Box::new(HandlebarsInflector::new().with_coercion(true)),
+        );
+
+        for (template, expected) in [
+            (r#"{{inflect 1 ordinalize=true coerce=true}}"#, "1st"),
+            (r#"{{inflect 1 to_upper_case=true}}"#, ""),
+            (r#"{{inflect 21 ordinalize=true}}"#, "21st"),
+            (r#"{{inflect 2 ops="ordinalize|to_upper_case"}}"#, "2ND"),
+            (r#"{{coerce 22 ordinalize=true}}"#, "22nd"),
+            (r#"{{coerce 22 to_upper_case=true coerce=false}}"#, ""),
+            (r#"{{coerce true to_upper_case=true}}"#, "TRUE"),
+            (r#"{{coerce null to_upper_case=true}}"#, ""),
+            (r#"{{coerce null default="none"}}"#, "none"),
+            (r#"{{#coerce 3 ordinalize=true}}{{this}}{{/coerce}}"#, "3rd"),
+        ] {
+            assert_eq!(
+                h.render_template(template, &()).expect("Render error"),
+                expected,
+                "Failed to test {}",
+                template
+            );
+        }
+
+        h.set_strict_mode(true);
+
+        assert!(
+            h.render_template(r#"{{inflect 1 to_upper_case=true}}"#, &())
+                .is_err(),
+            "Failed to test number in strict mode"
+        );
+        assert!(
+            h.render_template(r#"{{coerce 1 ordinalize=true}}"#, &())
+                .is_ok(),
+            "Failed to test coerced number in strict mode"
+        );
+    }
+
+    #[test]
+    fn it_joins_array_items() {
+        let mut h = Handlebars::new();
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+
+        let data = json!({ "columns": ["id", "firstName", 3, "CreatedAt"], "empty": [] });
+
+        for (template, expected) in [
+            (
+                r#"{{inflect columns to_snake_case=true}}"#,
+                "id, first_name, created_at",
+            ),
+            (
+                r#"{{inflect columns to_snake_case=true join=" AND "}}"#,
+                "id AND first_name AND created_at",
+            ),
+            (
+                r#"{{inflect columns to_snake_case=true coerce=true join="|"}}"#,
+                "id|first_name|3|created_at",
+            ),
+            (r#"{{inflect empty to_snake_case=true}}"#, ""),
+        ] {
+            assert_eq!(
+                h.render_template(template, &data).expect("Render error"),
+                expected,
+                "Failed to test {}",
+                template
+            );
+        }
+
+        h.set_strict_mode(true);
+
+        assert!(
+            h.render_template(r#"{{inflect columns to_snake_case=true}}"#, &data)
+                .is_err(),
+            "Failed to test number item in strict mode"
+        );
+    }
+
+    #[test]
+    fn it_spells_out_numbers() {
+        let mut h = Handlebars::new();
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+
+        for (template, expected) in [
+            (r#"{{inflect "42" number_to_words=true}}"#, "forty-two"),
+            (r#"{{inflect 1000 number_to_words=true}}"#, "one thousand"),
+            (
+                r#"{{inflect "1,001" number_to_words=true to_title_case=true}}"#,
+                "One Thousand One",
+            ),
+            (r#"{{inflect 12 number_to_words=true words_max=10}}"#, "12"),
+            (
+                r#"{{inflect 10 number_to_words=true words_max="10"}}"#,
+                "ten",
+            ),
+            (r#"{{inflect "4.5" number_to_words=true}}"#, "4.5"),
+            (r#"{{inflect "four" number_to_words=true}}"#, "four"),
+        ] {
+            assert_eq!(
+                h.render_template(template, &()).expect("Render error"),
+                expected,
+                "Failed to test {}",
+                template
+            );
+        }
+
+        h.set_strict_mode(true);
+
+        assert!(
+            h.render_template(r#"{{inflect 1 number_to_words=true words_max="x"}}"#, &())
+                .is_err(),
+            "Failed to test invalid words_max in strict mode"
+        );
+    }
+
+    #[test]
+    fn it_spells_out_ordinals() {
+        let mut h = Handlebars::new();
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+
+        for (template, expected) in [
+            (r#"{{inflect 1 ordinalize_words=true}}"#, "first"),
+            (
+                r#"{{inflect "23rd" ordinalize_words=true}}"#,
+                "twenty-third",
+            ),
+            (
+                r#"{{inflect "40" ordinalize_words=true to_title_case=true}}"#,
+                "Fortieth",
+            ),
+            (
+                r#"{{inflect 112 ordinalize_words=true words_max=100}}"#,
+                "112th",
+            ),
+            (r#"{{inflect "first" ordinalize_words=true}}"#, "first"),
+        ] {
+            assert_eq!(
+                h.render_template(template, &()).expect("Render error"),
+                expected,
+                "Failed to test {}",
+                template
+            );
+        }
+    }
+
+    #[test]
+    fn it_converts_roman_numerals() {
+        let mut h = Handlebars::new();
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+
+        for (template, expected) in [
+            (r#"{{inflect 4 to_roman=true}}"#, "IV"),
+            (
+                r#"{{inflect "1994" to_roman=true to_lower_case=true}}"#,
+                "mcmxciv",
+            ),
+            (r#"{{inflect 4000 to_roman=true}}"#, "4000"),
+            (r#"{{inflect "xiv" from_roman=true}}"#, "14"),
+            (
+                r#"{{inflect "XIV" from_roman=true number_to_words=true}}"#,
+                "fourteen",
+            ),
+            (r#"{{inflect "Mix" from_roman=true}}"#, "1009"),
+            (r#"{{inflect "Mixed" from_roman=true}}"#, "Mixed"),
+        ] {
+            assert_eq!(
+                h.render_template(template, &()).expect("Render error"),
+                expected,
+                "Failed to test {}",
+                template
+            );
+        }
+    }
+
+    #[test]
+    fn it_humanizes() {
+        let mut h = Handlebars::new();
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+        h.register_helper(
+            "acronyms",
+            Box::new(HandlebarsInflector::new().with_acronyms(["SSL", "API"])),
+        );
+
+        for (template, input, expected) in [
+            (
+                r#"{{inflect this humanize=true}}"#,
+                "employee_salary_id",
+                "Employee salary",
+            ),
+            (r#"{{inflect this humanize=true}}"#, "author_id", "Author"),
+            (
+                r#"{{inflect this humanize=true}}"#,
+                "Hello__World",
+                "Hello world",
+            ),
+            (r#"{{inflect this humanize=true}}"#, "id", "Id"),
+            (r#"{{inflect this humanize=true}}"#, "", ""),
+            (
+                r#"{{acronyms this humanize=true}}"#,
+                "ssl_error",
+                "SSL error",
+            ),
+            (
+                r#"{{acronyms this humanize=true}}"#,
+                "api_key_id",
+                "API key",
+            ),
+        ] {
+            assert_eq!(
+                h.render_template(template, &input).expect("Render error"),
+                expected,
+                "Failed to test {} with {}",
+                template,
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn it_parameterizes() {
+        let mut h = Handlebars::new();
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+
+        for (template, input, expected) in [
+            (
+                r#"{{inflect this parameterize=true}}"#,
+                "Crème Brûlée",
+                "creme-brulee",
+            ),
+            (
+                r#"{{inflect this parameterize=true separator="_"}}"#,
+                "Crème Brûlée",
+                "creme_brulee",
+            ),
+            (
+                r#"{{inflect this parameterize=true}}"#,
+                "Don't Stop: Believin’!",
+                "dont-stop-believin",
+            ),
+            (
+                r#"{{inflect this ops="parameterize|to_upper_case"}}"#,
+                "Crème Brûlée",
+                "CREME-BRULEE",
+            ),
+        ] {
+            assert_eq!(
+                h.render_template(template, &input).expect("Render error"),
+                expected,
+                "Failed to test {} with {}",
+                template,
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn it_applies_title_styles() {
+        let mut h = Handlebars::new();
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+
+        for (template, input, expected) in [
+            (
+                r#"{{inflect this to_title_case=true style="ap"}}"#,
+                "the_lord_of_the_rings",
+                "The Lord of the Rings",
+            ),
+            (
+                r#"{{inflect this to_title_case=true style="chicago"}}"#,
+                "gone with the wind",
+                "Gone with the Wind",
+            ),
+            (
+                r#"{{inflect this to_title_case=true}}"#,
+                "gone with the wind",
+                "Gone With The Wind",
+            ),
+            (
+                r#"{{inflect this to_title_case=true style="mla"}}"#,
+                "gone with the wind",
+                "Gone With The Wind",
+            ),
+        ] {
+            assert_eq!(
+                h.render_template(template, &input).expect("Render error"),
+                expected,
+                "Failed to test {} with {}",
+                template,
+                input
+            );
+        }
+
+        h.set_strict_mode(true);
+        assert!(
+            h.render_template(
+                r#"{{inflect this to_title_case=true style="mla"}}"#,
+                &"gone with the wind"
+            )
+            .is_err(),
+            "Failed to test unknown style in strict mode"
+        );
+    }
+
+    #[test]
+    fn it_changes_character_case() {
+        let mut h = Handlebars::new();
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+
+        for (template, input, expected) in [
+            (
+                r#"{{inflect this capitalize=true}}"#,
+                "productImages",
+                "ProductImages",
+            ),
+            (
+                r#"{{inflect this capitalize=true}}"#,
+                "the HTTP_client",
+                "The HTTP_client",
+            ),
+            (
+                r#"{{inflect this decapitalize=true}}"#,
+                "ProductImages",
+                "productImages",
+            ),
+            (
+                r#"{{inflect this decapitalize=true}}"#,
+                "URLParser",
+                "uRLParser",
+            ),
+            (r#"{{inflect this capitalize=true}}"#, "état", "État"),
+            (
+                r#"{{inflect this swap_case=true}}"#,
+                "hELLO wORLD",
+                "Hello World",
+            ),
+            (r#"{{inflect this swap_case=true}}"#, "ÉTAT_2", "état_2"),
+            (
+                r#"{{inflect this ops="swap_case|decapitalize"}}"#,
+                "PRODUCTiMAGES",
+                "productImages",
+            ),
+            (r#"{{inflect this decapitalize=true}}"#, "", ""),
+            (
+                r#"{{inflect this to_plural=true capitalize=true}}"#,
+                "productImage",
+                "ProductImages",
+            ),
+        ] {
+            assert_eq!(
+                h.render_template(template, &input).expect("Render error"),
+                expected,
+                "Failed to test {} with {}",
+                template,
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn it_handles_prefixes_and_suffixes() {
+        let mut h = Handlebars::new();
+        h.register_helper(
+            "inflect",
+            Box::new(HandlebarsInflector::new().with_reserved(["get_type"])),
+        );
+
+        for (template, input, expected) in [
+            (
+                r#"{{inflect this to_snake_case=true to_plural=true suffix="_controller"}}"#,
+                "User",
+                "users_controller",
+            ),
+            (
+                r#"{{inflect this to_pascal_case=true prefix="I"}}"#,
+                "repository",
+                "IRepository",
+            ),
+            (
+                r#"{{inflect this to_snake_case=true prefix="get_"}}"#,
+                "Type",
+                "get_type_",
+            ),
+            (
+                r#"{{inflect this to_snake_case=true suffix="_controller"}}"#,
+                "",
+                "",
+            ),
+            (
+                r#"{{inflect this suffix="_id" default="owner"}}"#,
+                "",
+                "owner_id",
+            ),
+            (
+                r#"{{inflect this strip_prefix="tbl_" to_pascal_case=true}}"#,
+                "tbl_product_images",
+                "ProductImages",
+            ),
+            (
+                r#"{{inflect this strip_prefix="tbl_" to_pascal_case=true}}"#,
+                "product_images",
+                "ProductImages",
+            ),
+            (
+                r#"{{inflect this strip_prefix="I" to_snake_case=true}}"#,
+                "IRepository",
+                "repository",
+            ),
+            (
+                r#"{{inflect this strip_suffix="Dto" to_snake_case=true suffix="_record"}}"#,
+                "UserDto",
+                "user_record",
+            ),
+        ] {
+            assert_eq!(
+                h.render_template(template, &input).expect("Render error"),
+                expected,
+                "Failed to test {} with {}",
+                template,
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn it_names_environment_variables() {
+        let mut h = Handlebars::new();
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+
+        for (template, input, expected) in [
+            (
+                r#"{{inflect this to_env_var=true env_prefix="myapp"}}"#,
+                "db.pool-size",
+                "MYAPP_DB_POOL_SIZE",
+            ),
+            (
+                r#"{{inflect this to_env_var=true}}"#,
+                "server.maxConnections",
+                "SERVER_MAX_CONNECTIONS",
+            ),
+            (
+                r#"{{inflect this ops="to_singular|to_env_var" env_prefix="APP_"}}"#,
+                "feature_flags",
+                "APP_FEATURE_FLAG",
+            ),
+        ] {
+            assert_eq!(
+                h.render_template(template, &input).expect("Render error"),
+                expected,
+                "Failed to test {} with {}",
+                template,
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn it_applies_target_conventions() {
+        let mut h = Handlebars::new();
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+
+        for (template, input, expected) in [
+            (
+                r#"{{inflect this target="rust" kind="type"}}"#,
+                "user_account",
+                "UserAccount",
+            ),
+            (
+                r#"{{inflect this target="rust" kind="constant"}}"#,
+                "maxRetryCount",
+                "MAX_RETRY_COUNT",
+            ),
+            (
+                r#"{{inflect this target="go" kind="function"}}"#,
+                "get_user",
+                "GetUser",
+            ),
+            (
+                r#"{{inflect this target="java" kind="method"}}"#,
+                "get user",
+                "getUser",
+            ),
+            (
+                r#"{{inflect this target="python" kind="variable" from="camel"}}"#,
+                "userV2Id",
+                "user_v2_id",
+            ),
+            (
+                r#"{{inflect this ops="to_singular|target=csharp" kind="variable"}}"#,
+                "UserAccounts",
+                "userAccount",
+            ),
+            (
+                r#"{{inflect this target="cobol" kind="type"}}"#,
+                "user_account",
+                "user_account",
+            ),
+            (
+                r#"{{inflect this target="go" kind="function"}}"#,
+                "serve_http",
+                "ServeHTTP",
+            ),
+            (
+                r#"{{inflect this target="go" exported=true}}"#,
+                "user_id",
+                "UserID",
+            ),
+            (
+                r#"{{inflect this target="go" kind="type" exported=false}}"#,
+                "HttpServer",
+                "httpServer",
+            ),
+            (
+                r#"{{inflect this target="go" exported=false}}"#,
+                "parseJsonUrl",
+                "parseJSONURL",
+            ),
+            (
+                r#"{{inflect this target="java" kind="type"}}"#,
+                "user_id",
+                "UserId",
+            ),
+            (
+                r#"{{inflect this target="graphql" kind="enum_value"}}"#,
+                "inStock",
+                "IN_STOCK",
+            ),
+            (
+                r#"{{inflect this target="graphql" kind="field"}}"#,
+                "created_at",
+                "createdAt",
+            ),
+            (
+                r#"{{inflect this target="protobuf" kind="field"}}"#,
+                "createdAt",
+                "created_at",
+            ),
+            (
+                r#"{{inflect this target="protobuf" kind="enum_value" enum_name="OrderStatus"}}"#,
+                "active",
+                "ORDER_STATUS_ACTIVE",
+            ),
+            (
+                r#"{{inflect this target="protobuf" kind="enum_value" enum_name="OrderStatus"}}"#,
+                "ORDER_STATUS_UNSPECIFIED",
+                "ORDER_STATUS_UNSPECIFIED",
+            ),
+            (
+                r#"{{inflect this target="graphql" kind="enum_value" enum_name="OrderStatus"}}"#,
+                "active",
+                "ACTIVE",
+            ),
+        ] {
+            assert_eq!(
+                h.render_template(template, &input).expect("Render error"),
+                expected,
+                "Failed to test {} with {}",
+                template,
+                input
+            );
+        }
+
+        h.set_strict_mode(true);
+        assert!(
+            h.render_template(r#"{{inflect this target="rust"}}"#, &"user")
+                .is_err(),
+            "Failed to test missing kind in strict mode"
+        );
+    }
+
+    #[test]
+    fn it_configures_namespace_separators() {
+        let mut h = Handlebars::new();
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+        h.register_helper(
+            "inflect_py",
+            Box::new(HandlebarsInflector::new().with_namespace_separator(".")),
+        );
+
+        for (template, input, expected) in [
+            (
+                r#"{{inflect this demodulize=true namespace_separator="."}}"#,
+                "com.acme.Invoice",
+                "Invoice",
+            ),
+            (
+                r#"{{inflect this deconstantize=true namespace_separator="\\"}}"#,
+                "App\\Http\\Kernel",
+                "Http",
+            ),
+            (
+                r#"{{inflect this demodulize=true namespace_separator="/"}}"#,
+                "models/line_item",
+                "LineItem",
+            ),
+            (
+                r#"{{inflect_py this deconstantize=true}}"#,
+                "app.auth.User",
+                "Auth",
+            ),
+            (
+                r#"{{inflect_py this demodulize=true namespace_separator="::"}}"#,
+                "app::models::User",
+                "User",
+            ),
+            (r#"{{inflect this demodulize=true}}"#, "std::io", "Io"),
+        ] {
+            assert_eq!(
+                h.render_template(template, &input).expect("Render error"),
+                expected,
+                "Failed to test {} with {}",
+                template,
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn it_applies_table_prefixes() {
+        let mut h = Handlebars::new();
+        h.register_helper(
+            "inflect",
+            Box::new(HandlebarsInflector::new().with_table_prefix("app_")),
+        );
+
+        for (template, input, expected) in [
+            (
+                r#"{{inflect this to_table_case=true}}"#,
+                "Invoice",
+                "app_invoices",
+            ),
+            (
+                r#"{{inflect this to_class_case=true}}"#,
+                "app_invoices",
+                "Invoice",
+            ),
+            (
+                r#"{{inflect this to_table_case=true}}"#,
+                "app_invoice",
+                "app_invoices",
+            ),
+            (
+                r#"{{inflect (inflect this to_table_case=true) to_class_case=true}}"#,
+                "LineItem",
+                "LineItem",
+            ),
+            (
+                r#"{{inflect this to_table_case=true table_prefix="crm_"}}"#,
+                "Invoice",
+                "crm_invoices",
+            ),
+            (
+                r#"{{inflect this to_table_case=true table_prefix=""}}"#,
+                "Invoice",
+                "invoices",
+            ),
+            (
+                r#"{{inflect this to_class_case=true table_prefix="app"}}"#,
+                "apples",
+                "Apple",
+            ),
+            (
+                r#"{{inflect this to_class_case=true table_prefix="app"}}"#,
+                "app_invoices",
+                "Invoice",
+            ),
+            (
+                r#"{{inflect this to_class_case=true table_prefix="app"}}"#,
+                "appInvoices",
+                "Invoice",
+            ),
+            (r#"{{inflect this to_class_case=true}}"#, "app_", "App"),
+        ] {
+            assert_eq!(
+                h.render_template(template, &input).expect("Render error"),
+                expected,
+                "Failed to test {} with {}",
+                template,
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn it_strips_vendor_prefixes() {
+        let mut h = Handlebars::new();
+        h.register_helper(
+            "inflect",
+            Box::new(HandlebarsInflector::new().with_vendor_prefixes(["tbl_", "vw_", "sp_"])),
+        );
+
+        for (template, input, expected) in [
+            (
+                r#"{{inflect this to_class_case=true}}"#,
+                "tbl_users",
+                "User",
+            ),
+            (
+                r#"{{inflect this to_class_case=true}}"#,
+                "VW_ActiveOrders",
+                "ActiveOrder",
+            ),
+            (
+                r#"{{inflect this to_table_case=true}}"#,
+                "tbl_LineItem",
+                "line_items",
+            ),
+            (r#"{{inflect this to_singular=true}}"#, "sp_orders", "order"),
+            (
+                r#"{{inflect this to_plural=true}}"#,
+                "tbl_user",
+                "tbl_users",
+            ),
+            (r#"{{inflect this to_class_case=true}}"#, "tbl_", "Tbl"),
+            (
+                r#"{{inflect this to_class_case=true vendor_prefixes="v_|mv_"}}"#,
+                "mv_daily_sales",
+                "DailySale",
+            ),
+            (
+                r#"{{inflect this to_class_case=true vendor_prefixes="v_"}}"#,
+                "tbl_users",
+                "TblUser",
+            ),
+        ] {
+            assert_eq!(
+                h.render_template(template, &input).expect("Render error"),
+                expected,
+                "Failed to test {} with {}",
+                template,
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn it_borrows_unchanged_input() {
+        for (input, prefix, suffix, expected) in [
+            ("tbl_users_v2", Some("tbl_"), Some("_v2"), "users"),
+            ("users", Some("tbl_"), None, "users"),
+            ("_v2", Some("_v2"), Some("_v2"), ""),
+        ] {
+            let stripped = strip_affixes(Cow::Borrowed(input), prefix, suffix);
+
+            assert!(
+                matches!(stripped, Cow::Borrowed(_)),
+                "Failed to test {}",
+                input
+            );
+            assert_eq!(stripped, expected, "Failed to test {}", input);
+        }
+
+        for (name, input, expected) in [
+            ("to_lower_case", "product_images", true),
+            ("to_lower_case", "ProductImages", false),
+            ("to_upper_case", "PRODUCT_IMAGES", true),
+            ("to_upper_case", "straße", false),
+            ("capitalize", "Product images", true),
+            ("capitalize", "ǆungla", false),
+            ("decapitalize", "productImages", true),
+            ("to_raw_identifier", "kind", true),
+            ("to_raw_identifier", "type", false),
+            ("to_snake_case", "product_images", false),
+        ] {
+            assert_eq!(
+                leaves_unchanged(name, input),
+                expected,
+                "Failed to test {} with {}",
+                name,
+                input
+            );
+        }
+
+        let mut h = Handlebars::new();
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+
+        for (template, input, expected) in [
+            (r#"{{inflect this}}"#, "Product images", "Product images"),
+            (
+                r#"{{inflect this to_lower_case=true strip_prefix="tbl_"}}"#,
+                "tbl_users",
+                "users",
+            ),
+            (
+                r#"{{inflect this to_lower_case=true to_raw_identifier=true}}"#,
+                "Type",
+                "r#type",
+            ),
+            (
+                r#"{{inflect this capitalize=true suffix="s"}}"#,
+                "Order",
+                "Orders",
+            ),
+        ] {
+            assert_eq!(
+                h.render_template(template, &input).expect("Render error"),
+                expected,
+                "Failed to test {} with {}",
+                template,
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn it_customizes_foreign_keys() {
+        let mut h = Handlebars::new();
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+
+        for (template, input, expected) in [
+            (
+                r#"{{inflect this to_foreign_key=true key_suffix="_uuid"}}"#,
+                "ProductImage",
+                "product_image_uuid",
+            ),
+            (
+                r#"{{inflect this to_foreign_key=true key_case="camel"}}"#,
+                "ProductImage",
+                "productImageId",
+            ),
+            (
+                r#"{{inflect this to_foreign_key=true key_suffix="_uuid" key_case="camel"}}"#,
+                "Admin::ProductImage",
+                "productImageUuid",
+            ),
+            (
+                r#"{{inflect this to_foreign_key=true key_suffix="_uuid"}}"#,
+                "product_image_uuid",
+                "product_image_uuid",
+            ),
+            (
+                r#"{{inflect this to_foreign_key=true key_suffix="Ref" key_case="pascal"}}"#,
+                "product image",
+                "ProductImageRef",
+            ),
+            (
+                r#"{{inflect this to_foreign_key=true}}"#,
+                "ProductImage",
+                "product_image_id",
+            ),
+        ] {
+            assert_eq!(
+                h.render_template(template, &input).expect("Render error"),
+                expected,
+                "Failed to test {} with {}",
+                template,
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn it_configures_digit_boundaries() {
+        let mut h = Handlebars::new();
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+
+        for (template, input, expected) in [
+            (
+                r#"{{inflect this digits="attach" to_snake_case=true}}"#,
+                "OAuth2Token",
+                "o_auth2_token",
+            ),
+            (
+                r#"{{inflect this digits="separate" to_snake_case=true}}"#,
+                "OAuth2Token",
+                "o_auth_2_token",
+            ),
+            (
+                r#"{{inflect this digits="attach" to_snake_case=true}}"#,
+                "address1",
+                "address1",
+            ),
+            (
+                r#"{{inflect this digits="separate" to_kebab_case=true}}"#,
+                "address1",
+                "address-1",
+            ),
+            (
+                r#"{{inflect this digits="separate" to_camel_case=true}}"#,
+                "address1_line",
+                "address1Line",
+            ),
+            (
+                r#"{{inflect this digits="attach" to_screaming_snake_case=true}}"#,
+                "v2Id",
+                "V2_ID",
+            ),
+            (
+                r#"{{inflect this digits="separate" to="snake" from="snake"}}"#,
+                "address1_line",
+                "address_1_line",
+            ),
+            (
+                r#"{{inflect this digits="separate" normalize=true}}"#,
+                "Address1Line",
+                "address 1 line",
+            ),
+            (
+                r#"{{inflect this digits="separate" to_title_case=true}}"#,
+                "address1",
+                "Address 1",
+            ),
+            (
+                r#"{{inflect this digits="sometimes" to_snake_case=true}}"#,
+                "address1",
+                "address_1",
+            ),
+        ] {
+            assert_eq!(
+                h.render_template(template, &input).expect("Render error"),
+                expected,
+                "Failed to test {} with {}",
+                template,
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn it_selects_a_word() {
+        let mut h = Handlebars::new();
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+
+        for (template, input, expected) in [
+            (
+                r#"{{inflect this word=-1 to_plural=true}}"#,
+                "user access token",
+                "tokens",
+            ),
+            (
+                r#"{{inflect this word=0 to_pascal_case=true}}"#,
+                "user_access_token",
+                "User",
+            ),
+            (r#"{{inflect this word=1}}"#, "userAccessToken", "Access"),
+            (r#"{{inflect this word=-3}}"#, "user access token", "user"),
+            (r#"{{inflect this word=3}}"#, "user access token", ""),
+            (r#"{{inflect this word=-4}}"#, "user access token", ""),
+            (
+                r#"{{inflect this word=7 to_plural=true}}"#,
+                "user access token",
+                "",
+            ),
+            (
+                r#"{{inflect this word=3 to_snake_case=true prefix="x"}}"#,
+                "user access token",
+                "",
+            ),
+            (
+                r#"{{inflect this word="last"}}"#,
+                "user access token",
+                "user access token",
+            ),
+        ] {
+            assert_eq!(
+                h.render_template(template, &input).expect("Render error"),
+                expected,
+                "Failed to test {} with {}",
+                template,
+                input
+            );
+        }
+
+        h.set_strict_mode(true);
+        for template in [
+            r#"{{inflect this word=3}}"#,
+            r#"{{inflect this word="last"}}"#,
+        ] {
+            assert!(
+                h.render_template(template, &"user access token").is_err(),
+                "Failed to test {} in strict mode",
+                template
+            );
+        }
+    }
+
+    #[test]
+    fn it_inflects_path_segments() {
+        let mut h = Handlebars::new();
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+
+        for (template, input, expected) in [
+            (
+                r#"{{inflect this to_camel_case=true segments=true}}"#,
+                "api/product_images/by_id",
+                "api/productImages/byId",
+            ),
+            (
+                r#"{{inflect this to_kebab_case=true segments=true}}"#,
+                "/Api/ProductImages/",
+                "/api/product-images/",
+            ),
+            (
+                r#"{{inflect this to_pascal_case=true segments="::"}}"#,
+                "admin::user_accounts",
+                "Admin::UserAccounts",
+            ),
+            (
+                r#"{{inflect this ops="to_singular|to_pascal_case" segments=true}}"#,
+                "users/orders",
+                "User/Order",
+            ),
+            (
+                r#"{{inflect this to_camel_case=true segments=false}}"#,
+                "api/product_images",
+                "apiProductImages",
+            ),
+        ] {
+            assert_eq!(
+                h.render_template(template, &input).expect("Render error"),
+                expected,
+                "Failed to test {} with {}",
+                template,
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn it_truncates_between_words() {
+        let mut h = Handlebars::new();
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+
+        for (template, input, expected) in [
+            (
+                r#"{{inflect this truncate_words=true max=14}}"#,
+                "The quick brown fox",
+                "The quick...",
+            ),
+            (
+                r#"{{inflect this truncate_words=true max=13}}"#,
+                "The quick brown fox",
+                "The quick...",
+            ),
+            (
+                r#"{{inflect this truncate_words=true max=19}}"#,
+                "The quick brown fox",
+                "The quick brown fox",
+            ),
+            (
+                r#"{{inflect this truncate_words=true max=12 omission="…"}}"#,
+                "Hello, wonderful world",
+                "Hello…",
+            ),
+            (
+                r#"{{inflect this truncate_words=true max=5}}"#,
+                "Extraordinary",
+                "...",
+            ),
+            (r#"{{inflect this truncate_words=true max=2}}"#, "abc", ".."),
+            (r#"{{inflect this truncate_words=true max=0}}"#, "abc", ""),
+            (
+                r#"{{inflect this truncate_words=true max=1 omission="…"}}"#,
+                "ab",
+                "…",
+            ),
+            (
+                r#"{{inflect this ops="to_sentence_case|truncate_words=12"}}"#,
+                "product_image_gallery",
+                "Product...",
+            ),
+            (
+                r#"{{inflect this truncate_words=true}}"#,
+                "The quick brown fox",
+                "The quick brown fox",
+            ),
+            (
+                r#"{{inflect this truncate_words=14}}"#,
+                "The quick brown fox",
+                "The quick...",
+            ),
+        ] {
+            assert_eq!(
+                h.render_template(template, &input).expect("Render error"),
+                expected,
+                "Failed to test {} with {}",
+                template,
+                input
+            );
+        }
+
+        h.set_strict_mode(true);
+        assert!(
+            h.render_template(r#"{{inflect this truncate_words=true}}"#, &"The quick fox")
+                .is_err(),
+            "Failed to test missing max in strict mode"
+        );
+    }
+
+    #[test]
+    fn it_prefixes_indefinite_articles() {
+        let mut h = Handlebars::new();
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+
+        for (template, input, expected) in [
+            (
+                r#"{{inflect this indefinite_article=true}}"#,
+                "hour",
+                "an hour",
+            ),
+            (
+                r#"{{inflect this indefinite_article=true}}"#,
+                "user",
+                "a user",
+            ),
+            (
+                r#"{{inflect this indefinite_article=true}}"#,
+                "API",
+                "an API",
+            ),
+            (
+                r#"{{inflect this to_sentence_case=true indefinite_article=true}}"#,
+                "ElephantEar",
+                "an Elephant ear",
+            ),
+            (
+                r#"{{inflect this indefinite_article=true capitalize=true}}"#,
+                "error occurred",
+                "An error occurred",
+            ),
+            (r#"{{inflect this indefinite_article=true}}"#, "", ""),
+            (r#"{{inflect this indefinite_article=true}}"#, " ", " "),
+        ] {
+            assert_eq!(
+                h.render_template(template, &input).expect("Render error"),
+                expected,
+                "Failed to test {} with {}",
+                template,
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn it_abbreviates_words() {
+        let mut h = Handlebars::new();
+        h.register_helper(
+            "inflect",
+            Box::new(HandlebarsInflector::new().with_abbreviations([
+                ("Identifier", "id"),
+                ("number", "num"),
+                ("configuration", "config"),
+            ])),
+        );
+
+        for (template, input, expected) in [
+            (
+                r#"{{inflect this abbreviate=true}}"#,
+                "customer_identifier",
+                "customer_id",
+            ),
+            (
+                r#"{{inflect this abbreviate=true}}"#,
+                "CustomerIdentifier",
+                "CustomerId",
+            ),
+            (
+                r#"{{inflect this abbreviate=true}}"#,
+                "ORDER_NUMBER",
+                "ORDER_NUM",
+            ),
+            (
+                r#"{{inflect this abbreviate=true to_camel_case=true}}"#,
+                "Server Configuration Number",
+                "serverConfigNum",
+            ),
+            (
+                r#"{{inflect this abbreviate=true}}"#,
+                " numbers, number! ",
+                " numbers, num! ",
+            ),
+            (
+                r#"{{inflect this ops="to_snake_case|abbreviate"}}"#,
+                "OrderNumber",
+                "order_num",
+            ),
+        ] {
+            assert_eq!(
+                h.render_template(template, &input).expect("Render error"),
+                expected,
+                "Failed to test {} with {}",
+                template,
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn it_abbreviates_to_acronyms() {
+        let mut h = Handlebars::new();
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+
+        for (template, input, expected) in [
+            (
+                r#"{{inflect this to_acronym=true}}"#,
+                "Portable Network Graphics",
+                "PNG",
+            ),
+            (
+                r#"{{inflect this to_acronym=true}}"#,
+                "John Ronald Tolkien",
+                "JRT",
+            ),
+            (
+                r#"{{inflect this to_acronym=true dotted=true}}"#,
+                "John Ronald Tolkien",
+                "J.R.T.",
+            ),
+            (
+                r#"{{inflect this to_acronym=true}}"#,
+                "portable_network_graphics",
+                "PNG",
+            ),
+            (r#"{{inflect this to_acronym=true}}"#, "sharedKey", "SK"),
+            (
+                r#"{{inflect this ops="to_acronym|to_lower_case"}}"#,
+                "Frequently Asked Questions",
+                "faq",
+            ),
+            (r#"{{inflect this to_acronym=true}}"#, "", ""),
+        ] {
+            assert_eq!(
+                h.render_template(template, &input).expect("Render error"),
+                expected,
+                "Failed to test {} with {}",
+                template,
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn it_converts_to_dot_and_path_case() {
+        let mut h = Handlebars::new();
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+
+        for (template, input, expected) in [
+            (
+                r#"{{inflect this to_dot_case=true}}"#,
+                "serverMaxConnections",
+                "server.max.connections",
+            ),
+            (
+                r#"{{inflect this to_path_case=true}}"#,
+                "Admin::ProductImages",
+                "admin/product/images",
+            ),
+            (
+                r#"{{inflect this to="path" from="snake"}}"#,
+                "api_v2",
+                "api/v2",
+            ),
+            (
+                r#"{{inflect this to_dot_case=true skip_if_matching=true}}"#,
+                "server.v2",
+                "server.v2",
+            ),
+        ] {
+            assert_eq!(
+                h.render_template(template, &input).expect("Render error"),
+                expected,
+                "Failed to test {} with {}",
+                template,
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn it_accepts_rails_aliases() {
+        let mut h = Handlebars::new();
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+
+        for (template, input, expected) in [
+            (
+                r#"{{inflect this underscore=true pluralize=true}}"#,
+                "ProductImage",
+                "product_images",
+            ),
+            (
+                r#"{{inflect this dasherize=true}}"#,
+                "product_image",
+                "product-image",
+            ),
+            (
+                r#"{{inflect this camelize=true}}"#,
+                "product_image",
+                "ProductImage",
+            ),
+            (
+                r#"{{inflect this classify=true}}"#,
+                "product_images",
+                "ProductImage",
+            ),
+            (
+                r#"{{inflect this tableize=true}}"#,
+                "ProductImage",
+                "product_images",
+            ),
+            (
+                r#"{{inflect this titleize=true}}"#,
+                "product_image",
+                "Product Image",
+            ),
+            (
+                r#"{{inflect this foreign_key=true}}"#,
+                "Product",
+                "product_id",
+            ),
+            (r#"{{inflect this singularize=true}}"#, "boxes", "box"),
+            (
+                r#"{{inflect this ops="singularize|camelize"}}"#,
+                "product_images",
+                "ProductImage",
+            ),
+        ] {
+            assert_eq!(
+                h.render_template(template, &input).expect("Render error"),
+                expected,
+                "Failed to test {} with {}",
+                template,
+                input
+            );
+        }
+
+        h.set_strict_mode(true);
+
+        assert!(
+            h.render_template(
+                r#"{{inflect this pluralize=true to_singular=true}}"#,
+                &"box"
+            )
+            .is_err(),
+            "Failed to test conflicting aliases in strict mode"
+        );
+    }
+
+    #[test]
+    fn it_keeps_acronyms() {
+        let mut h = Handlebars::new();
+        h.register_helper(
+            "inflect",
+            Box::new(HandlebarsInflector::new().with_acronyms(["API", "http", "ID", "URL"])),
+        );
+
+        for (template, input, expected) in [
+            (
+                r#"{{inflect this to_pascal_case=true}}"#,
+                "api_key",
+                "APIKey",
+            ),
+            (
+                r#"{{inflect this to_camel_case=true}}"#,
+                "user_id",
+                "userID",
+            ),
+            (
+                r#"{{inflect this to_camel_case=true}}"#,
+                "api_key",
+                "apiKey",
+            ),
+            (
+                r#"{{inflect this to_snake_case=true}}"#,
+                "HTTPServer",
+                "http_server",
+            ),
+            (
+                r#"{{inflect this to_snake_case=true}}"#,
+                "APIKeyURL",
+                "api_key_url",
+            ),
+            (
+                r#"{{inflect this to="train"}}"#,
+                "http_server",
+                "HTTP-Server",
+            ),
+            (
+                r#"{{inflect this to_pascal_case=true}}"#,
+                "product_image",
+                "ProductImage",
+            ),
+        ] {
+            assert_eq!(
+                h.render_template(template, &input).expect("Render error"),
+                expected,
+                "Failed to test {} with {}",
+                template,
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn it_transliterates() {
+        let mut h = Handlebars::new();
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+
+        #[cfg(feature = "pinyin")]
+        assert_eq!(
+            h.render_template(
+                r#"{{inflect this transliterate=true scheme="pinyin" to_kebab_case=true}}"#,
+                &String::from("北京大学")
+            )
+            .expect("Render error"),
+            "bei-jing-da-xue",
+            "Failed to test transliterate"
+        );
+
+        assert_eq!(
+            h.render_template(
+                r#"{{inflect this transliterate=true scheme="klingon" to_kebab_case=true}}"#,
+                &String::from("Product Images")
+            )
+            .expect("Render error"),
+            "product-images",
+            "Failed to test unknown scheme"
+        );
+
+        h.set_strict_mode(true);
+
+        assert!(
+            h.render_template(
+                r#"{{inflect this transliterate=true scheme="klingon"}}"#,
+                &String::from("Product Images")
+            )
+            .is_err(),
+            "Failed to test unknown scheme in strict mode"
+        );
+    }
+
+    #[test]
+    fn it_normalizes_confusables() {
+        let mut h = Handlebars::new();
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+
+        assert_eq!(
+            h.render_template(
+                r#"{{inflect this normalize_confusables=true to_snake_case=true}}"#,
+                &String::from("Ｕѕеr Ιmаgеѕ")
+            )
+            .expect("Render error"),
+            "user_images",
+            "Failed to test normalize_confusables"
+        );
+    }
+
+    #[test]
+    fn it_coerces_identifiers() {
+        let mut h = Handlebars::new();
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+
+        assert_eq!(
+            h.render_template(
+                r#"{{inflect this to_identifier=true profile="rust"}}"#,
+                &String::from("2fa enabled?")
+            )
+            .expect("Render error"),
+            "_2fa_enabled",
+            "Failed to test to_identifier"
         );
         assert_eq!(
             h.render_template(
-                r#"{{inflect this to_singular=true}}"#,
+                r#"{{inflect this to_camel_case=true to_identifier=true profile="javascript"}}"#,
+                &String::from("größe in €")
+            )
+            .expect("Render error"),
+            "größeIn",
+            "Failed to test to_identifier with unicode letters"
+        );
+        assert_eq!(
+            h.render_template(
+                r#"{{inflect this to_identifier=true leading_digit="prefix"}}"#,
+                &String::from("2nd order!")
+            )
+            .expect("Render error"),
+            "n2nd_order",
+            "Failed to test to_identifier with a leading digit prefix"
+        );
+        assert_eq!(
+            h.render_template(
+                r#"{{inflect this to_identifier=true}}"#,
+                &String::from("2nd order!")
+            )
+            .expect("Render error"),
+            "n2nd_order",
+            "Failed to test to_identifier with a leading digit"
+        );
+        assert_eq!(
+            h.render_template(
+                r#"{{inflect this to_identifier=true leading_digit="prefix"}}"#,
+                &String::from("--user   name--")
+            )
+            .expect("Render error"),
+            "user_name",
+            "Failed to test to_identifier with separator runs"
+        );
+    }
+
+    #[test]
+    fn it_escapes_rust_keywords() {
+        let mut h = Handlebars::new();
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+
+        for (input, expected) in [
+            ("Type", "r#type"),
+            ("Match", "r#match"),
+            ("Self", "self_"),
+            ("User Type", "user_type"),
+        ] {
+            assert_eq!(
+                h.render_template(
+                    r#"{{inflect this to_snake_case=true to_raw_identifier=true}}"#,
+                    &String::from(input)
+                )
+                .expect("Render error"),
+                expected,
+                "Failed to test {}",
+                input
+            );
+        }
+
+        assert_eq!(
+            h.render_template(r#"{{inflect "type" ops="to_raw_identifier"}}"#, &())
+                .expect("Render error"),
+            "r#type",
+            "Failed to test to_raw_identifier in a pipeline"
+        );
+    }
+
+    #[test]
+    fn it_makes_dns_labels() {
+        let mut h = Handlebars::new();
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+
+        let long = "product ".repeat(10);
+
+        for (template, input, expected) in [
+            (
+                r#"{{inflect this to_dns_label=true}}"#,
+                "Product Images API",
+                "product-images-api",
+            ),
+            (
+                r#"{{inflect this to_kebab_case=true to_dns_label=true}}"#,
+                "userProfileService",
+                "user-profile-service",
+            ),
+            (
+                r#"{{inflect this to_dns_label=true}}"#,
+                long.as_str(),
+                "product-product-product-product-product-product-product-product",
+            ),
+            (
+                r#"{{inflect this to_dns_label=true max_length=12}}"#,
+                "Product Images",
+                "product-imag",
+            ),
+            (
+                r#"{{inflect this to_dns_label=true subdomain=true}}"#,
+                "API.Example_Corp.com",
+                "api.example-corp.com",
+            ),
+        ] {
+            assert_eq!(
+                h.render_template(template, &input).expect("Render error"),
+                expected,
+                "Failed to test {} with {}",
+                template,
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn it_sanitizes_filenames() {
+        let mut h = Handlebars::new();
+        h.register_escape_fn(handlebars::no_escape);
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+
+        for (template, input, expected) in [
+            (
+                r#"{{inflect this to_filename=true}}"#,
+                r#"Reports/Q1: "Sales""#,
+                "Reports_Q1_ _Sales",
+            ),
+            (
+                r#"{{inflect this to_snake_case=true to_filename=true}}"#,
+                "Nul",
+                "_nul",
+            ),
+            (
+                r#"{{inflect this to_filename=true max_length=10 keep_extension=true}}"#,
+                "product_images.rs",
+                "product.rs",
+            ),
+            (
+                r#"{{inflect this to_filename=true max_length=10}}"#,
+                "product_images.rs",
+                "product_im",
+            ),
+        ] {
+            assert_eq!(
+                h.render_template(template, &input).expect("Render error"),
+                expected,
+                "Failed to test {} with {}",
+                template,
+                input
+            );
+        }
+
+        h.set_strict_mode(true);
+
+        assert!(
+            h.render_template(
+                r#"{{inflect "name" to_filename=true max_length="long"}}"#,
+                &()
+            )
+            .is_err(),
+            "Failed to test invalid max_length in strict mode"
+        );
+    }
+
+    #[test]
+    fn it_quotes_sql_identifiers() {
+        let mut h = Handlebars::new();
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+
+        for (template, expected) in [
+            (
+                r#"{{inflect "User" to_table_case=true quote_identifier=true}}"#,
+                "users",
+            ),
+            (
+                r#"{{inflect "User" to_snake_case=true quote_identifier=true}}"#,
+                r#""user""#,
+            ),
+            (
+                r#"{{inflect "Order" to_snake_case=true quote_identifier=true dialect="mysql"}}"#,
+                "`order`",
+            ),
+            (
+                r#"{{inflect "Group" quote_identifier=true dialect="mssql"}}"#,
+                "[Group]",
+            ),
+            (
+                r#"{{inflect "ProductImage" quote_identifier=true}}"#,
+                r#""ProductImage""#,
+            ),
+        ] {
+            assert_eq!(
+                h.render_template(template, &()).expect("Render error"),
+                expected,
+                "Failed to test {}",
+                template
+            );
+        }
+
+        h.set_strict_mode(true);
+
+        assert!(
+            h.render_template(
+                r#"{{inflect "user" quote_identifier=true dialect="oracle"}}"#,
+                &()
+            )
+            .is_err(),
+            "Failed to test unknown dialect in strict mode"
+        );
+    }
+
+    #[test]
+    fn it_skips_matching_input() {
+        let mut h = Handlebars::new();
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+
+        assert_eq!(
+            h.render_template(
+                r#"{{inflect this to_camel_case=true skip_if_matching=true}}"#,
+                &String::from("productImages")
+            )
+            .expect("Render error"),
+            "productImages",
+            "Failed to test matching input"
+        );
+        assert_eq!(
+            h.render_template(
+                r#"{{inflect this to_camel_case=true skip_if_matching=true}}"#,
                 &String::from("product_images")
             )
             .expect("Render error"),
-            "product_image",
-            "Failed to test to_singular"
+            "productImages",
+            "Failed to test non-matching input"
         );
         assert_eq!(
             h.render_template(
-                r#"{{inflect this to_upper_case=true}}"#,
-                &String::from("product image")
+                r#"{{inflect this to_camel_case=true to_snake_case=true skip_if_matching=true}}"#,
+                &String::from("product_images")
             )
             .expect("Render error"),
-            "PRODUCT IMAGE",
-            "Failed to test to_upper_case"
+            "product_images",
+            "Failed to test last requested convention"
         );
         assert_eq!(
             h.render_template(
-                r#"{{inflect this to_lower_case=true}}"#,
-                &String::from("PRODUCT IMAGE")
+                r#"{{inflect this to_plural=true skip_if_matching=true}}"#,
+                &String::from("product_image")
             )
             .expect("Render error"),
-            "product image",
-            "Failed to test to_lower_case"
+            "product_images",
+            "Failed to test without case convention"
+        );
+
+        for (template, expected) in [
+            (
+                r#"{{inflect this to_snake_case=true to_plural=true skip_if_matching=true}}"#,
+                "product_image",
+            ),
+            (
+                r#"{{inflect this to_snake_case=true to_plural=true skip_if_matching=false}}"#,
+                "product_images",
+            ),
+        ] {
+            assert_eq!(
+                h.render_template(template, &String::from("product_image"))
+                    .expect("Render error"),
+                expected,
+                "Failed to test {}",
+                template
+            );
+        }
+    }
+
+    #[test]
+    fn it_converts_between_conventions() {
+        let mut h = Handlebars::new();
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+
+        for (template, input, expected) in [
+            (
+                r#"{{inflect this from="pascal" to="snake"}}"#,
+                "ABTest",
+                "ab_test",
+            ),
+            (
+                r#"{{inflect this from="camel" to="snake"}}"#,
+                "v2Id",
+                "v2_id",
+            ),
+            (
+                r#"{{inflect this from="snake" to="camel"}}"#,
+                "product_images",
+                "productImages",
+            ),
+            (
+                r#"{{inflect this from="kebab" to="screaming_snake"}}"#,
+                "max-retry-count",
+                "MAX_RETRY_COUNT",
+            ),
+            (
+                r#"{{inflect this to="title"}}"#,
+                "product_images",
+                "Product Images",
+            ),
+            (
+                r#"{{inflect this from="snake" to="pascal" to_plural=true}}"#,
+                "product_image",
+                "ProductImages",
+            ),
+        ] {
+            assert_eq!(
+                h.render_template(template, &input).expect("Render error"),
+                expected,
+                "Failed to test {}",
+                template
+            );
+        }
+
+        assert_eq!(
+            h.render_template(r#"{{inflect this to="klingon"}}"#, &"product_images")
+                .expect("Render error"),
+            "product_images",
+            "Failed to test unknown convention"
+        );
+
+        h.set_strict_mode(true);
+
+        assert!(
+            h.render_template(r#"{{inflect this to="klingon"}}"#, &"product_images")
+                .is_err(),
+            "Failed to test unknown convention in strict mode"
+        );
+        assert!(
+            h.render_template(r#"{{inflect this from="snake"}}"#, &"product_images")
+                .is_err(),
+            "Failed to test from without to in strict mode"
+        );
+    }
+
+    #[test]
+    fn it_normalizes_mixed_separators() {
+        let mut h = Handlebars::new();
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+
+        for input in [
+            "Product-image_URL",
+            "product image url",
+            "productImageURL",
+            " product__Image--url ",
+        ] {
+            assert_eq!(
+                h.render_template(
+                    r#"{{inflect this normalize=true to_snake_case=true}}"#,
+                    &input
+                )
+                .expect("Render error"),
+                "product_image_url",
+                "Failed to test normalize with {}",
+                input
+            );
+        }
+
+        assert_eq!(
+            h.render_template(
+                r#"{{inflect this normalize=true}}"#,
+                &"Product-image_URL list"
+            )
+            .expect("Render error"),
+            "product image url list",
+            "Failed to test normalize"
+        );
+    }
+
+    #[test]
+    fn it_configures_punctuation_boundaries() {
+        let mut h = Handlebars::new();
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+
+        for (template, expected) in [
+            (r#"{{inflect this to_snake_case=true}}"#, "don_t_stop"),
+            (
+                r#"{{inflect this apostrophes="strip" to_snake_case=true}}"#,
+                "dont_stop",
+            ),
+            (
+                r#"{{inflect this apostrophes="keep" to="snake"}}"#,
+                "don't_stop",
+            ),
+            (
+                r#"{{inflect this apostrophes="keep" to_snake_case=true}}"#,
+                "don't_stop",
+            ),
+            (
+                r#"{{inflect this apostrophes="keep" to_pascal_case=true}}"#,
+                "Don'tStop",
+            ),
+        ] {
+            assert_eq!(
+                h.render_template(template, &"don't stop")
+                    .expect("Render error"),
+                expected,
+                "Failed to test {}",
+                template
+            );
+        }
+
+        assert_eq!(
+            h.render_template(
+                r#"{{inflect this periods="keep" to="kebab"}}"#,
+                &"Release v1.2"
+            )
+            .expect("Render error"),
+            "release-v1.2",
+            "Failed to test periods=keep"
+        );
+
+        for (template, expected) in [
+            (r#"{{inflect this to_snake_case=true}}"#, "release_v_1_2"),
+            (
+                r#"{{inflect this periods="keep" to_snake_case=true}}"#,
+                "release_v1.2",
+            ),
+            (
+                r#"{{inflect this periods="keep" to_kebab_case=true}}"#,
+                "release-v1.2",
+            ),
+            (
+                r#"{{inflect this periods="keep" to_screaming_snake_case=true}}"#,
+                "RELEASE_V1.2",
+            ),
+            (
+                r#"{{inflect this periods="keep" to_title_case=true}}"#,
+                "Release V1.2",
+            ),
+        ] {
+            assert_eq!(
+                h.render_template(template, &"Release v1.2")
+                    .expect("Render error"),
+                expected,
+                "Failed to test {}",
+                template
+            );
+        }
+
+        for (template, expected) in [
+            (
+                r#"{{inflect this hyphens="keep" to_title_case=true}}"#,
+                "State-of-the-Art E-Commerce",
+            ),
+            (
+                r#"{{inflect this hyphens="keep" to="title"}}"#,
+                "State-of-the-Art E-Commerce",
+            ),
+            (
+                r#"{{inflect this from="sentence" to="title"}}"#,
+                "State-of-the-Art E-Commerce",
+            ),
+            (
+                r#"{{inflect this hyphens="split" to_title_case=true}}"#,
+                "State Of The Art E Commerce",
+            ),
+            (
+                r#"{{inflect this hyphens="strip" to_title_case=true}}"#,
+                "Stateoftheart Ecommerce",
+            ),
+        ] {
+            assert_eq!(
+                h.render_template(template, &"state-of-the-art e-commerce")
+                    .expect("Render error"),
+                expected,
+                "Failed to test {}",
+                template
+            );
+        }
+    }
+
+    #[test]
+    fn it_escapes_markdown() {
+        let mut h = Handlebars::new();
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+
+        assert_eq!(
+            h.render_template(
+                r#"| {{inflect this to_snake_case=true escape="markdown"}} |"#,
+                &"ProductImageUrl"
+            )
+            .expect("Render error"),
+            r"| product\_image\_url |",
+            "Failed to test escape=markdown"
+        );
+
+        assert_eq!(
+            h.render_template(
+                r#"{{inflect this to_snake_case=true skip_if_matching=true escape="markdown"}}"#,
+                &"already_snake"
+            )
+            .expect("Render error"),
+            r"already\_snake",
+            "Failed to test escape=markdown with skip_if_matching"
+        );
+    }
+
+    #[test]
+    fn it_tracks_unique_names() {
+        let mut h = Handlebars::new();
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+
+        let template = r#"{{#each this}}{{inflect this to_snake_case=true unique=true}} {{/each}}"#;
+        let names = ["userId", "UserID", "user-id", "user_id_2", "other"];
+
+        for _ in 0..2 {
+            assert_eq!(
+                h.render_template(template, &names).expect("Render error"),
+                "user_id user_id_2 user_id_3 user_id_2_2 other ",
+                "Failed to test unique=true"
+            );
+        }
+
+        assert_eq!(
+            h.render_template(
+                r#"{{inflect "a" unique="x"}} {{inflect "a" unique="y"}} {{inflect "a" unique="x"}} {{inflect "a"}}"#,
+                &()
+            )
+            .expect("Render error"),
+            "a a a_2 a",
+            "Failed to test unique groups"
+        );
+
+        h.set_strict_mode(true);
+
+        assert!(
+            h.render_template(
+                r#"{{inflect "userId" to_snake_case=true unique=true}}{{inflect "user_id" unique=true}}"#,
+                &()
+            )
+            .is_err(),
+            "Failed to test unique=true in strict mode"
+        );
+    }
+
+    #[test]
+    fn it_avoids_reserved_names() {
+        let mut h = Handlebars::new();
+        h.register_helper(
+            "inflect",
+            Box::new(HandlebarsInflector::new().with_reserved(["type", "type_", "match"])),
+        );
+        h.register_helper(
+            "inflect_r",
+            Box::new(
+                HandlebarsInflector::new()
+                    .with_reserved(["self"])
+                    .with_reserved_suffix("_value"),
+            ),
+        );
+
+        for (template, expected) in [
+            (r#"{{inflect "Type" to_snake_case=true}}"#, "type__"),
+            (r#"{{inflect "match"}}"#, "match_"),
+            (r#"{{inflect "matches" to_singular=true}}"#, "match_"),
+            (r#"{{inflect "types"}}"#, "types"),
+            (r#"{{inflect_r "Self" to_lower_case=true}}"#, "self_value"),
+        ] {
+            assert_eq!(
+                h.render_template(template, &()).expect("Render error"),
+                expected,
+                "Failed to test {}",
+                template
+            );
+        }
+
+        h.set_strict_mode(true);
+
+        assert!(
+            h.render_template(r#"{{inflect "match"}}"#, &()).is_err(),
+            "Failed to test reserved names in strict mode"
+        );
+    }
+
+    #[test]
+    fn it_escapes_reserved_words() {
+        let mut h = Handlebars::new();
+        h.register_helper(
+            "inflect",
+            Box::new(
+                HandlebarsInflector::new()
+                    .with_reserved_words("sql", ReservedWords::sql())
+                    .with_reserved_words("python", ReservedWords::python())
+                    .with_reserved_words(
+                        "mssql",
+                        ReservedWords::sql()
+                            .with_strategy(ReservedStrategy::Quote("[".into(), "]".into())),
+                    )
+                    .with_reserved_words(
+                        "strict",
+                        ReservedWords::new(["class"]).with_strategy(ReservedStrategy::Error),
+                    ),
+            ),
+        );
+
+        for (template, expected) in [
+            (
+                r#"{{{inflect "Order" to_snake_case=true reserved="sql"}}}"#,
+                r#""order""#,
+            ),
+            (
+                r#"{{inflect "Orders" to_snake_case=true reserved="sql"}}"#,
+                "orders",
+            ),
+            (r#"{{inflect "User" reserved="mssql"}}"#, "[User]"),
+            (
+                r#"{{inflect "LAMBDA" to_lower_case=true reserved="python"}}"#,
+                "lambda_",
+            ),
+            (
+                r#"{{inflect "Class" to_lower_case=true reserved="strict"}}"#,
+                "class",
+            ),
+            (r#"{{inflect "class" reserved="unknown"}}"#, "class"),
+        ] {
+            assert_eq!(
+                h.render_template(template, &()).expect("Render error"),
+                expected,
+                "Failed to test {}",
+                template
+            );
+        }
+
+        h.set_strict_mode(true);
+
+        assert!(
+            h.render_template(r#"{{inflect "class" reserved="strict"}}"#, &())
+                .is_err(),
+            "Failed to test error strategy in strict mode"
+        );
+        assert!(
+            h.render_template(r#"{{inflect "class" reserved="unknown"}}"#, &())
+                .is_err(),
+            "Failed to test unknown list in strict mode"
+        );
+    }
+
+    #[test]
+    fn it_applies_overrides() {
+        let mut h = Handlebars::new();
+        h.register_helper(
+            "type_name",
+            Box::new(
+                HandlebarsInflector::new()
+                    .with_override("oauth2_token", "OAuth2Token")
+                    .with_overrides([("ios_app", "IOSApp"), ("api", "API")])
+                    .with_reserved(["API"]),
+            ),
+        );
+
+        for (template, expected) in [
+            (
+                r#"{{type_name "oauth2_token" to_pascal_case=true}}"#,
+                "OAuth2Token",
+            ),
+            (r#"{{type_name "ios_app" to_pascal_case=true}}"#, "IOSApp"),
+            (
+                r#"{{type_name "other_app" to_pascal_case=true}}"#,
+                "OtherApp",
+            ),
+            (r#"{{type_name "api" unique=true}}"#, "API_"),
+        ] {
+            assert_eq!(
+                h.render_template(template, &()).expect("Render error"),
+                expected,
+                "Failed to test {}",
+                template
+            );
+        }
+    }
+
+    #[test]
+    fn it_preserves_edges() {
+        let mut h = Handlebars::new();
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+
+        for (template, expected) in [
+            (
+                r#"{{inflect "_internal_field" to_camel_case=true preserve_edges=true}}"#,
+                "_internalField",
+            ),
+            (
+                r#"{{inflect "__dunder__" to_pascal_case=true preserve_edges=true}}"#,
+                "__Dunder__",
+            ),
+            (
+                r#"{{inflect "_privateField" to_snake_case=true preserve_edges=true}}"#,
+                "_private_field",
+            ),
+            (
+                r#"{{inflect "_private_field" to_screaming_snake_case=true preserve_edges=true}}"#,
+                "_PRIVATE_FIELD",
+            ),
+            (
+                r#"{{inflect "_internal_field" to_camel_case=true}}"#,
+                "internalField",
+            ),
+            (
+                r#"{{inflect "_internal_field" to_camel_case=true preserve_edges=false}}"#,
+                "internalField",
+            ),
+            (
+                r#"{{inflect "_internal_field" to_kebab_case=true preserve_edges=true}}"#,
+                "_internal-field",
+            ),
+            (
+                r#"{{inflect "__" to_camel_case=true preserve_edges=true}}"#,
+                "",
+            ),
+        ] {
+            assert_eq!(
+                h.render_template(template, &()).expect("Render error"),
+                expected,
+                "Failed to test {}",
+                template
+            );
+        }
+    }
+
+    #[test]
+    fn it_handles_leading_digits() {
+        let mut h = Handlebars::new();
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+
+        for (template, expected) in [
+            (
+                r#"{{inflect "2fa enabled" to_snake_case=true to_identifier=true leading_digit="spell"}}"#,
+                "two_fa_enabled",
+            ),
+            (
+                r#"{{inflect "2fa_enabled" to_identifier=true leading_digit="prefix" digit_prefix="num"}}"#,
+                "num2fa_enabled",
+            ),
+            (
+                r#"{{inflect "2fa_enabled" to_identifier=true leading_digit="underscore" profile="python"}}"#,
+                "_2fa_enabled",
+            ),
+            (
+                r#"{{inflect "2fa_enabled" to_identifier=true leading_digit="error"}}"#,
+                "n2fa_enabled",
+            ),
+            (
+                r#"{{inflect "2fa_enabled" to_identifier=true}}"#,
+                "n2fa_enabled",
+            ),
+        ] {
+            assert_eq!(
+                h.render_template(template, &()).expect("Render error"),
+                expected,
+                "Failed to test {}",
+                template
+            );
+        }
+
+        h.set_strict_mode(true);
+
+        assert!(
+            h.render_template(
+                r#"{{inflect "2fa_enabled" to_identifier=true leading_digit="error"}}"#,
+                &()
+            )
+            .is_err(),
+            "Failed to test leading_digit=error in strict mode"
         );
     }
+
+    #[test]
+    fn it_overrides_strictness() {
+        let mut h = Handlebars::new();
+        h.register_helper(
+            "strict",
+            Box::new(HandlebarsInflector::new().with_strictness(Strictness::Always)),
+        );
+        h.register_helper(
+            "lenient",
+            Box::new(HandlebarsInflector::new().with_strictness(Strictness::Never)),
+        );
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+
+        for strict_mode in [false, true] {
+            h.set_strict_mode(strict_mode);
+
+            assert!(
+                h.render_template(r#"{{strict 42}}"#, &()).is_err(),
+                "Failed to test Strictness::Always"
+            );
+            assert!(
+                h.render_template(r#"{{strict "a" escape="html"}}"#, &())
+                    .is_err(),
+                "Failed to test Strictness::Always with invalid options"
+            );
+            assert_eq!(
+                h.render_template(r#"{{lenient 42}}{{lenient "a" escape="html"}}"#, &())
+                    .expect("Render error"),
+                "a",
+                "Failed to test Strictness::Never"
+            );
+            assert_eq!(
+                h.render_template(r#"{{inflect 42}}"#, &()).is_err(),
+                strict_mode,
+                "Failed to test Strictness::Registry"
+            );
+        }
+    }
+
+    #[cfg(feature = "stemming")]
+    #[test]
+    fn it_stems() {
+        let mut h = Handlebars::new();
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+
+        for (template, expected) in [
+            (
+                r#"{{inflect "Running Shoes" stem=true to_snake_case=true}}"#,
+                "run_shoe",
+            ),
+            (
+                r#"{{inflect "RunningShoes" normalize=true stem=true to_kebab_case=true}}"#,
+                "run-shoe",
+            ),
+            (r#"{{inflect "Häuser" stem=true stemmer="de"}}"#, "haus"),
+        ] {
+            assert_eq!(
+                h.render_template(template, &()).expect("Render error"),
+                expected,
+                "Failed to test {}",
+                template
+            );
+        }
+    }
+
+    #[cfg(feature = "phonetic")]
+    #[test]
+    fn it_derives_phonetic_keys() {
+        let mut h = Handlebars::new();
+        h.register_helper("inflect", Box::new(HandlebarsInflector));
+
+        for (template, expected) in [
+            (
+                r#"{{inflect "Robert Smith" phonetic="soundex"}}"#,
+                "R163 S530",
+            ),
+            (
+                r#"{{inflect "Jon Schmidt" phonetic="double_metaphone" to_snake_case=true}}"#,
+                "jn_xmt",
+            ),
+        ] {
+            assert_eq!(
+                h.render_template(template, &()).expect("Render error"),
+                expected,
+                "Failed to test {}",
+                template
+            );
+        }
+    }
 }
@@ -0,0 +1,360 @@
+use std::str::FromStr;
+
+use crate::irregular::{last_word_start, match_case};
+
+/// Language whose noun rules `to_plural`, `to_singular`, `ensure_plural`, `ensure_singular` and
+/// `count` follow, selected with the `locale` hash option or
+/// [`HandlebarsInflector::with_locale`](crate::HandlebarsInflector::with_locale)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Locale {
+    #[default]
+    English,
+    German,
+    Spanish,
+}
+
+impl FromStr for Locale {
+    type Err = ();
+
+    /// Parse a locale from its ISO 639-1 code, ignoring any region subtag (`es-MX`, `de_AT`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let code = s.split(['-', '_']).next().unwrap_or_default();
+
+        match code.to_lowercase().as_str() {
+            "en" => Ok(Locale::English),
+            "de" => Ok(Locale::German),
+            "es" => Ok(Locale::Spanish),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Locale {
+    /// Put the last word of `input` in plural or singular form.
+    ///
+    /// With `ensure` a word already in the requested form is left untouched. Not meant for
+    /// English, which is handled by Inflector.
+    pub(crate) fn inflect(self, input: &str, plural: bool, ensure: bool) -> String {
+        let (head, word) = input.split_at(last_word_start(input));
+
+        if word.is_empty() {
+            return input.to_string();
+        }
+
+        let lower = word.to_lowercase();
+        let is_plural = || {
+            let singular = self.singular(&lower);
+            singular != lower && self.plural(&singular) == lower
+        };
+
+        let form = match (plural, ensure) {
+            (true, true) if is_plural() => lower,
+            (false, true) if !is_plural() => lower,
+            (true, _) => self.plural(&lower),
+            (false, _) => self.singular(&lower),
+        };
+
+        format!("{}{}", head, match_case(word, &form))
+    }
+
+    fn plural(self, word: &str) -> String {
+        match self {
+            Locale::English => word.to_string(),
+            Locale::German => german_plural(word),
+            Locale::Spanish => spanish_plural(word),
+        }
+    }
+
+    fn singular(self, word: &str) -> String {
+        match self {
+            Locale::English => word.to_string(),
+            Locale::German => german_singular(word),
+            Locale::Spanish => spanish_singular(word),
+        }
+    }
+}
+
+/// German suffixes taking `-en` in plural
+const GERMAN_EN: &[&str] = &[
+    "ung", "heit", "keit", "schaft", "ion", "tät", "ei", "ur", "enz", "anz", "ik",
+];
+
+/// German suffixes without a plural ending
+const GERMAN_UNCHANGED: &[&str] = &["er", "el", "en", "chen", "lein"];
+
+/// Plural of a regular German noun in lowercase.
+fn german_plural(word: &str) -> String {
+    if word.ends_with("in") {
+        format!("{}nen", word)
+    } else if word.ends_with('e') {
+        format!("{}n", word)
+    } else if GERMAN_EN.iter().any(|suffix| word.ends_with(suffix)) {
+        format!("{}en", word)
+    } else if GERMAN_UNCHANGED.iter().any(|suffix| word.ends_with(suffix)) {
+        word.to_string()
+    } else if word.ends_with(['a', 'i', 'o', 'u', 'y']) {
+        format!("{}s", word)
+    } else {
+        format!("{}e", word)
+    }
+}
+
+/// Singular of a regular German noun in lowercase.
+fn german_singular(word: &str) -> String {
+    if let Some(stem) = word.strip_suffix("innen") {
+        format!("{}in", stem)
+    } else if let Some(stem) = GERMAN_EN.iter().find_map(|suffix| {
+        word.strip_suffix(&format!("{}en", suffix))
+            .map(|s| (s, suffix))
+    }) {
+        format!("{}{}", stem.0, stem.1)
+    } else if let Some(stem) = word.strip_suffix("en") {
+        format!("{}e", stem)
+    } else if word.ends_with(['a', 'i', 'o', 'u', 'y']) {
+        word.to_string()
+    } else if let Some(stem) = word
+        .strip_suffix('s')
+        .filter(|stem| stem.ends_with(['a', 'i', 'o', 'u', 'y']))
+    {
+        stem.to_string()
+    } else if let Some(stem) = word.strip_suffix('e') {
+        stem.to_string()
+    } else {
+        word.to_string()
+    }
+}
+
+/// Replace an accented vowel with its plain form.
+fn strip_accent(c: char) -> char {
+    match c {
+        'á' => 'a',
+        'é' => 'e',
+        'í' => 'i',
+        'ó' => 'o',
+        'ú' => 'u',
+        _ => c,
+    }
+}
+
+/// Whether `word` has a single vowel group, like `mes` or `tos`.
+fn is_monosyllable(word: &str) -> bool {
+    let is_vowel = |c: char| "aeiouáéíóú".contains(c);
+    let chars: Vec<char> = word.chars().collect();
+
+    chars
+        .iter()
+        .enumerate()
+        .filter(|(i, c)| is_vowel(**c) && (*i == 0 || !is_vowel(chars[i - 1])))
+        .count()
+        == 1
+}
+
+/// Plural of a regular Spanish noun in lowercase.
+fn spanish_plural(word: &str) -> String {
+    let Some(last) = word.chars().last() else {
+        return String::new();
+    };
+    let stem = &word[..word.len() - last.len_utf8()];
+
+    match last {
+        'z' => format!("{}ces", stem),
+        'a' | 'e' | 'i' | 'o' | 'u' | 'á' | 'é' | 'í' | 'ó' | 'ú' => format!("{}s", word),
+        's' | 'x' => {
+            let stressed = stem.chars().last().is_some_and(|c| c != strip_accent(c));
+
+            if stressed {
+                format!("{}es", word.chars().map(strip_accent).collect::<String>())
+            } else if is_monosyllable(word) {
+                format!("{}es", word)
+            } else {
+                word.to_string()
+            }
+        }
+        _ => format!("{}es", word.chars().map(strip_accent).collect::<String>()),
+    }
+}
+
+/// Spanish nouns with the same form in singular and plural.
+///
+/// The stress of the singular can't be read from the plural, so unstressed words ending in `-s`
+/// are listed here instead of being derived.
+const SPANISH_UNCHANGED: &[&str] = &["sis", "lunes", "martes", "miércoles", "jueves", "viernes"];
+
+/// Put an accent on the last vowel of `word`, like `autobus` to `autobús`.
+fn add_accent(word: &str) -> String {
+    let Some((i, c)) = word
+        .char_indices()
+        .rev()
+        .find(|(_, c)| "aeiou".contains(*c))
+    else {
+        return word.to_string();
+    };
+    let accented = match c {
+        'a' => 'á',
+        'e' => 'é',
+        'i' => 'í',
+        'o' => 'ó',
+        _ => 'ú',
+    };
+
+    format!("{}{}{}", &word[..i], accented, &word[i + c.len_utf8()..])
+}
+
+/// Whether the `-es` plural of `stem` drops a final `-e` rather than a whole `-es`.
+///
+/// Only monosyllables starting with at most one consonant are read as `-s` words, so `meses`
+/// becomes `mes` and `clases` becomes `clase`.
+fn has_simple_onset(stem: &str) -> bool {
+    stem.chars()
+        .take_while(|c| !"aeiouáéíóú".contains(*c))
+        .count()
+        <= 1
+}
+
+/// Singular of a regular Spanish noun in lowercase, the inverse of `spanish_plural`.
+fn spanish_singular(word: &str) -> String {
+    if SPANISH_UNCHANGED
+        .iter()
+        .any(|suffix| word.ends_with(suffix))
+    {
+        word.to_string()
+    } else if let Some(stem) = word.strip_suffix("ces") {
+        format!("{}z", stem)
+    } else if let Some(stem) = word.strip_suffix("iones") {
+        format!("{}ión", stem)
+    } else if let Some(stem) = word
+        .strip_suffix("es")
+        .filter(|stem| stem.ends_with(['l', 'r', 'n', 'd', 'j', 'y']))
+    {
+        stem.to_string()
+    } else if let Some(stem) = word
+        .strip_suffix("es")
+        .filter(|stem| stem.ends_with(['s', 'x']))
+        .filter(|stem| !is_monosyllable(stem) || has_simple_onset(stem))
+    {
+        if is_monosyllable(stem) {
+            stem.to_string()
+        } else {
+            add_accent(stem)
+        }
+    } else if let Some(stem) = word.strip_suffix('s') {
+        stem.to_string()
+    } else {
+        word.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses_locales() {
+        assert_eq!("es-MX".parse(), Ok(Locale::Spanish));
+        assert_eq!("de_AT".parse(), Ok(Locale::German));
+        assert_eq!("EN".parse(), Ok(Locale::English));
+        assert_eq!("fr".parse::<Locale>(), Err(()));
+    }
+
+    #[test]
+    fn it_inflects_spanish() {
+        for (singular, plural) in [
+            ("casa", "casas"),
+            ("papel", "papeles"),
+            ("ciudad", "ciudades"),
+            ("luz", "luces"),
+            ("canción", "canciones"),
+            ("autobús", "autobuses"),
+            ("mes", "meses"),
+            ("lunes", "lunes"),
+            ("café", "cafés"),
+        ] {
+            assert_eq!(
+                spanish_plural(singular),
+                plural,
+                "Failed to test {}",
+                singular
+            );
+            assert_eq!(
+                spanish_singular(plural),
+                singular,
+                "Failed to test {}",
+                plural
+            );
+        }
+
+        for (plural, singular) in [
+            ("clases", "clase"),
+            ("ingleses", "inglés"),
+            ("crisis", "crisis"),
+        ] {
+            assert_eq!(
+                spanish_singular(plural),
+                singular,
+                "Failed to test {}",
+                plural
+            );
+        }
+    }
+
+    #[test]
+    fn it_inflects_german() {
+        for (singular, plural) in [
+            ("blume", "blumen"),
+            ("zeitung", "zeitungen"),
+            ("lehrerin", "lehrerinnen"),
+            ("lehrer", "lehrer"),
+            ("auto", "autos"),
+            ("tag", "tage"),
+        ] {
+            assert_eq!(
+                german_plural(singular),
+                plural,
+                "Failed to test {}",
+                singular
+            );
+        }
+
+        for (plural, singular) in [
+            ("blumen", "blume"),
+            ("zeitungen", "zeitung"),
+            ("lehrerinnen", "lehrerin"),
+            ("autos", "auto"),
+            ("tage", "tag"),
+        ] {
+            assert_eq!(
+                german_singular(plural),
+                singular,
+                "Failed to test {}",
+                plural
+            );
+        }
+    }
+
+    #[test]
+    fn it_follows_the_last_word_and_casing() {
+        for (locale, input, plural, ensure, expected) in [
+            (
+                Locale::Spanish,
+                "nueva Canción",
+                true,
+                false,
+                "nueva Canciones",
+            ),
+            (Locale::Spanish, "casas", true, true, "casas"),
+            (Locale::Spanish, "casas", false, true, "casa"),
+            (Locale::Spanish, "lunes", false, true, "lunes"),
+            (Locale::Spanish, "Meses", false, false, "Mes"),
+            (Locale::German, "Zeitung", true, false, "Zeitungen"),
+            (Locale::German, "ZEITUNG", true, false, "ZEITUNGEN"),
+            (Locale::German, "Zeitungen", true, true, "Zeitungen"),
+        ] {
+            assert_eq!(
+                locale.inflect(input, plural, ensure),
+                expected,
+                "Failed to test {}",
+                input
+            );
+        }
+    }
+}
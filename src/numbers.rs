@@ -0,0 +1,290 @@
+/// English names of the decimal digits
+const DIGITS: [&str; 10] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// English name of an ASCII digit.
+pub(crate) fn digit_word(c: char) -> Option<&'static str> {
+    c.to_digit(10).map(|d| DIGITS[d as usize])
+}
+
+/// English names of the numbers below twenty
+const UNITS: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+/// English names of the multiples of ten from twenty
+const TENS: [&str; 8] = [
+    "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+/// English names of the powers of a thousand
+const SCALES: [&str; 7] = [
+    "",
+    "thousand",
+    "million",
+    "billion",
+    "trillion",
+    "quadrillion",
+    "quintillion",
+];
+
+/// Parse an integer written with optional `,` or `_` thousands separators.
+pub(crate) fn parse_integer(input: &str) -> Option<i64> {
+    let input = input.trim();
+    let digits = input.strip_prefix('-').unwrap_or(input);
+
+    if digits.is_empty()
+        || !digits.starts_with(|c: char| c.is_ascii_digit())
+        || !digits
+            .chars()
+            .all(|c| c.is_ascii_digit() || c == ',' || c == '_')
+    {
+        return None;
+    }
+
+    input.replace([',', '_'], "").parse().ok()
+}
+
+/// English words for a number below a thousand.
+fn hundreds_to_words(n: u64) -> String {
+    let mut words = Vec::new();
+
+    if n >= 100 {
+        words.push(format!("{} hundred", UNITS[(n / 100) as usize]));
+    }
+
+    match n % 100 {
+        0 if n > 0 => {}
+        rest @ 0..=19 => words.push(UNITS[rest as usize].to_string()),
+        rest if rest % 10 == 0 => words.push(TENS[(rest / 10 - 2) as usize].to_string()),
+        rest => words.push(format!(
+            "{}-{}",
+            TENS[(rest / 10 - 2) as usize],
+            UNITS[(rest % 10) as usize]
+        )),
+    }
+
+    words.join(" ")
+}
+
+/// English words for `n`, like `minus one thousand two hundred thirty-four`.
+pub(crate) fn number_to_words(n: i64) -> String {
+    if n == 0 {
+        return UNITS[0].to_string();
+    }
+
+    let mut rest = n.unsigned_abs();
+    let mut groups = Vec::new();
+
+    for scale in SCALES {
+        let group = rest % 1000;
+
+        if group > 0 {
+            let words = hundreds_to_words(group);
+
+            groups.push(match scale {
+                "" => words,
+                scale => format!("{} {}", words, scale),
+            });
+        }
+
+        rest /= 1000;
+
+        if rest == 0 {
+            break;
+        }
+    }
+
+    groups.reverse();
+
+    let words = groups.join(" ");
+
+    if n < 0 {
+        format!("minus {}", words)
+    } else {
+        words
+    }
+}
+
+/// English ordinal words for `n`, like `twenty-third`.
+pub(crate) fn ordinal_words(n: i64) -> String {
+    let words = number_to_words(n);
+    let last = words.rfind([' ', '-']).map_or(0, |i| i + 1);
+    let (head, word) = words.split_at(last);
+
+    let ordinal = match word {
+        "one" => "first".to_string(),
+        "two" => "second".to_string(),
+        "three" => "third".to_string(),
+        "five" => "fifth".to_string(),
+        "eight" => "eighth".to_string(),
+        "nine" => "ninth".to_string(),
+        "twelve" => "twelfth".to_string(),
+        word => match word.strip_suffix('y') {
+            Some(stem) => format!("{}ieth", stem),
+            None => format!("{}th", word),
+        },
+    };
+
+    format!("{}{}", head, ordinal)
+}
+
+/// Roman numerals with their values, largest first, including subtractive pairs
+const ROMAN: [(&str, u32); 13] = [
+    ("M", 1000),
+    ("CM", 900),
+    ("D", 500),
+    ("CD", 400),
+    ("C", 100),
+    ("XC", 90),
+    ("L", 50),
+    ("XL", 40),
+    ("X", 10),
+    ("IX", 9),
+    ("V", 5),
+    ("IV", 4),
+    ("I", 1),
+];
+
+/// Roman numeral for `n` from 1 to 3999.
+pub(crate) fn to_roman(n: i64) -> Option<String> {
+    let mut rest = u32::try_from(n).ok().filter(|n| (1..4000).contains(n))?;
+    let mut roman = String::new();
+
+    for (numeral, value) in ROMAN {
+        while rest >= value {
+            roman.push_str(numeral);
+            rest -= value;
+        }
+    }
+
+    Some(roman)
+}
+
+/// Value of a Roman numeral in canonical form, in either case.
+pub(crate) fn from_roman(input: &str) -> Option<u32> {
+    let upper = input.trim().to_uppercase();
+    let mut rest = upper.as_str();
+    let mut value = 0;
+
+    for (numeral, numeral_value) in ROMAN {
+        while let Some(tail) = rest.strip_prefix(numeral) {
+            value += numeral_value;
+            rest = tail;
+        }
+    }
+
+    let canonical = rest.is_empty() && to_roman(value.into()).is_some_and(|roman| roman == upper);
+
+    canonical.then_some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_spells_numbers() {
+        for (n, expected) in [
+            (0, "zero"),
+            (7, "seven"),
+            (13, "thirteen"),
+            (40, "forty"),
+            (42, "forty-two"),
+            (100, "one hundred"),
+            (101, "one hundred one"),
+            (1_000, "one thousand"),
+            (1_234, "one thousand two hundred thirty-four"),
+            (2_000_015, "two million fifteen"),
+            (-42, "minus forty-two"),
+            (
+                i64::MIN,
+                "minus nine quintillion two hundred twenty-three quadrillion three hundred seventy-two trillion thirty-six billion eight hundred fifty-four million seven hundred seventy-five thousand eight hundred eight",
+            ),
+        ] {
+            assert_eq!(number_to_words(n), expected, "Failed to test {}", n);
+        }
+    }
+
+    #[test]
+    fn it_spells_ordinals() {
+        for (n, expected) in [
+            (0, "zeroth"),
+            (1, "first"),
+            (2, "second"),
+            (3, "third"),
+            (12, "twelfth"),
+            (20, "twentieth"),
+            (23, "twenty-third"),
+            (100, "one hundredth"),
+            (1_005, "one thousand fifth"),
+            (-1, "minus first"),
+        ] {
+            assert_eq!(ordinal_words(n), expected, "Failed to test {}", n);
+        }
+    }
+
+    #[test]
+    fn it_converts_roman_numerals() {
+        for (n, roman) in [
+            (1, "I"),
+            (4, "IV"),
+            (9, "IX"),
+            (14, "XIV"),
+            (40, "XL"),
+            (1994, "MCMXCIV"),
+            (3999, "MMMCMXCIX"),
+        ] {
+            assert_eq!(to_roman(n).as_deref(), Some(roman), "Failed to test {}", n);
+            assert_eq!(
+                from_roman(roman),
+                Some(n as u32),
+                "Failed to test {}",
+                roman
+            );
+        }
+
+        assert_eq!(to_roman(0), None);
+        assert_eq!(to_roman(4000), None);
+        assert_eq!(from_roman("xiv"), Some(14));
+        assert_eq!(from_roman("IIII"), None);
+        assert_eq!(from_roman("IM"), None);
+        assert_eq!(from_roman(""), None);
+        assert_eq!(from_roman("Mix up"), None);
+    }
+
+    #[test]
+    fn it_parses_integers() {
+        for (input, expected) in [
+            ("42", Some(42)),
+            (" -1,000 ", Some(-1000)),
+            ("1_000_000", Some(1_000_000)),
+            ("1.5", None),
+            ("-", None),
+            (",1", None),
+            ("forty", None),
+        ] {
+            assert_eq!(parse_integer(input), expected, "Failed to test {}", input);
+        }
+    }
+}
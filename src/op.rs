@@ -0,0 +1,460 @@
+use std::fmt;
+use std::str::FromStr;
+
+use handlebars::JsonValue;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{canonical, HandlebarsInflector, Pipeline, PipelineError, OPERATIONS};
+
+/// Operation of the `inflect` helper, named like its hash option
+///
+/// Operations are written the way the `ops` hash option lists them, `to_plural` or `to=kebab`,
+/// and serialized as such strings, so lists of them can be validated and stored. Parsing also
+/// accepts the short names of [`Pipeline`], like `plural` or `to-kebab-case`:
+///
+/// ```
+/// use handlebars_inflector::Op;
+///
+/// let op: Op = "to=kebab".parse().expect("Unknown operation");
+///
+/// assert_eq!(op, Op::To("kebab".to_string()));
+/// assert_eq!(op.to_string(), "to=kebab");
+/// assert_eq!("kebab".parse::<Op>(), Ok(Op::ToKebabCase));
+/// assert!("kebab_case_please".parse::<Op>().is_err());
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum Op {
+    Transliterate,
+    NormalizeConfusables,
+    Normalize,
+    Abbreviate,
+    Stem,
+    /// Phonetic key with the named algorithm
+    Phonetic(String),
+    FromRoman,
+    NumberToWords,
+    OrdinalizeWords,
+    ToRoman,
+    /// Explicit case conversion to the named convention or custom case
+    To(String),
+    /// Standard convention of the named language for the `kind` hash option
+    Target(String),
+    ToCamelCase,
+    ToPascalCase,
+    ToSnakeCase,
+    ToScreamingSnakeCase,
+    ToEnvVar,
+    ToKebabCase,
+    ToTrainCase,
+    ToDotCase,
+    ToPathCase,
+    ToSentenceCase,
+    ToTitleCase,
+    Humanize,
+    Parameterize,
+    ToAcronym,
+    Ordinalize,
+    Deordinalize,
+    ToForeignKey,
+    Demodulize,
+    Deconstantize,
+    ToClassCase,
+    ToTableCase,
+    ToPlural,
+    ToSingular,
+    EnsurePlural,
+    EnsureSingular,
+    /// Singular or plural form agreeing with the count
+    Count(f64),
+    AgreeCount,
+    IndefiniteArticle,
+    SwapCase,
+    Capitalize,
+    Decapitalize,
+    ToUpperCase,
+    ToLowerCase,
+    /// Shortened to at most this many characters, cut between words
+    TruncateWords(usize),
+    ToIdentifier,
+    ToRawIdentifier,
+    QuoteIdentifier,
+    ToFilename,
+    ToDnsLabel,
+}
+
+impl Op {
+    /// Name of the operation's hash option
+    pub fn name(&self) -> &'static str {
+        match self {
+            Op::Transliterate => "transliterate",
+            Op::NormalizeConfusables => "normalize_confusables",
+            Op::Normalize => "normalize",
+            Op::Abbreviate => "abbreviate",
+            Op::Stem => "stem",
+            Op::Phonetic(_) => "phonetic",
+            Op::To(_) => "to",
+            Op::Target(_) => "target",
+            Op::ToCamelCase => "to_camel_case",
+            Op::ToPascalCase => "to_pascal_case",
+            Op::ToSnakeCase => "to_snake_case",
+            Op::ToScreamingSnakeCase => "to_screaming_snake_case",
+            Op::ToEnvVar => "to_env_var",
+            Op::ToKebabCase => "to_kebab_case",
+            Op::ToTrainCase => "to_train_case",
+            Op::ToDotCase => "to_dot_case",
+            Op::ToPathCase => "to_path_case",
+            Op::ToSentenceCase => "to_sentence_case",
+            Op::ToTitleCase => "to_title_case",
+            Op::Humanize => "humanize",
+            Op::Parameterize => "parameterize",
+            Op::ToAcronym => "to_acronym",
+            Op::Ordinalize => "ordinalize",
+            Op::Deordinalize => "deordinalize",
+            Op::FromRoman => "from_roman",
+            Op::NumberToWords => "number_to_words",
+            Op::OrdinalizeWords => "ordinalize_words",
+            Op::ToRoman => "to_roman",
+            Op::ToForeignKey => "to_foreign_key",
+            Op::Demodulize => "demodulize",
+            Op::Deconstantize => "deconstantize",
+            Op::ToClassCase => "to_class_case",
+            Op::ToTableCase => "to_table_case",
+            Op::ToPlural => "to_plural",
+            Op::ToSingular => "to_singular",
+            Op::EnsurePlural => "ensure_plural",
+            Op::EnsureSingular => "ensure_singular",
+            Op::Count(_) => "count",
+            Op::AgreeCount => "agree_count",
+            Op::IndefiniteArticle => "indefinite_article",
+            Op::SwapCase => "swap_case",
+            Op::Capitalize => "capitalize",
+            Op::Decapitalize => "decapitalize",
+            Op::ToUpperCase => "to_upper_case",
+            Op::ToLowerCase => "to_lower_case",
+            Op::TruncateWords(_) => "truncate_words",
+            Op::ToIdentifier => "to_identifier",
+            Op::ToRawIdentifier => "to_raw_identifier",
+            Op::QuoteIdentifier => "quote_identifier",
+            Op::ToFilename => "to_filename",
+            Op::ToDnsLabel => "to_dns_label",
+        }
+    }
+
+    /// Value of the operation's hash option, `true` for flags.
+    pub(crate) fn value(&self) -> JsonValue {
+        match self {
+            Op::Phonetic(value) | Op::To(value) | Op::Target(value) => json!(value),
+            Op::Count(count) => json!(count),
+            Op::TruncateWords(max) => json!(max),
+            _ => json!(true),
+        }
+    }
+}
+
+impl fmt::Display for Op {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Op::Phonetic(value) | Op::To(value) | Op::Target(value) => {
+                write!(f, "{}={}", self.name(), value)
+            }
+            Op::Count(count) => write!(f, "{}={}", self.name(), count),
+            Op::TruncateWords(max) => write!(f, "{}={}", self.name(), max),
+            _ => f.write_str(self.name()),
+        }
+    }
+}
+
+impl FromStr for Op {
+    type Err = PipelineError;
+
+    /// Parse an operation by its hash option name, short name or Rails alias, value operations as
+    /// `name=value`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let unknown = || PipelineError::UnknownOperation(s.to_string());
+
+        let Some((name, value)) = s.split_once('=') else {
+            return resolve(s.trim()).and_then(flag).ok_or_else(unknown);
+        };
+
+        let value = value.trim();
+
+        match resolve(name.trim()).ok_or_else(unknown)? {
+            "phonetic" => Ok(Op::Phonetic(value.to_string())),
+            "to" => Ok(Op::To(value.to_string())),
+            "target" => Ok(Op::Target(value.to_string())),
+            "count" => value.parse().map(Op::Count).map_err(|_| unknown()),
+            "truncate_words" => value.parse().map(Op::TruncateWords).map_err(|_| unknown()),
+            _ => Err(unknown()),
+        }
+    }
+}
+
+impl TryFrom<String> for Op {
+    type Error = PipelineError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<Op> for String {
+    fn from(op: Op) -> Self {
+        op.to_string()
+    }
+}
+
+/// Resolve `snake`, `to_snake`, `to-snake-case` or an alias like `pluralize` to the name of an
+/// operation.
+fn resolve(name: &str) -> Option<&'static str> {
+    let name = canonical(name).replace('-', "_");
+
+    [
+        name.clone(),
+        format!("to_{}", name),
+        format!("to_{}_case", name),
+    ]
+    .into_iter()
+    .find_map(|candidate| OPERATIONS.iter().copied().find(|op| *op == candidate))
+}
+
+/// Operation enabled with the boolean flag `name`.
+fn flag(name: &str) -> Option<Op> {
+    Some(match name {
+        "transliterate" => Op::Transliterate,
+        "normalize_confusables" => Op::NormalizeConfusables,
+        "normalize" => Op::Normalize,
+        "abbreviate" => Op::Abbreviate,
+        "stem" => Op::Stem,
+        "to_camel_case" => Op::ToCamelCase,
+        "to_pascal_case" => Op::ToPascalCase,
+        "to_snake_case" => Op::ToSnakeCase,
+        "to_screaming_snake_case" => Op::ToScreamingSnakeCase,
+        "to_env_var" => Op::ToEnvVar,
+        "to_kebab_case" => Op::ToKebabCase,
+        "to_train_case" => Op::ToTrainCase,
+        "to_dot_case" => Op::ToDotCase,
+        "to_path_case" => Op::ToPathCase,
+        "to_sentence_case" => Op::ToSentenceCase,
+        "to_title_case" => Op::ToTitleCase,
+        "humanize" => Op::Humanize,
+        "parameterize" => Op::Parameterize,
+        "to_acronym" => Op::ToAcronym,
+        "ordinalize" => Op::Ordinalize,
+        "deordinalize" => Op::Deordinalize,
+        "number_to_words" => Op::NumberToWords,
+        "ordinalize_words" => Op::OrdinalizeWords,
+        "from_roman" => Op::FromRoman,
+        "to_roman" => Op::ToRoman,
+        "to_foreign_key" => Op::ToForeignKey,
+        "demodulize" => Op::Demodulize,
+        "deconstantize" => Op::Deconstantize,
+        "to_class_case" => Op::ToClassCase,
+        "to_table_case" => Op::ToTableCase,
+        "to_plural" => Op::ToPlural,
+        "to_singular" => Op::ToSingular,
+        "ensure_plural" => Op::EnsurePlural,
+        "ensure_singular" => Op::EnsureSingular,
+        "agree_count" => Op::AgreeCount,
+        "indefinite_article" => Op::IndefiniteArticle,
+        "swap_case" => Op::SwapCase,
+        "capitalize" => Op::Capitalize,
+        "decapitalize" => Op::Decapitalize,
+        "to_upper_case" => Op::ToUpperCase,
+        "to_lower_case" => Op::ToLowerCase,
+        "to_identifier" => Op::ToIdentifier,
+        "to_raw_identifier" => Op::ToRawIdentifier,
+        "quote_identifier" => Op::QuoteIdentifier,
+        "to_filename" => Op::ToFilename,
+        "to_dns_label" => Op::ToDnsLabel,
+        _ => return None,
+    })
+}
+
+impl HandlebarsInflector {
+    /// Apply `ops` to `input` in order with this helper, like a [`Pipeline`] of them.
+    ///
+    /// Invalid values, like an unknown case convention, fail with an error. Build a [`Pipeline`]
+    /// once to apply the same operations to many strings.
+    pub fn inflect_str(&self, input: &str, ops: &[Op]) -> Result<String, PipelineError> {
+        Pipeline::with_inflector(self.clone())
+            .with_ops(ops.iter().cloned())
+            .apply(input)
+    }
+}
+
+/// Apply `ops` to `input` in order with the `inflect` helper without any configuration.
+///
+/// ```
+/// use handlebars_inflector::{inflect_str, Op};
+///
+/// assert_eq!(
+///     inflect_str("ProductImage", &[Op::ToSnakeCase, Op::ToPlural]).expect("Inflect error"),
+///     "product_images"
+/// );
+/// ```
+pub fn inflect_str(input: &str, ops: &[Op]) -> Result<String, PipelineError> {
+    HandlebarsInflector::new().inflect_str(input, ops)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Strictness, OPERATIONS};
+
+    #[test]
+    fn it_names_every_operation() {
+        let ops = [
+            Op::Transliterate,
+            Op::NormalizeConfusables,
+            Op::Normalize,
+            Op::Abbreviate,
+            Op::Stem,
+            Op::Phonetic(String::new()),
+            Op::FromRoman,
+            Op::NumberToWords,
+            Op::OrdinalizeWords,
+            Op::ToRoman,
+            Op::To(String::new()),
+            Op::Target(String::new()),
+            Op::ToCamelCase,
+            Op::ToPascalCase,
+            Op::ToSnakeCase,
+            Op::ToScreamingSnakeCase,
+            Op::ToEnvVar,
+            Op::ToKebabCase,
+            Op::ToTrainCase,
+            Op::ToDotCase,
+            Op::ToPathCase,
+            Op::ToSentenceCase,
+            Op::ToTitleCase,
+            Op::Humanize,
+            Op::Parameterize,
+            Op::ToAcronym,
+            Op::Ordinalize,
+            Op::Deordinalize,
+            Op::ToForeignKey,
+            Op::Demodulize,
+            Op::Deconstantize,
+            Op::ToClassCase,
+            Op::ToTableCase,
+            Op::ToPlural,
+            Op::ToSingular,
+            Op::EnsurePlural,
+            Op::EnsureSingular,
+            Op::Count(0.0),
+            Op::AgreeCount,
+            Op::IndefiniteArticle,
+            Op::SwapCase,
+            Op::Capitalize,
+            Op::Decapitalize,
+            Op::ToUpperCase,
+            Op::ToLowerCase,
+            Op::TruncateWords(0),
+            Op::ToIdentifier,
+            Op::ToRawIdentifier,
+            Op::QuoteIdentifier,
+            Op::ToFilename,
+            Op::ToDnsLabel,
+        ];
+
+        assert_eq!(ops.iter().map(Op::name).collect::<Vec<_>>(), OPERATIONS);
+
+        for op in ops {
+            let value = match op {
+                Op::Phonetic(_) => Op::Phonetic("soundex".to_string()),
+                Op::To(_) => Op::To("kebab".to_string()),
+                Op::Target(_) => Op::Target("rust".to_string()),
+                Op::Count(_) => Op::Count(2.5),
+                Op::TruncateWords(_) => Op::TruncateWords(20),
+                op => op,
+            };
+
+            assert_eq!(
+                value.to_string().parse(),
+                Ok(value.clone()),
+                "Failed to test {}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn it_parses_operations() {
+        for (input, expected) in [
+            ("to_plural", Ok(Op::ToPlural)),
+            ("to = kebab", Ok(Op::To("kebab".to_string()))),
+            ("count=3", Ok(Op::Count(3.0))),
+            ("count=many", Err(())),
+            ("to", Err(())),
+            ("to_plural=true", Err(())),
+            ("pluralize", Ok(Op::ToPlural)),
+            ("plural", Ok(Op::ToPlural)),
+            ("snake", Ok(Op::ToSnakeCase)),
+            ("screaming-snake", Ok(Op::ToScreamingSnakeCase)),
+            ("foreign_key", Ok(Op::ToForeignKey)),
+            ("demodulize", Ok(Op::Demodulize)),
+            ("truncate-words=10", Ok(Op::TruncateWords(10))),
+            ("pluralise", Err(())),
+        ] {
+            assert_eq!(
+                input.parse::<Op>().map_err(|_| ()),
+                expected,
+                "Failed to test {}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn it_serializes_operations() {
+        let ops = vec![Op::ToSnakeCase, Op::To("kebab".to_string()), Op::Count(3.0)];
+        let json = serde_json::to_string(&ops).expect("Serialize error");
+
+        assert_eq!(json, r#"["to_snake_case","to=kebab","count=3"]"#);
+        assert_eq!(
+            serde_json::from_str::<Vec<Op>>(&json).expect("Deserialize error"),
+            ops
+        );
+        assert!(serde_json::from_str::<Op>(r#""pluralise""#).is_err());
+    }
+
+    #[test]
+    fn it_inflects_strings() {
+        for (input, ops, expected) in [
+            (
+                "ProductImage",
+                vec![Op::ToSnakeCase, Op::ToPlural],
+                "product_images",
+            ),
+            ("box", vec![Op::ToPlural, Op::ToUpperCase], "BOXES"),
+            ("ABTest", vec![Op::To("kebab".to_string())], "ab-test"),
+            ("apple", vec![Op::Count(3.0)], "apples"),
+            ("apples", vec![Op::Count(1.0)], "apple"),
+            ("<b>&", vec![], "<b>&"),
+        ] {
+            assert_eq!(
+                inflect_str(input, &ops).expect("Inflect error"),
+                expected,
+                "Failed to test {:?}",
+                ops
+            );
+        }
+
+        let inflector = HandlebarsInflector::new()
+            .with_rules([("octopus", "octopuses")])
+            .with_reserved(["type"])
+            .with_strictness(Strictness::Always);
+
+        assert_eq!(
+            inflector
+                .inflect_str("SeaOctopus", &[Op::ToTableCase])
+                .expect("Inflect error"),
+            "sea_octopuses"
+        );
+        assert!(inflector.inflect_str("type", &[Op::ToLowerCase]).is_err());
+        assert!(inflect_str("AsIs", &[Op::To("nope".to_string())]).is_err());
+        assert!(inflect_str("AsIs", &[Op::To("snake|to_plural".to_string())]).is_err());
+    }
+}
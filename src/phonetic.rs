@@ -0,0 +1,74 @@
+use std::str::FromStr;
+
+use rphonetic::{DoubleMetaphone, Encoder, Metaphone, Soundex};
+
+/// Phonetic algorithm selected with the `phonetic` hash option
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Phonetic {
+    /// American Soundex (`Robert` to `R163`)
+    Soundex,
+    /// Original Metaphone (`Knight` to `NT`)
+    Metaphone,
+    /// Primary Double Metaphone code (`Schmidt` to `XMT`)
+    DoubleMetaphone,
+    /// Alternate Double Metaphone code (`Schmidt` to `SMT`)
+    DoubleMetaphoneAlternate,
+}
+
+impl FromStr for Phonetic {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "soundex" => Ok(Phonetic::Soundex),
+            "metaphone" => Ok(Phonetic::Metaphone),
+            "double_metaphone" => Ok(Phonetic::DoubleMetaphone),
+            "double_metaphone_alternate" => Ok(Phonetic::DoubleMetaphoneAlternate),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Encode every word of `input`, joining the codes with a space.
+pub(crate) fn phonetic_key(input: &str, algorithm: Phonetic) -> String {
+    let encode = |word: &str| match algorithm {
+        Phonetic::Soundex => Soundex::default().encode(word),
+        Phonetic::Metaphone => Metaphone::default().encode(word),
+        Phonetic::DoubleMetaphone => DoubleMetaphone::default().encode(word),
+        Phonetic::DoubleMetaphoneAlternate => DoubleMetaphone::default().encode_alternate(word),
+    };
+
+    input
+        .split(|c: char| !c.is_alphabetic())
+        .filter(|word| !word.is_empty())
+        .map(encode)
+        .filter(|code| !code.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_encodes() {
+        for (input, algorithm, expected) in [
+            ("Robert", Phonetic::Soundex, "R163"),
+            ("Rupert", Phonetic::Soundex, "R163"),
+            ("John Smith", Phonetic::Soundex, "J500 S530"),
+            ("Knight", Phonetic::Metaphone, "NT"),
+            ("Schmidt", Phonetic::DoubleMetaphone, "XMT"),
+            ("Schmidt", Phonetic::DoubleMetaphoneAlternate, "SMT"),
+            ("", Phonetic::Soundex, ""),
+        ] {
+            assert_eq!(
+                phonetic_key(input, algorithm),
+                expected,
+                "Failed to encode {} with {:?}",
+                input,
+                algorithm
+            );
+        }
+    }
+}
@@ -0,0 +1,271 @@
+use std::fmt;
+
+use handlebars::{no_escape, Handlebars, JsonValue};
+use serde_json::{json, Map};
+
+use crate::identifier::Profile;
+use crate::{canonical, HandlebarsInflector, Op, Rules, OPERATIONS, VALUE_OPERATIONS};
+
+/// Error returned by [`Pipeline`] and by parsing an [`Op`](crate::Op)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PipelineError {
+    /// The name does not resolve to an operation
+    UnknownOperation(String),
+    /// An option key names an operation, which would run it in every step
+    OperationOption(String),
+    /// An operation failed, e.g. because of an invalid option
+    Inflect(String),
+}
+
+impl fmt::Display for PipelineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PipelineError::UnknownOperation(name) => write!(f, "Unknown operation `{}`", name),
+            PipelineError::OperationOption(key) => {
+                write!(f, "Option `{}` is an operation, add it with `with_op`", key)
+            }
+            PipelineError::Inflect(message) => write!(f, "Failed to inflect: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for PipelineError {}
+
+/// Ordered sequence of `inflect` operations applied to plain strings
+///
+/// Every operation runs as a separate call of the [`inflect`](struct@crate::HandlebarsInflector)
+/// helper on the result of the previous one, so the order is the order the operations were added
+/// in and the results are exactly the ones templates render. Operations are named like their hash
+/// options (`to_snake_case`, `to=kebab`) or by a short name (`snake`, `plural`), see
+/// [`operations`](Pipeline::operations).
+///
+/// ```
+/// use handlebars_inflector::Pipeline;
+///
+/// let pipeline = Pipeline::new()
+///     .with_op("snake").expect("Unknown operation")
+///     .with_op("plural").expect("Unknown operation");
+///
+/// assert_eq!(pipeline.apply("ProductImage").expect("Inflect error"), "product_images");
+/// ```
+#[derive(Clone)]
+pub struct Pipeline {
+    inflector: HandlebarsInflector,
+    steps: Vec<(&'static str, JsonValue)>,
+    options: Map<String, JsonValue>,
+    registry: Handlebars<'static>,
+}
+
+impl Default for Pipeline {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Pipeline {
+    /// Create an empty pipeline using the `inflect` helper without any configuration
+    pub fn new() -> Self {
+        Self::with_inflector(HandlebarsInflector::new())
+    }
+
+    /// Create an empty pipeline using a configured `inflect` helper
+    pub fn with_inflector(inflector: HandlebarsInflector) -> Self {
+        let mut pipeline = Pipeline {
+            inflector,
+            steps: Vec::new(),
+            options: Map::new(),
+            registry: Handlebars::new(),
+        };
+
+        pipeline.compile();
+        pipeline
+    }
+
+    /// Names of all operations, value operations written as `name=VALUE`
+    pub fn operations() -> Vec<String> {
+        flags()
+            .map(|name| name.to_string())
+            .chain(
+                VALUE_OPERATIONS
+                    .iter()
+                    .map(|name| format!("{}=VALUE", name)),
+            )
+            .collect()
+    }
+
+    /// Names of the identifier profiles accepted by the `profile` option
+    pub fn profiles() -> Vec<String> {
+        Profile::NAMES.iter().map(|name| name.to_string()).collect()
+    }
+
+    /// Append an operation: `to_snake_case`, `snake`, `to=kebab` or `phonetic=soundex`, parsed
+    /// as an [`Op`](crate::Op)
+    pub fn with_op(self, op: &str) -> Result<Self, PipelineError> {
+        Ok(self.with_ops([op.parse()?]))
+    }
+
+    /// Append operations given as [`Op`](crate::Op) values
+    pub fn with_ops<I: IntoIterator<Item = Op>>(mut self, ops: I) -> Self {
+        self.steps
+            .extend(ops.into_iter().map(|op| (op.name(), op.value())));
+        self.compile();
+        self
+    }
+
+    /// Pass a hash option like `profile` or `scheme` to every operation
+    ///
+    /// Keys naming an operation, like `to_plural` or `ops`, are rejected.
+    pub fn with_option<K: Into<String>, V: Into<JsonValue>>(
+        mut self,
+        key: K,
+        value: V,
+    ) -> Result<Self, PipelineError> {
+        let key = key.into();
+
+        if matches!(key.as_str(), "ops" | "pipeline") || OPERATIONS.contains(&canonical(&key)) {
+            return Err(PipelineError::OperationOption(key));
+        }
+
+        self.options.insert(key, value.into());
+        self.compile();
+        Ok(self)
+    }
+
+    /// Add custom rules to the `inflect` helper, see [`HandlebarsInflector::with_rule_set`]
+    pub fn with_rule_set(mut self, rules: Rules) -> Self {
+        self.inflector = self.inflector.with_rule_set(rules);
+        self.compile();
+        self
+    }
+
+    /// Whether no operation has been added yet
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Apply all operations to `input` in order
+    pub fn apply(&self, input: &str) -> Result<String, PipelineError> {
+        let mut output = input.to_string();
+
+        for (i, (_, value)) in self.steps.iter().enumerate() {
+            let context = json!({ "input": output, "step": value, "options": self.options });
+
+            output = self
+                .registry
+                .render(&i.to_string(), &context)
+                .map_err(|e| PipelineError::Inflect(e.reason().to_string()))?;
+        }
+
+        Ok(output)
+    }
+
+    /// Register one template per step, reading the input and options from the context.
+    fn compile(&mut self) {
+        let mut registry = Handlebars::new();
+        registry.set_strict_mode(true);
+        registry.register_escape_fn(no_escape);
+        registry.register_helper("inflect", Box::new(self.inflector.clone()));
+
+        for (i, (name, _)) in self.steps.iter().enumerate() {
+            let mut template = format!("{{{{inflect input {}=step", name);
+
+            for key in self.options.keys() {
+                template.push_str(&format!(" {}=options.[{}]", key, key));
+            }
+
+            template.push_str("}}");
+
+            if let Err(e) = registry.register_template_string(&i.to_string(), template) {
+                log::warn!("Failed to compile pipeline step `{}`: {}", name, e);
+            }
+        }
+
+        self.registry = registry;
+    }
+}
+
+/// Operations enabled with a boolean flag, in the helper's order of execution
+fn flags() -> impl Iterator<Item = &'static str> + Clone {
+    OPERATIONS
+        .iter()
+        .copied()
+        .filter(|name| !VALUE_OPERATIONS.contains(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_resolves_operations() {
+        assert_eq!(
+            Pipeline::new().with_op("pluralise").err(),
+            Some(PipelineError::UnknownOperation("pluralise".to_string()))
+        );
+        assert!(Pipeline::new().with_op("pluralize").is_ok());
+
+        for key in ["to_plural", "pluralize", "to", "ops", "pipeline"] {
+            assert_eq!(
+                Pipeline::new().with_option(key, true).err(),
+                Some(PipelineError::OperationOption(key.to_string())),
+                "Failed to test {}",
+                key
+            );
+        }
+    }
+
+    #[test]
+    fn it_applies_operations_in_order() {
+        for (ops, input, expected) in [
+            (vec!["snake", "plural"], "ProductImage", "product_images"),
+            (vec!["plural", "upper"], "box", "BOXES"),
+            (vec!["to=kebab"], "ABTest", "ab-test"),
+            (vec!["truncate_words=10"], "Product images", "Product..."),
+            (vec![], "As is", "As is"),
+        ] {
+            let pipeline = ops
+                .iter()
+                .try_fold(Pipeline::new(), |pipeline, op| pipeline.with_op(op))
+                .expect("Unknown operation");
+
+            assert_eq!(
+                pipeline.apply(input).expect("Inflect error"),
+                expected,
+                "Failed to test {:?}",
+                ops
+            );
+        }
+
+        let pipeline = Pipeline::new()
+            .with_op("identifier")
+            .expect("Unknown operation")
+            .with_option("profile", "rust")
+            .expect("Invalid option");
+
+        assert_eq!(
+            pipeline.apply("2fa enabled").expect("Inflect error"),
+            "_2fa_enabled"
+        );
+
+        let pipeline = Pipeline::new()
+            .with_op("pascal")
+            .expect("Unknown operation")
+            .with_rule_set(
+                HandlebarsInflector::new()
+                    .with_reserved(["Type"])
+                    .with_override("oauth2_token", "OAuth2Token")
+                    .rules(),
+            );
+
+        assert_eq!(
+            pipeline.apply("oauth2_token").expect("Inflect error"),
+            "OAuth2Token"
+        );
+        assert!(pipeline.apply("type").is_err());
+        assert!(Pipeline::new()
+            .with_op("to=nope")
+            .expect("Unknown operation")
+            .apply("x")
+            .is_err());
+    }
+}
@@ -0,0 +1,379 @@
+use std::str::FromStr;
+
+use handlebars::{
+    Context, Handlebars, Helper, HelperDef, HelperResult, JsonValue, Output, RenderContext,
+    RenderErrorReason,
+};
+
+use crate::hash_option_for;
+
+/// CLDR plural category
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl PluralCategory {
+    /// Name of the category as used by ICU MessageFormat and CLDR.
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            PluralCategory::Zero => "zero",
+            PluralCategory::One => "one",
+            PluralCategory::Two => "two",
+            PluralCategory::Few => "few",
+            PluralCategory::Many => "many",
+            PluralCategory::Other => "other",
+        }
+    }
+}
+
+/// Plural operands of a decimal number as defined by
+/// [UTS #35](https://unicode.org/reports/tr35/tr35-numbers.html#Operands)
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Operands {
+    /// Absolute value
+    pub(crate) n: f64,
+    /// Integer digits
+    pub(crate) i: u64,
+    /// Number of visible fraction digits, with trailing zeros
+    pub(crate) v: usize,
+    /// Visible fraction digits, with trailing zeros
+    pub(crate) f: u64,
+    /// Written form, substituted for `#`
+    pub(crate) display: String,
+}
+
+impl Operands {
+    /// Extract operands from a helper parameter.
+    ///
+    /// Numbers and numeric strings keep their visible fraction digits, so `"1.0"` is not `one` in
+    /// English. Arrays and objects contribute their length.
+    pub(crate) fn of(value: &JsonValue) -> Option<Self> {
+        match value {
+            JsonValue::Number(n) => n.to_string().parse().ok(),
+            JsonValue::String(s) => s.trim().parse().ok(),
+            JsonValue::Array(a) => a.len().to_string().parse().ok(),
+            JsonValue::Object(o) => o.len().to_string().parse().ok(),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for Operands {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let n = s.parse::<f64>().map_err(|_| ())?.abs();
+        let digits = s.trim_start_matches(['-', '+']);
+        let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+
+        let plain = !integer.is_empty()
+            && integer.bytes().all(|b| b.is_ascii_digit())
+            && fraction.bytes().all(|b| b.is_ascii_digit());
+
+        let (i, v, f) = if plain {
+            (
+                integer.parse().map_err(|_| ())?,
+                fraction.len(),
+                fraction.parse().unwrap_or(0),
+            )
+        } else {
+            // Exponents and the like: fall back to the value.
+            (n.trunc() as u64, 0, 0)
+        };
+
+        Ok(Operands {
+            n,
+            i,
+            v,
+            f,
+            display: s.to_string(),
+        })
+    }
+}
+
+/// Locale with known cardinal plural rules, selected with the `locale` hash option
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) enum PluralLocale {
+    /// English, German, Dutch and the Scandinavian languages: `one`, `other`
+    #[default]
+    English,
+    /// Italian, Spanish: `one`, `many`, `other`
+    Italian,
+    /// French, Portuguese: `one` includes zero, `many`, `other`
+    French,
+    /// Russian, Ukrainian: `one`, `few`, `many`, `other`
+    Russian,
+    /// Polish: `one`, `few`, `many`, `other`
+    Polish,
+    /// Czech, Slovak: `one`, `few`, `many`, `other`
+    Czech,
+    /// Arabic: all six categories
+    Arabic,
+    /// Chinese, Japanese, Korean and others without plural inflection: `other`
+    Invariant,
+}
+
+impl FromStr for PluralLocale {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let language = s.split(['-', '_']).next().unwrap_or_default();
+
+        match language.to_lowercase().as_str() {
+            "en" | "de" | "nl" | "sv" | "da" | "no" | "nb" | "nn" | "fi" | "et" => {
+                Ok(PluralLocale::English)
+            }
+            "it" | "es" | "ca" => Ok(PluralLocale::Italian),
+            "fr" | "pt" => Ok(PluralLocale::French),
+            "ru" | "uk" | "be" => Ok(PluralLocale::Russian),
+            "pl" => Ok(PluralLocale::Polish),
+            "cs" | "sk" => Ok(PluralLocale::Czech),
+            "ar" => Ok(PluralLocale::Arabic),
+            "zh" | "ja" | "ko" | "vi" | "th" | "id" | "ms" => Ok(PluralLocale::Invariant),
+            _ => Err(()),
+        }
+    }
+}
+
+impl PluralLocale {
+    /// Cardinal plural category of a number.
+    pub(crate) fn category(self, o: &Operands) -> PluralCategory {
+        use PluralCategory::*;
+
+        let i10 = o.i % 10;
+        let i100 = o.i % 100;
+        let millions = o.v == 0 && o.i != 0 && o.i % 1_000_000 == 0;
+
+        match self {
+            PluralLocale::English if o.i == 1 && o.v == 0 => One,
+            PluralLocale::Italian if o.i == 1 && o.v == 0 => One,
+            PluralLocale::French if o.i <= 1 => One,
+            PluralLocale::Italian | PluralLocale::French if millions => Many,
+            PluralLocale::Russian if o.v != 0 => Other,
+            PluralLocale::Russian if i10 == 1 && i100 != 11 => One,
+            PluralLocale::Russian | PluralLocale::Polish
+                if o.v == 0 && (2..=4).contains(&i10) && !(12..=14).contains(&i100) =>
+            {
+                Few
+            }
+            PluralLocale::Russian => Many,
+            PluralLocale::Polish if o.v != 0 => Other,
+            PluralLocale::Polish if o.i == 1 => One,
+            PluralLocale::Polish => Many,
+            PluralLocale::Czech if o.v != 0 => Many,
+            PluralLocale::Czech if o.i == 1 => One,
+            PluralLocale::Czech if (2..=4).contains(&o.i) => Few,
+            PluralLocale::Arabic if o.n == 0.0 => Zero,
+            PluralLocale::Arabic if o.n == 1.0 => One,
+            PluralLocale::Arabic if o.n == 2.0 => Two,
+            PluralLocale::Arabic if o.n.fract() == 0.0 && (3..=10).contains(&i100) => Few,
+            PluralLocale::Arabic if o.n.fract() == 0.0 && (11..=99).contains(&i100) => Many,
+            _ => Other,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+/// ICU-style plural selection helper for handlebars-rust
+///
+/// # Registration
+///
+/// ```
+/// use handlebars::Handlebars;
+/// use handlebars_inflector::HandlebarsPluralSelect;
+///
+/// let mut h = Handlebars::new();
+/// h.register_helper("plural_select", Box::new(HandlebarsPluralSelect));
+///
+/// assert_eq!(h.render_template(r##"{{plural_select 5 locale="ru" one="# файл" few="# файла" many="# файлов"}}"##, &()).expect("Render error"), "5 файлов");
+/// ```
+///
+/// # Arguments
+///
+/// * `count` - A number, a numeric string, an array or an object whose length is used as count
+///
+/// # Hash options
+///
+/// `zero`, `one`, `two`, `few`, `many`, `other`: the message for each plural category. A `#`
+/// in the selected message is replaced with the count.
+///
+/// `locale`: language whose plural rules apply, `en` by default. Supported are English, German,
+/// Dutch, the Scandinavian languages, Finnish, Estonian, Italian, Spanish, Catalan, French,
+/// Portuguese, Russian, Ukrainian, Belarusian, Polish, Czech, Slovak, Arabic and languages
+/// without plural inflection such as Chinese, Japanese and Korean.
+///
+/// # Selection
+///
+/// As with the explicit `=0` case in ICU MessageFormat, a count of exactly zero selects `zero`
+/// when given, whatever the locale. Otherwise the message for the count's category is used,
+/// falling back to `other`.
+///
+/// # Example usage:
+///
+/// `
+/// {{plural_select files zero="no files" one="one file" other="# files"}}
+/// `
+///
+pub struct HandlebarsPluralSelect;
+
+impl HelperDef for HandlebarsPluralSelect {
+    fn call<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        r: &'reg Handlebars,
+        _ctx: &'rc Context,
+        _rc: &mut RenderContext<'reg, 'rc>,
+        out: &mut dyn Output,
+    ) -> HelperResult {
+        let count = if let Some(count) = h.param(0) {
+            count
+        } else {
+            if r.strict_mode() {
+                return Err(RenderErrorReason::ParamNotFoundForIndex("plural_select", 0).into());
+            }
+
+            return Ok(());
+        };
+
+        let operands = if let Some(operands) = Operands::of(count.value()) {
+            operands
+        } else {
+            if r.strict_mode() {
+                return Err(RenderErrorReason::ParamTypeMismatchForName(
+                    "plural_select",
+                    "0".to_string(),
+                    "number or array".to_string(),
+                )
+                .into());
+            }
+
+            return Ok(());
+        };
+
+        let locale = hash_option_for::<PluralLocale>(
+            "plural_select",
+            h,
+            r.strict_mode(),
+            "locale",
+            "supported locale",
+        )?
+        .unwrap_or_default();
+
+        let selected = select_message(h, &operands, locale.category(&operands), true);
+
+        if let Some(selected) = selected {
+            out.write(&selected)?;
+        } else if r.strict_mode() {
+            return Err(RenderErrorReason::ParamNotFoundForName(
+                "plural_select",
+                "other".to_string(),
+            )
+            .into());
+        }
+
+        Ok(())
+    }
+}
+
+/// Pick the message for `category` from the hash options and substitute the count for `#`.
+///
+/// With `exact_zero` a count of exactly zero prefers the `zero` message. Falls back to `other`.
+pub(crate) fn select_message(
+    h: &Helper,
+    operands: &Operands,
+    category: PluralCategory,
+    exact_zero: bool,
+) -> Option<String> {
+    let message = |key: &str| h.hash_get(key).and_then(|v| v.value().as_str());
+
+    (exact_zero && operands.n == 0.0)
+        .then(|| message("zero"))
+        .flatten()
+        .or_else(|| message(category.as_str()))
+        .or_else(|| message("other"))
+        .map(|message| message.replace('#', &operands.display))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use handlebars::Handlebars;
+
+    #[test]
+    fn it_selects_categories() {
+        use PluralCategory::*;
+
+        for (locale, count, expected) in [
+            ("en", "1", One),
+            ("en", "1.0", Other),
+            ("en", "0", Other),
+            ("fr", "0", One),
+            ("fr", "1.5", One),
+            ("es", "1000000", Many),
+            ("ru", "21", One),
+            ("ru", "11", Many),
+            ("ru", "22", Few),
+            ("ru", "1.5", Other),
+            ("pl", "1", One),
+            ("pl", "22", Few),
+            ("pl", "21", Many),
+            ("cs", "3", Few),
+            ("cs", "1.5", Many),
+            ("ar", "0", Zero),
+            ("ar", "2", Two),
+            ("ar", "103", Few),
+            ("ar", "111", Many),
+            ("ar", "100", Other),
+            ("ja", "1", Other),
+        ] {
+            let locale: PluralLocale = locale.parse().expect("Unsupported locale");
+            let operands: Operands = count.parse().expect("Invalid count");
+
+            assert_eq!(
+                locale.category(&operands),
+                expected,
+                "Failed to select category of {} for {:?}",
+                count,
+                locale
+            );
+        }
+    }
+
+    #[test]
+    fn it_works() {
+        let mut h = Handlebars::new();
+        h.register_helper("plural_select", Box::new(HandlebarsPluralSelect));
+
+        let template = r##"{{plural_select this zero="no files" one="one file" other="# files"}}"##;
+
+        for (count, expected) in [
+            (serde_json::json!(0), "no files"),
+            (serde_json::json!(1), "one file"),
+            (serde_json::json!(2.5), "2.5 files"),
+            (serde_json::json!(["a", "b", "c"]), "3 files"),
+        ] {
+            assert_eq!(
+                h.render_template(template, &count).expect("Render error"),
+                expected,
+                "Failed to test plural_select with {}",
+                count
+            );
+        }
+
+        assert_eq!(
+            h.render_template(
+                r##"{{plural_select 0 locale="fr-CA" one="# fichier" other="# fichiers"}}"##,
+                &()
+            )
+            .expect("Render error"),
+            "0 fichier",
+            "Failed to test plural_select with a locale"
+        );
+    }
+}
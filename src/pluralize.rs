@@ -0,0 +1,175 @@
+use handlebars::{
+    Context, Handlebars, Helper, HelperDef, HelperResult, JsonValue, Output, RenderContext,
+    RenderErrorReason,
+};
+
+use crate::count::{count_of, is_singular};
+use crate::{ensure_plural, ensure_singular};
+
+#[derive(Clone, Copy)]
+/// Counted noun helper for handlebars-rust
+///
+/// Renders a count followed by the noun in the form agreeing with it.
+///
+/// # Registration
+///
+/// ```
+/// use handlebars::Handlebars;
+/// use handlebars_inflector::HandlebarsPluralize;
+///
+/// let mut h = Handlebars::new();
+/// h.register_helper("pluralize", Box::new(HandlebarsPluralize));
+///
+/// assert_eq!(h.render_template(r#"{{pluralize 3 "goose"}}"#, &()).expect("Render error"), "3 geese");
+/// ```
+///
+/// # Arguments
+///
+/// * `count` - A number, a numeric string, an array or an object whose length is used as count
+/// * `noun` - The noun to agree with the count, in either singular or plural form
+///
+/// # Hash options
+///
+/// `plural`: the plural form to use instead of the inflected one
+///
+/// # Example usage:
+///
+/// `1 goose` / `2 geese`:
+///
+/// `
+/// {{pluralize count "goose"}}
+/// `
+///
+/// `3 criteria`:
+///
+/// `
+/// {{pluralize filters "criterion" plural="criteria"}}
+/// `
+///
+pub struct HandlebarsPluralize;
+
+impl HelperDef for HandlebarsPluralize {
+    fn call<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        r: &'reg Handlebars,
+        _ctx: &'rc Context,
+        _rc: &mut RenderContext<'reg, 'rc>,
+        out: &mut dyn Output,
+    ) -> HelperResult {
+        let value = if let Some(value) = h.param(0) {
+            value.value()
+        } else {
+            if r.strict_mode() {
+                return Err(RenderErrorReason::ParamNotFoundForIndex("pluralize", 0).into());
+            }
+
+            return Ok(());
+        };
+
+        let count = if let Some(count) = count_of(value) {
+            count
+        } else {
+            if r.strict_mode() {
+                return Err(RenderErrorReason::ParamTypeMismatchForName(
+                    "pluralize",
+                    "0".to_string(),
+                    "number or array".to_string(),
+                )
+                .into());
+            }
+
+            return Ok(());
+        };
+
+        let noun = if let Some(noun) = h.param(1).and_then(|v| v.value().as_str()) {
+            noun
+        } else {
+            if r.strict_mode() {
+                return Err(RenderErrorReason::ParamNotFoundForIndex("pluralize", 1).into());
+            }
+
+            return Ok(());
+        };
+
+        let noun = if is_singular(count) {
+            ensure_singular(noun.to_string())
+        } else if let Some(plural) = h.hash_get("plural").and_then(|v| v.value().as_str()) {
+            plural.to_string()
+        } else {
+            ensure_plural(noun.to_string())
+        };
+
+        out.write(&format!("{} {}", display_count(value, count), noun))?;
+
+        Ok(())
+    }
+}
+
+/// The count as written in the template, or the length of an array or object.
+fn display_count(value: &JsonValue, count: f64) -> String {
+    match value {
+        JsonValue::Number(n) => n.to_string(),
+        JsonValue::String(s) => s.trim().to_string(),
+        _ => count.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use handlebars::Handlebars;
+
+    #[test]
+    fn it_works() {
+        let mut h = Handlebars::new();
+        h.register_helper("pluralize", Box::new(HandlebarsPluralize));
+
+        for (template, expected) in [
+            (r#"{{pluralize 3 "goose"}}"#, "3 geese"),
+            (r#"{{pluralize 1 "goose"}}"#, "1 goose"),
+            (r#"{{pluralize 1 "boxes"}}"#, "1 box"),
+            (r#"{{pluralize 0 "status"}}"#, "0 statuses"),
+            (r#"{{pluralize "2" "box"}}"#, "2 boxes"),
+            (r#"{{pluralize 1.5 "hour"}}"#, "1.5 hours"),
+            (r#"{{pluralize this "item"}}"#, "2 items"),
+            (
+                r#"{{pluralize 3 "criterion" plural="criteria"}}"#,
+                "3 criteria",
+            ),
+        ] {
+            assert_eq!(
+                h.render_template(template, &vec!["a", "b"])
+                    .expect("Render error"),
+                expected,
+                "Failed to test {}",
+                template
+            );
+        }
+    }
+
+    #[test]
+    fn it_errors_in_strict_mode() {
+        let mut h = Handlebars::new();
+        h.register_helper("pluralize", Box::new(HandlebarsPluralize));
+
+        assert_eq!(
+            h.render_template(r#"{{pluralize true "item"}}"#, &())
+                .expect("Render error"),
+            "",
+            "Failed to test lenient type mismatch"
+        );
+
+        h.set_strict_mode(true);
+
+        assert!(
+            h.render_template(r#"{{pluralize true "item"}}"#, &())
+                .is_err(),
+            "Failed to test strict type mismatch"
+        );
+        assert!(
+            h.render_template(r#"{{pluralize 2}}"#, &()).is_err(),
+            "Failed to test missing noun"
+        );
+    }
+}
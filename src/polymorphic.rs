@@ -0,0 +1,103 @@
+use handlebars::{
+    Context, Handlebars, Helper, HelperDef, JsonValue, RenderContext, RenderError,
+    RenderErrorReason, ScopedJson,
+};
+use inflector::Inflector;
+use serde_json::{json, Map};
+
+#[derive(Clone, Copy)]
+/// Polymorphic association column helper for handlebars-rust
+///
+/// Returns the `type` and `id` column names of a polymorphic association as an object. The `id`
+/// column follows the same convention as the `to_foreign_key` operation of
+/// [`HandlebarsInflector`](struct@crate::HandlebarsInflector).
+///
+/// # Registration
+///
+/// ```
+/// use handlebars::Handlebars;
+/// use handlebars_inflector::HandlebarsPolymorphic;
+///
+/// let mut h = Handlebars::new();
+/// h.register_helper("polymorphic", Box::new(HandlebarsPolymorphic));
+///
+/// assert_eq!(h.render_template(r#"{{#with (polymorphic "Commentable")}}{{type}}, {{id}}{{/with}}"#, &()).expect("Render error"), "commentable_type, commentable_id");
+/// ```
+///
+/// # Arguments
+///
+/// * `association` - The association name, in any case convention. Module paths are dropped.
+///
+/// # Example usage:
+///
+/// `
+/// t.string :{{lookup (polymorphic name) "type"}}
+/// `
+///
+pub struct HandlebarsPolymorphic;
+
+impl HelperDef for HandlebarsPolymorphic {
+    fn call_inner<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        r: &'reg Handlebars,
+        _ctx: &'rc Context,
+        _rc: &mut RenderContext<'reg, 'rc>,
+    ) -> Result<ScopedJson<'rc>, RenderError> {
+        let Some(association) = h.param(0).and_then(|v| v.value().as_str()) else {
+            if r.strict_mode() {
+                return Err(RenderErrorReason::ParamNotFoundForIndex("polymorphic", 0).into());
+            }
+
+            return Ok(ScopedJson::Derived(JsonValue::Null));
+        };
+
+        let mut columns = Map::new();
+        columns.insert(
+            "type".to_string(),
+            json!(format!("{}_type", association.demodulize().to_snake_case())),
+        );
+        columns.insert("id".to_string(), json!(association.to_foreign_key()));
+
+        Ok(ScopedJson::Derived(JsonValue::Object(columns)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use handlebars::Handlebars;
+
+    #[test]
+    fn it_works() {
+        let mut h = Handlebars::new();
+        h.register_helper("polymorphic", Box::new(HandlebarsPolymorphic));
+
+        for (association, expected) in [
+            ("commentable", "commentable_type commentable_id"),
+            (
+                "ImageAttachable",
+                "image_attachable_type image_attachable_id",
+            ),
+            ("Blog::Taggable", "taggable_type taggable_id"),
+        ] {
+            assert_eq!(
+                h.render_template(
+                    r#"{{#with (polymorphic this)}}{{type}} {{id}}{{/with}}"#,
+                    &association
+                )
+                .expect("Render error"),
+                expected,
+                "Failed to test polymorphic with {}",
+                association
+            );
+        }
+
+        assert_eq!(
+            h.render_template(r#"{{lookup (polymorphic "owner") "type"}}"#, &())
+                .expect("Render error"),
+            "owner_type",
+            "Failed to test polymorphic with lookup"
+        );
+    }
+}
@@ -0,0 +1,100 @@
+use std::collections::BTreeMap;
+
+use pyo3::exceptions::{PyOSError, PyValueError};
+use pyo3::prelude::*;
+
+use crate::{Pipeline, Rules, RulesError};
+
+/// [`Rules`] exported to Python as `Rules`
+#[pyclass(name = "Rules", frozen)]
+#[derive(Clone)]
+struct PyRules(Rules);
+
+#[pymethods]
+impl PyRules {
+    /// Parse rules from their JSON representation
+    #[staticmethod]
+    fn from_json(json: &str) -> PyResult<Self> {
+        serde_json::from_str(json)
+            .map(PyRules)
+            .map_err(|e| PyValueError::new_err(format!("Invalid JSON rules: {}", e)))
+    }
+
+    /// Load rules from a `.toml`, `.yaml` or `.yml` file
+    #[cfg(any(feature = "toml", feature = "yaml"))]
+    #[staticmethod]
+    fn load(path: std::path::PathBuf) -> PyResult<Self> {
+        Rules::load(path).map(PyRules).map_err(to_py_err)
+    }
+
+    /// Serialize the rules as JSON
+    fn to_json(&self) -> PyResult<String> {
+        serde_json::to_string(&self.0).map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+}
+
+fn to_py_err(e: RulesError) -> PyErr {
+    match e {
+        RulesError::Io(_) => PyOSError::new_err(e.to_string()),
+        _ => PyValueError::new_err(e.to_string()),
+    }
+}
+
+/// Apply the operations to `input` in order, see [`Pipeline`]
+#[pyfunction]
+#[pyo3(signature = (input, ops, profile=None, rules=None, options=None))]
+fn apply_ops(
+    input: &str,
+    ops: Vec<String>,
+    profile: Option<String>,
+    rules: Option<PyRules>,
+    options: Option<BTreeMap<String, String>>,
+) -> PyResult<String> {
+    let mut pipeline = Pipeline::new();
+
+    if let Some(PyRules(rules)) = rules {
+        pipeline = pipeline.with_rule_set(rules);
+    }
+
+    let options = profile
+        .map(|profile| ("profile".to_string(), profile))
+        .into_iter()
+        .chain(options.unwrap_or_default());
+
+    for (key, value) in options {
+        pipeline = pipeline
+            .with_option(key, value)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+    }
+
+    for op in &ops {
+        pipeline = pipeline
+            .with_op(op)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+    }
+
+    pipeline
+        .apply(input)
+        .map_err(|e| PyValueError::new_err(e.to_string()))
+}
+
+/// Names of all operations, value operations written as `name=VALUE`
+#[pyfunction]
+fn operations() -> Vec<String> {
+    Pipeline::operations()
+}
+
+/// Names of the identifier profiles accepted by `profile`
+#[pyfunction]
+fn profiles() -> Vec<String> {
+    Pipeline::profiles()
+}
+
+#[pymodule]
+fn handlebars_inflector(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyRules>()?;
+    m.add_function(wrap_pyfunction!(apply_ops, m)?)?;
+    m.add_function(wrap_pyfunction!(operations, m)?)?;
+    m.add_function(wrap_pyfunction!(profiles, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,331 @@
+use std::collections::BTreeSet;
+use std::fmt;
+
+use handlebars::{Handlebars, HelperDef};
+
+use crate::{
+    HandlebarsBreadcrumbs, HandlebarsConstraintName, HandlebarsDateSlug, HandlebarsEnumVariants,
+    HandlebarsHeadingAnchor, HandlebarsIndexName, HandlebarsInflectEach, HandlebarsInflectKeys,
+    HandlebarsInflector, HandlebarsIsIdentifier, HandlebarsJoinTable, HandlebarsPluralForms,
+    HandlebarsPluralSelect, HandlebarsPluralize, HandlebarsPolymorphic, HandlebarsVerbAgreement,
+};
+
+type BoxedHelper = Box<dyn HelperDef + Send + Sync>;
+
+/// Helpers every `Handlebars` registry starts with
+const BUILT_IN_HELPERS: &[&str] = &[
+    "if", "unless", "each", "with", "lookup", "raw", "log", "eq", "ne", "gt", "gte", "lt", "lte",
+    "and", "or", "not", "len",
+];
+
+/// What [`Registration::register`] does when a helper name is already taken
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OnConflict {
+    /// Silently replace the existing helper, like `Handlebars::register_helper`
+    #[default]
+    Replace,
+    /// Log a warning and replace the existing helper
+    Warn,
+    /// Register nothing and return an error
+    Error,
+}
+
+/// Error produced by [`Registration::register`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RegistrationError {
+    /// A helper with this name is already registered
+    Conflict(String),
+}
+
+impl fmt::Display for RegistrationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RegistrationError::Conflict(name) => {
+                write!(f, "A helper named `{}` is already registered", name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for RegistrationError {}
+
+/// Registers all helpers of this crate at once, optionally under a namespace
+///
+/// Each helper is registered under its conventional name (`inflect`, `agree`, `plural_select`,
+/// ...). With a namespace the names get a prefix, so they can't clash with an application's own
+/// helpers.
+///
+/// The namespace is separated with `:` by default, so the helpers are called as
+/// `{{inflector:inflect ...}}` rather than `{{inflector.inflect ...}}`. Handlebars parses a `.` in
+/// a helper name as a path and rejects the template, so a dotted namespace isn't usable. Any other
+/// separator can be set with [`with_separator`](Self::with_separator).
+///
+/// A `Handlebars` registry doesn't list its helpers, so conflicts are detected against the
+/// built-in helpers and the names passed to [`with_taken_names`](Self::with_taken_names). Names
+/// registered by another `Registration` can be passed on with [`names`](Self::names).
+///
+/// [`HandlebarsGenderedArticle`](crate::HandlebarsGenderedArticle) needs dictionaries and is not
+/// included.
+///
+/// ```
+/// use handlebars::Handlebars;
+/// use handlebars_inflector::Registration;
+///
+/// let mut h = Handlebars::new();
+/// Registration::new().with_namespace("inflector").register(&mut h).expect("Registration error");
+///
+/// assert_eq!(h.render_template(r#"{{inflector:inflect this to_snake_case=true}}"#, &String::from("ProductImages")).expect("Render error"), "product_images");
+/// ```
+#[derive(Clone, Default)]
+pub struct Registration {
+    namespace: Option<String>,
+    separator: Option<String>,
+    inflector: HandlebarsInflector,
+    on_conflict: OnConflict,
+    taken: BTreeSet<String>,
+}
+
+impl Registration {
+    /// Register every helper under its plain name
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Prefix all helper names with `namespace` and the separator
+    pub fn with_namespace<S: Into<String>>(mut self, namespace: S) -> Self {
+        self.namespace = Some(namespace.into());
+        self
+    }
+
+    /// Separator between the namespace and the helper name, `:` by default
+    pub fn with_separator<S: Into<String>>(mut self, separator: S) -> Self {
+        self.separator = Some(separator.into());
+        self
+    }
+
+    /// Use a configured `inflect` helper instead of the default one
+    pub fn with_inflector(mut self, inflector: HandlebarsInflector) -> Self {
+        self.inflector = inflector;
+        self
+    }
+
+    /// Choose what happens when a helper name is already taken
+    pub fn with_on_conflict(mut self, on_conflict: OnConflict) -> Self {
+        self.on_conflict = on_conflict;
+        self
+    }
+
+    /// Helper names already registered by the application, checked on top of the built-in helpers
+    pub fn with_taken_names<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.taken.extend(names.into_iter().map(Into::into));
+        self
+    }
+
+    /// Full name a helper is registered under
+    pub fn name(&self, helper: &str) -> String {
+        match &self.namespace {
+            Some(namespace) => format!(
+                "{}{}{}",
+                namespace,
+                self.separator.as_deref().unwrap_or(":"),
+                helper
+            ),
+            None => helper.to_string(),
+        }
+    }
+
+    /// Full names of all helpers [`register`](Self::register) installs
+    pub fn names(&self) -> Vec<String> {
+        self.helpers()
+            .into_iter()
+            .map(|(name, _)| self.name(name))
+            .collect()
+    }
+
+    /// Register all helpers with `registry`
+    ///
+    /// Built-in helpers like `if` and the names passed to
+    /// [`with_taken_names`](Self::with_taken_names) count as taken. With [`OnConflict::Error`]
+    /// nothing is registered when any name is taken.
+    pub fn register(&self, registry: &mut Handlebars) -> Result<(), RegistrationError> {
+        let helpers = self.helpers();
+
+        if self.on_conflict != OnConflict::Replace {
+            for (name, _) in &helpers {
+                let name = self.name(name);
+
+                if !self.is_taken(&name) {
+                    continue;
+                }
+
+                if self.on_conflict == OnConflict::Error {
+                    return Err(RegistrationError::Conflict(name));
+                }
+
+                log::warn!("Replacing the helper already registered as `{}`", name);
+            }
+        }
+
+        for (name, helper) in helpers {
+            registry.register_helper(&self.name(name), helper);
+        }
+
+        Ok(())
+    }
+
+    fn helpers(&self) -> Vec<(&'static str, BoxedHelper)> {
+        #[cfg_attr(not(feature = "fluent"), allow(unused_mut))]
+        let mut helpers: Vec<(&'static str, BoxedHelper)> = vec![
+            (
+                HandlebarsInflector::DEFAULT_HELPER_NAME,
+                Box::new(self.inflector.clone()),
+            ),
+            (
+                "inflect_each",
+                Box::new(HandlebarsInflectEach::new().with_inflector(self.inflector.clone())),
+            ),
+            (
+                "inflect_keys",
+                Box::new(HandlebarsInflectKeys::new().with_inflector(self.inflector.clone())),
+            ),
+            ("agree", Box::new(HandlebarsVerbAgreement)),
+            ("breadcrumbs", Box::new(HandlebarsBreadcrumbs)),
+            ("constraint_name", Box::new(HandlebarsConstraintName)),
+            ("date_slug", Box::new(HandlebarsDateSlug)),
+            ("enum_variants", Box::new(HandlebarsEnumVariants)),
+            ("heading_anchor", Box::new(HandlebarsHeadingAnchor)),
+            ("index_name", Box::new(HandlebarsIndexName)),
+            ("is_identifier", Box::new(HandlebarsIsIdentifier)),
+            ("join_table", Box::new(HandlebarsJoinTable)),
+            ("plural_forms", Box::new(HandlebarsPluralForms)),
+            ("plural_select", Box::new(HandlebarsPluralSelect)),
+            ("pluralize", Box::new(HandlebarsPluralize)),
+            ("polymorphic", Box::new(HandlebarsPolymorphic)),
+        ];
+
+        #[cfg(feature = "fluent")]
+        helpers.push(("cldr_plural", Box::new(crate::HandlebarsCldrPlural)));
+
+        helpers
+    }
+
+    /// Whether `name` is a built-in helper or was passed as a taken name.
+    fn is_taken(&self, name: &str) -> bool {
+        BUILT_IN_HELPERS.contains(&name) || self.taken.contains(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use handlebars::Handlebars;
+
+    #[test]
+    fn it_registers_helpers() {
+        let mut h = Handlebars::new();
+        Registration::new()
+            .register(&mut h)
+            .expect("Registration error");
+
+        assert_eq!(
+            h.render_template(r#"{{inflect "box" to_plural=true}} {{agree 2 "is"}}"#, &())
+                .expect("Render error"),
+            "boxes are",
+            "Failed to test plain registration"
+        );
+
+        let mut h = Handlebars::new();
+        Registration::new()
+            .with_namespace("inflector")
+            .with_inflector(HandlebarsInflector::new().with_reserved(["type"]))
+            .register(&mut h)
+            .expect("Registration error");
+
+        assert_eq!(
+            h.render_template(
+                r#"{{inflector:inflect "Type" to_snake_case=true}} {{inflector:join_table "User" "Product"}}"#,
+                &()
+            )
+            .expect("Render error"),
+            "type_ products_users",
+            "Failed to test namespaced registration"
+        );
+        assert!(
+            h.render_template(r#"{{inflect "box" to_plural=true}}"#, &())
+                .is_err(),
+            "Failed to test namespaced registration leaves plain names free"
+        );
+
+        assert_eq!(
+            Registration::new()
+                .with_namespace("inflector")
+                .with_separator("_")
+                .name("inflect"),
+            "inflector_inflect"
+        );
+    }
+
+    #[test]
+    fn it_detects_conflicts() {
+        let mut h = Handlebars::new();
+        h.register_helper("join_table", Box::new(HandlebarsPolymorphic));
+
+        assert_eq!(
+            Registration::new()
+                .with_on_conflict(OnConflict::Error)
+                .with_taken_names(["join_table"])
+                .register(&mut h),
+            Err(RegistrationError::Conflict("join_table".to_string())),
+            "Failed to test OnConflict::Error"
+        );
+        assert!(
+            h.render_template(r#"{{inflect "a"}}"#, &()).is_err(),
+            "Failed to test OnConflict::Error registers nothing"
+        );
+
+        assert_eq!(
+            Registration::new()
+                .with_namespace("inflector")
+                .with_on_conflict(OnConflict::Error)
+                .with_taken_names(["join_table"])
+                .register(&mut h),
+            Ok(()),
+            "Failed to test OnConflict::Error without conflicts"
+        );
+
+        Registration::new()
+            .with_on_conflict(OnConflict::Warn)
+            .with_taken_names(["join_table"])
+            .register(&mut h)
+            .expect("Registration error");
+
+        assert_eq!(
+            h.render_template(r#"{{join_table "User" "Product"}}"#, &())
+                .expect("Render error"),
+            "products_users",
+            "Failed to test OnConflict::Warn replaces the helper"
+        );
+
+        let namespaced = Registration::new().with_namespace("inflector");
+        let registration = Registration::new().with_taken_names(namespaced.names());
+
+        assert!(registration.is_taken("if"));
+        assert!(!registration.is_taken("inflect"));
+        assert!(registration.is_taken("inflector:index_name"));
+
+        let mut h = Handlebars::new();
+        h.register_helper("helperMissing", Box::new(HandlebarsPolymorphic));
+
+        assert_eq!(
+            Registration::new()
+                .with_on_conflict(OnConflict::Error)
+                .register(&mut h),
+            Ok(()),
+            "Failed to test OnConflict::Error with helperMissing"
+        );
+    }
+}
@@ -0,0 +1,393 @@
+use std::collections::BTreeSet;
+
+/// Words reserved by Java, including the literals `true`, `false` and `null`
+const JAVA: &[&str] = &[
+    "_",
+    "abstract",
+    "assert",
+    "boolean",
+    "break",
+    "byte",
+    "case",
+    "catch",
+    "char",
+    "class",
+    "const",
+    "continue",
+    "default",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "extends",
+    "false",
+    "final",
+    "finally",
+    "float",
+    "for",
+    "goto",
+    "if",
+    "implements",
+    "import",
+    "instanceof",
+    "int",
+    "interface",
+    "long",
+    "native",
+    "new",
+    "null",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "return",
+    "short",
+    "static",
+    "strictfp",
+    "super",
+    "switch",
+    "synchronized",
+    "this",
+    "throw",
+    "throws",
+    "transient",
+    "true",
+    "try",
+    "void",
+    "volatile",
+    "while",
+];
+
+/// Python keywords
+const PYTHON: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield",
+];
+
+/// Go keywords
+const GO: &[&str] = &[
+    "break",
+    "case",
+    "chan",
+    "const",
+    "continue",
+    "default",
+    "defer",
+    "else",
+    "fallthrough",
+    "for",
+    "func",
+    "go",
+    "goto",
+    "if",
+    "import",
+    "interface",
+    "map",
+    "package",
+    "range",
+    "return",
+    "select",
+    "struct",
+    "switch",
+    "type",
+    "var",
+];
+
+/// Words reserved by PostgreSQL, plus the join and statement keywords every major dialect
+/// reserves
+const SQL: &[&str] = &[
+    "all",
+    "alter",
+    "analyse",
+    "analyze",
+    "and",
+    "any",
+    "array",
+    "as",
+    "asc",
+    "asymmetric",
+    "between",
+    "both",
+    "by",
+    "case",
+    "cast",
+    "check",
+    "collate",
+    "column",
+    "constraint",
+    "create",
+    "cross",
+    "current_catalog",
+    "current_date",
+    "current_role",
+    "current_time",
+    "current_timestamp",
+    "current_user",
+    "default",
+    "deferrable",
+    "delete",
+    "desc",
+    "distinct",
+    "do",
+    "drop",
+    "else",
+    "end",
+    "except",
+    "exists",
+    "false",
+    "fetch",
+    "for",
+    "foreign",
+    "from",
+    "full",
+    "grant",
+    "group",
+    "having",
+    "in",
+    "index",
+    "initially",
+    "inner",
+    "insert",
+    "intersect",
+    "into",
+    "is",
+    "join",
+    "key",
+    "lateral",
+    "leading",
+    "left",
+    "like",
+    "limit",
+    "localtime",
+    "localtimestamp",
+    "natural",
+    "not",
+    "null",
+    "offset",
+    "on",
+    "only",
+    "or",
+    "order",
+    "outer",
+    "placing",
+    "primary",
+    "references",
+    "returning",
+    "right",
+    "select",
+    "session_user",
+    "set",
+    "some",
+    "symmetric",
+    "table",
+    "then",
+    "to",
+    "trailing",
+    "true",
+    "union",
+    "unique",
+    "update",
+    "user",
+    "using",
+    "values",
+    "variadic",
+    "when",
+    "where",
+    "window",
+    "with",
+];
+
+/// Whether `word` is in the portable SQL reserved words, ignoring case.
+pub(crate) fn is_sql_reserved(word: &str) -> bool {
+    SQL.binary_search(&word.to_lowercase().as_str()).is_ok()
+}
+
+/// What the [`inflect`](struct@crate::HandlebarsInflector) helper does with a result found in a
+/// [`ReservedWords`] list
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReservedStrategy {
+    /// Prepend a string: `class` to `_class`
+    Prefix(String),
+    /// Append a string: `class` to `class_`
+    Suffix(String),
+    /// Wrap the result in an opening and a closing quote: `user` to `"user"`
+    Quote(String, String),
+    /// Fail the render in strict mode, leave the result as it is otherwise
+    Error,
+}
+
+impl Default for ReservedStrategy {
+    fn default() -> Self {
+        ReservedStrategy::Suffix("_".to_string())
+    }
+}
+
+impl ReservedStrategy {
+    /// Escape the reserved word `word`. `Error` leaves it untouched, the caller decides how to
+    /// fail.
+    pub(crate) fn apply(&self, word: String) -> String {
+        match self {
+            ReservedStrategy::Prefix(prefix) => format!("{}{}", prefix, word),
+            ReservedStrategy::Suffix(suffix) => format!("{}{}", word, suffix),
+            ReservedStrategy::Quote(open, close) => format!("{}{}{}", open, word, close),
+            ReservedStrategy::Error => word,
+        }
+    }
+}
+
+/// Named list of reserved words for the `reserved` hash option of the
+/// [`inflect`](struct@crate::HandlebarsInflector) helper
+///
+/// Register lists with
+/// [`HandlebarsInflector::with_reserved_words`](crate::HandlebarsInflector::with_reserved_words),
+/// one per target language, and select one per call. A result found in the selected list is
+/// escaped with the list's [`ReservedStrategy`]. Lists for SQL, Java, Python and Go are
+/// available as presets:
+///
+/// ```
+/// use handlebars::Handlebars;
+/// use handlebars_inflector::{HandlebarsInflector, ReservedStrategy, ReservedWords};
+///
+/// let inflector = HandlebarsInflector::new()
+///     .with_reserved_words("sql", ReservedWords::sql())
+///     .with_reserved_words("php", ReservedWords::new(["class", "function"]).with_strategy(ReservedStrategy::Prefix("_".to_string())));
+///
+/// let mut h = Handlebars::new();
+/// h.register_helper("inflect", Box::new(inflector));
+///
+/// assert_eq!(h.render_template(r#"{{{inflect "User" to_snake_case=true reserved="sql"}}} {{inflect "Class" to_snake_case=true reserved="php"}}"#, &()).expect("Render error"), r#""user" _class"#);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReservedWords {
+    words: BTreeSet<String>,
+    strategy: ReservedStrategy,
+    ignore_case: bool,
+}
+
+impl ReservedWords {
+    /// Create a case sensitive list escaping its words with a `_` suffix
+    pub fn new<I, S>(words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        ReservedWords {
+            words: words.into_iter().map(Into::into).collect(),
+            strategy: ReservedStrategy::default(),
+            ignore_case: false,
+        }
+    }
+
+    /// Portable SQL reserved words, matched ignoring case and quoted with `"`
+    pub fn sql() -> Self {
+        ReservedWords::new(SQL.iter().copied())
+            .with_strategy(ReservedStrategy::Quote("\"".to_string(), "\"".to_string()))
+            .with_ignore_case(true)
+    }
+
+    /// Java keywords and literals, escaped with a `_` suffix
+    pub fn java() -> Self {
+        ReservedWords::new(JAVA.iter().copied())
+    }
+
+    /// Python keywords, escaped with a `_` suffix as recommended by PEP 8
+    pub fn python() -> Self {
+        ReservedWords::new(PYTHON.iter().copied())
+    }
+
+    /// Go keywords, escaped with a `_` suffix
+    pub fn go() -> Self {
+        ReservedWords::new(GO.iter().copied())
+    }
+
+    /// Add words to the list
+    pub fn with_words<I, S>(mut self, words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.words.extend(words.into_iter().map(Into::into));
+        self
+    }
+
+    /// Escaping of results found in the list, a `_` suffix by default
+    pub fn with_strategy(mut self, strategy: ReservedStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Match results ignoring case, e.g. for SQL keywords
+    pub fn with_ignore_case(mut self, ignore_case: bool) -> Self {
+        self.ignore_case = ignore_case;
+        self
+    }
+
+    /// Escaping of results found in the list
+    pub(crate) fn strategy(&self) -> &ReservedStrategy {
+        &self.strategy
+    }
+
+    /// Whether `word` is in the list.
+    pub(crate) fn contains(&self, word: &str) -> bool {
+        if self.ignore_case {
+            self.words
+                .iter()
+                .any(|reserved| reserved.to_lowercase() == word.to_lowercase())
+        } else {
+            self.words.contains(word)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_matches_presets() {
+        for (words, input, expected) in [
+            (ReservedWords::sql(), "user", true),
+            (ReservedWords::sql(), "ORDER", true),
+            (ReservedWords::sql(), "users", false),
+            (ReservedWords::java(), "class", true),
+            (ReservedWords::java(), "Class", false),
+            (ReservedWords::python(), "None", true),
+            (ReservedWords::python(), "none", false),
+            (ReservedWords::go(), "type", true),
+            (ReservedWords::go(), "string", false),
+        ] {
+            assert_eq!(words.contains(input), expected, "Failed to test {}", input);
+        }
+    }
+
+    #[test]
+    fn it_sorts_sql_words() {
+        assert!(
+            SQL.windows(2).all(|pair| pair[0] < pair[1]),
+            "Failed to test SQL word order"
+        );
+        assert!(is_sql_reserved("Select"), "Failed to test SQL lookup");
+    }
+
+    #[test]
+    fn it_applies_strategies() {
+        for (strategy, expected) in [
+            (ReservedStrategy::Prefix("_".to_string()), "_class"),
+            (ReservedStrategy::Suffix("_".to_string()), "class_"),
+            (
+                ReservedStrategy::Quote("[".to_string(), "]".to_string()),
+                "[class]",
+            ),
+            (ReservedStrategy::Error, "class"),
+        ] {
+            assert_eq!(
+                strategy.apply("class".to_string()),
+                expected,
+                "Failed to test {:?}",
+                strategy
+            );
+        }
+    }
+}
@@ -0,0 +1,235 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+#[cfg(any(feature = "toml", feature = "yaml"))]
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+/// Error returned when custom rules cannot be loaded or saved
+#[derive(Debug)]
+pub enum RulesError {
+    /// The rules file could not be read or written
+    Io(std::io::Error),
+    /// The file extension does not name a supported format
+    UnsupportedFormat(String),
+    /// The source is not valid in the given format
+    Parse {
+        format: &'static str,
+        message: String,
+    },
+    /// The rules cannot be represented in the given format
+    Serialize {
+        format: &'static str,
+        message: String,
+    },
+}
+
+impl fmt::Display for RulesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RulesError::Io(e) => write!(f, "Failed to access rules file: {}", e),
+            RulesError::UnsupportedFormat(extension) => {
+                write!(f, "Unsupported rules file format: `{}`", extension)
+            }
+            RulesError::Parse { format, message } => {
+                write!(f, "Invalid {} rules: {}", format, message)
+            }
+            RulesError::Serialize { format, message } => {
+                write!(f, "Failed to serialize rules as {}: {}", format, message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for RulesError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RulesError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for RulesError {
+    fn from(e: std::io::Error) -> Self {
+        RulesError::Io(e)
+    }
+}
+
+/// Custom rules of an [`inflect`](struct@crate::HandlebarsInflector) helper
+///
+/// Rules are captured from a configured helper with
+/// [`HandlebarsInflector::rules`](crate::HandlebarsInflector::rules) and applied to another one
+/// with [`HandlebarsInflector::with_rule_set`](crate::HandlebarsInflector::with_rule_set), so
+/// rules added at runtime can be written to a file, committed and shared between services and
+/// build-time generators. TOML and YAML are available behind the `toml` and `yaml` features;
+/// since `Rules` implements serde's traits any other format works as well.
+///
+/// ```
+/// use handlebars_inflector::{HandlebarsInflector, Rules};
+///
+/// let inflector = HandlebarsInflector::new()
+///     .with_reserved(["type"])
+///     .with_override("oauth2_token", "OAuth2Token");
+///
+/// let json = serde_json::to_string(&inflector.rules()).expect("Serialize error");
+/// let rules: Rules = serde_json::from_str(&json).expect("Parse error");
+///
+/// assert_eq!(HandlebarsInflector::new().with_rule_set(rules).rules(), inflector.rules());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Rules {
+    /// Names that results must never equal
+    pub reserved: BTreeSet<String>,
+    /// Suffix appended to results equal to a reserved name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reserved_suffix: Option<String>,
+    /// Inputs that are always written as the given target
+    pub overrides: BTreeMap<String, String>,
+    /// Irregular nouns as singular to plural forms
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub irregulars: BTreeMap<String, String>,
+    /// Nouns without a plural form
+    #[serde(skip_serializing_if = "BTreeSet::is_empty")]
+    pub uncountable: BTreeSet<String>,
+    /// Acronyms kept intact by case conversions
+    #[serde(skip_serializing_if = "BTreeSet::is_empty")]
+    pub acronyms: BTreeSet<String>,
+    /// Words and the abbreviations the `abbreviate` operation replaces them with
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub abbreviations: BTreeMap<String, String>,
+}
+
+impl Rules {
+    /// Parse rules from TOML
+    #[cfg(feature = "toml")]
+    pub fn from_toml(source: &str) -> Result<Self, RulesError> {
+        toml::from_str(source).map_err(|e| RulesError::Parse {
+            format: "TOML",
+            message: e.to_string(),
+        })
+    }
+
+    /// Serialize the rules as TOML
+    #[cfg(feature = "toml")]
+    pub fn to_toml(&self) -> Result<String, RulesError> {
+        toml::to_string(self).map_err(|e| RulesError::Serialize {
+            format: "TOML",
+            message: e.to_string(),
+        })
+    }
+
+    /// Parse rules from YAML
+    #[cfg(feature = "yaml")]
+    pub fn from_yaml(source: &str) -> Result<Self, RulesError> {
+        serde_yaml::from_str(source).map_err(|e| RulesError::Parse {
+            format: "YAML",
+            message: e.to_string(),
+        })
+    }
+
+    /// Serialize the rules as YAML
+    #[cfg(feature = "yaml")]
+    pub fn to_yaml(&self) -> Result<String, RulesError> {
+        serde_yaml::to_string(self).map_err(|e| RulesError::Serialize {
+            format: "YAML",
+            message: e.to_string(),
+        })
+    }
+
+    /// Load rules from a `.toml`, `.yaml` or `.yml` file
+    #[cfg(any(feature = "toml", feature = "yaml"))]
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, RulesError> {
+        let path = path.as_ref();
+
+        let parse: fn(&str) -> Result<Self, RulesError> = match extension_of(path).as_str() {
+            #[cfg(feature = "toml")]
+            "toml" => Self::from_toml,
+            #[cfg(feature = "yaml")]
+            "yaml" | "yml" => Self::from_yaml,
+            extension => return Err(RulesError::UnsupportedFormat(extension.to_string())),
+        };
+
+        parse(&std::fs::read_to_string(path)?)
+    }
+
+    /// Write the rules to a `.toml`, `.yaml` or `.yml` file, replacing its content
+    #[cfg(any(feature = "toml", feature = "yaml"))]
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), RulesError> {
+        let path = path.as_ref();
+
+        let serialized = match extension_of(path).as_str() {
+            #[cfg(feature = "toml")]
+            "toml" => self.to_toml()?,
+            #[cfg(feature = "yaml")]
+            "yaml" | "yml" => self.to_yaml()?,
+            extension => return Err(RulesError::UnsupportedFormat(extension.to_string())),
+        };
+
+        Ok(std::fs::write(path, serialized)?)
+    }
+}
+
+#[cfg(any(feature = "toml", feature = "yaml"))]
+fn extension_of(path: &Path) -> String {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or_default()
+        .to_lowercase()
+}
+
+#[cfg(all(test, any(feature = "toml", feature = "yaml")))]
+mod tests {
+    use super::*;
+
+    fn rules() -> Rules {
+        Rules {
+            reserved: BTreeSet::from(["self".to_string(), "type".to_string()]),
+            reserved_suffix: Some("_field".to_string()),
+            overrides: BTreeMap::from([("oauth2_token".to_string(), "OAuth2Token".to_string())]),
+            irregulars: BTreeMap::new(),
+            uncountable: BTreeSet::new(),
+            acronyms: BTreeSet::new(),
+            abbreviations: BTreeMap::new(),
+        }
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn it_round_trips_toml() {
+        let toml = rules().to_toml().expect("Serialize error");
+
+        assert_eq!(
+            toml,
+            "reserved = [\"self\", \"type\"]\nreserved_suffix = \"_field\"\n\n[overrides]\noauth2_token = \"OAuth2Token\"\n",
+            "Failed to test TOML export"
+        );
+        assert_eq!(Rules::from_toml(&toml).expect("Parse error"), rules());
+        assert_eq!(
+            Rules::from_toml("reserved = [\"type\"]").expect("Parse error"),
+            Rules {
+                reserved: BTreeSet::from(["type".to_string()]),
+                ..Rules::default()
+            },
+            "Failed to test partial TOML"
+        );
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn it_round_trips_yaml() {
+        let yaml = rules().to_yaml().expect("Serialize error");
+
+        assert_eq!(Rules::from_yaml(&yaml).expect("Parse error"), rules());
+        assert!(Rules::from_yaml("reserved: 42").is_err());
+    }
+
+    #[test]
+    fn it_rejects_unknown_formats() {
+        assert!(matches!(
+            rules().save("rules.ini"),
+            Err(RulesError::UnsupportedFormat(extension)) if extension == "ini"
+        ));
+    }
+}
@@ -0,0 +1,134 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex, PoisonError, Weak};
+
+use handlebars::{HelperDef, RenderContext};
+
+/// State shared by all helper calls within a single render
+///
+/// handlebars-rust has no storage attached to a render, but it does drop the `RenderContext`,
+/// and with it all local helpers, once a render is done. The first access during a render
+/// registers a marker local helper owning fresh state, later accesses find the marker and look
+/// its state up by the marker's address. A weak reference guards against a new marker reusing the
+/// address of one from a finished render.
+pub(crate) struct RenderScoped<T> {
+    marker: &'static str,
+    states: Mutex<Vec<(usize, Weak<Mutex<T>>)>>,
+}
+
+/// Local helper owning the state of one render
+struct Marker<T> {
+    _state: Arc<Mutex<T>>,
+}
+
+impl<T: Send> HelperDef for Marker<T> {}
+
+impl<T: Default + Send + 'static> RenderScoped<T> {
+    /// Create render scoped state using `marker` as the local helper name.
+    pub(crate) const fn new(marker: &'static str) -> Self {
+        RenderScoped {
+            marker,
+            states: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Run `f` with the state of the render `rc` belongs to.
+    pub(crate) fn with<R>(&self, rc: &mut RenderContext, f: impl FnOnce(&mut T) -> R) -> R {
+        let state = match self.current(rc) {
+            Some(state) => state,
+            None => self.start(rc),
+        };
+
+        let mut state = state.lock().unwrap_or_else(PoisonError::into_inner);
+
+        f(&mut state)
+    }
+
+    fn current(&self, rc: &RenderContext) -> Option<Arc<Mutex<T>>> {
+        let address = address(&rc.get_local_helper(self.marker)?);
+        let states = self.states.lock().unwrap_or_else(PoisonError::into_inner);
+
+        states
+            .iter()
+            .find(|(a, _)| *a == address)
+            .and_then(|(_, state)| state.upgrade())
+    }
+
+    fn start(&self, rc: &mut RenderContext) -> Arc<Mutex<T>> {
+        let state = Arc::new(Mutex::new(T::default()));
+
+        rc.register_local_helper(
+            self.marker,
+            Box::new(Marker {
+                _state: state.clone(),
+            }),
+        );
+
+        if let Some(marker) = rc.get_local_helper(self.marker) {
+            let address = address(&marker);
+            let mut states = self.states.lock().unwrap_or_else(PoisonError::into_inner);
+
+            states.retain(|(a, state)| *a != address && state.strong_count() > 0);
+            states.push((address, Arc::downgrade(&state)));
+        }
+
+        state
+    }
+}
+
+fn address(marker: &Rc<dyn HelperDef + Send + Sync + '_>) -> usize {
+    Rc::as_ptr(marker) as *const () as usize
+}
+
+/// Occurrence counters keyed by a scope name and a value
+pub(crate) type Occurrences = HashMap<String, HashMap<String, usize>>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use handlebars::{Context, Handlebars, Helper, HelperResult, Output, RenderContext};
+
+    static COUNTER: RenderScoped<usize> = RenderScoped::new("__handlebars_inflector_test_counter");
+
+    struct Count;
+
+    impl HelperDef for Count {
+        fn call<'reg: 'rc, 'rc>(
+            &self,
+            _h: &Helper<'rc>,
+            _r: &'reg Handlebars,
+            _ctx: &'rc Context,
+            rc: &mut RenderContext<'reg, 'rc>,
+            out: &mut dyn Output,
+        ) -> HelperResult {
+            let count = COUNTER.with(rc, |count| {
+                *count += 1;
+                *count
+            });
+
+            out.write(&count.to_string())?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn it_scopes_state_to_a_render() {
+        let mut h = Handlebars::new();
+        h.register_helper("count", Box::new(Count));
+        h.register_partial("counted", "{{count}}")
+            .expect("Partial error");
+
+        for _ in 0..3 {
+            assert_eq!(
+                h.render_template(
+                    "{{count}}{{#each this}}{{count}}{{/each}}{{> counted}}",
+                    &[1, 2]
+                )
+                .expect("Render error"),
+                "1234",
+                "Failed to test render scoped state"
+            );
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use std::str::FromStr;
+
+use crate::reserved::is_sql_reserved;
+
+/// SQL dialect selected with the `dialect` hash option
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) enum Dialect {
+    /// PostgreSQL and SQLite: `"name"`
+    #[default]
+    Postgres,
+    /// MySQL and MariaDB: `` `name` ``
+    MySql,
+    /// Microsoft SQL Server: `[name]`
+    MsSql,
+}
+
+impl FromStr for Dialect {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "postgres" | "postgresql" | "sqlite" => Ok(Dialect::Postgres),
+            "mysql" | "mariadb" => Ok(Dialect::MySql),
+            "mssql" | "sqlserver" | "tsql" => Ok(Dialect::MsSql),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Dialect {
+    fn quotes(self) -> (char, char) {
+        match self {
+            Dialect::Postgres => ('"', '"'),
+            Dialect::MySql => ('`', '`'),
+            Dialect::MsSql => ('[', ']'),
+        }
+    }
+
+    /// Whether `name` can't be written as an unquoted identifier.
+    ///
+    /// PostgreSQL folds unquoted names to lowercase, so names with uppercase letters are quoted
+    /// to keep their case.
+    fn needs_quotes(self, name: &str) -> bool {
+        let valid = name
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+            && !(self == Dialect::Postgres && name.chars().any(|c| c.is_ascii_uppercase()));
+
+        !valid || is_sql_reserved(name)
+    }
+}
+
+/// Quote `name` as an identifier of `dialect` if it is a reserved word or contains characters
+/// that unquoted identifiers can't, doubling closing quotes inside it.
+pub(crate) fn quote_identifier(name: &str, dialect: Dialect) -> String {
+    if name.is_empty() || !dialect.needs_quotes(name) {
+        return name.to_string();
+    }
+
+    let (open, close) = dialect.quotes();
+    let mut output = String::with_capacity(name.len() + 2);
+
+    output.push(open);
+    for c in name.chars() {
+        if c == close {
+            output.push(c);
+        }
+        output.push(c);
+    }
+    output.push(close);
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_quotes_identifiers() {
+        for (input, dialect, expected) in [
+            ("product_images", Dialect::Postgres, "product_images"),
+            ("user", Dialect::Postgres, r#""user""#),
+            ("ORDER", Dialect::MySql, "`ORDER`"),
+            ("group", Dialect::MsSql, "[group]"),
+            ("ProductImages", Dialect::Postgres, r#""ProductImages""#),
+            ("ProductImages", Dialect::MySql, "ProductImages"),
+            ("product-images", Dialect::MySql, "`product-images`"),
+            ("2fa", Dialect::Postgres, r#""2fa""#),
+            ("a]b", Dialect::MsSql, "[a]]b]"),
+            (r#"say "hi""#, Dialect::Postgres, r#""say ""hi""""#),
+            ("", Dialect::Postgres, ""),
+        ] {
+            assert_eq!(
+                quote_identifier(input, dialect),
+                expected,
+                "Failed to test {} with {:?}",
+                input,
+                dialect
+            );
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use std::str::FromStr;
+
+use rust_stemmers::{Algorithm, Stemmer};
+
+/// Snowball stemmer language selected with the `stemmer` hash option
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct StemLanguage(Algorithm);
+
+impl Default for StemLanguage {
+    fn default() -> Self {
+        StemLanguage(Algorithm::English)
+    }
+}
+
+impl FromStr for StemLanguage {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let algorithm = match s.to_lowercase().as_str() {
+            "arabic" | "ar" => Algorithm::Arabic,
+            "danish" | "da" => Algorithm::Danish,
+            "dutch" | "nl" => Algorithm::Dutch,
+            "english" | "en" | "porter" => Algorithm::English,
+            "finnish" | "fi" => Algorithm::Finnish,
+            "french" | "fr" => Algorithm::French,
+            "german" | "de" => Algorithm::German,
+            "greek" | "el" => Algorithm::Greek,
+            "hungarian" | "hu" => Algorithm::Hungarian,
+            "italian" | "it" => Algorithm::Italian,
+            "norwegian" | "no" | "nb" => Algorithm::Norwegian,
+            "portuguese" | "pt" => Algorithm::Portuguese,
+            "romanian" | "ro" => Algorithm::Romanian,
+            "russian" | "ru" => Algorithm::Russian,
+            "spanish" | "es" => Algorithm::Spanish,
+            "swedish" | "sv" => Algorithm::Swedish,
+            "tamil" | "ta" => Algorithm::Tamil,
+            "turkish" | "tr" => Algorithm::Turkish,
+            _ => return Err(()),
+        };
+
+        Ok(StemLanguage(algorithm))
+    }
+}
+
+/// Reduce every word of `input` to its lowercase stem, keeping everything between words.
+pub(crate) fn stem(input: &str, language: StemLanguage) -> String {
+    let stemmer = Stemmer::create(language.0);
+    let mut output = String::with_capacity(input.len());
+    let mut word = String::new();
+
+    for c in input.chars().chain(std::iter::once('\0')) {
+        if c.is_alphanumeric() {
+            word.extend(c.to_lowercase());
+            continue;
+        }
+
+        if !word.is_empty() {
+            output.push_str(&stemmer.stem(&word));
+            word.clear();
+        }
+
+        if c != '\0' {
+            output.push(c);
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_stems() {
+        for (input, language, expected) in [
+            ("running", "english", "run"),
+            ("Running Shoes", "english", "run shoe"),
+            ("connected_connections", "en", "connect_connect"),
+            ("Häuser", "german", "haus"),
+            ("", "english", ""),
+        ] {
+            assert_eq!(
+                stem(input, language.parse().expect("Unsupported language")),
+                expected,
+                "Failed to stem {}",
+                input
+            );
+        }
+    }
+}
@@ -0,0 +1,222 @@
+use std::str::FromStr;
+
+use crate::case::{split_words, Boundaries, Convention};
+
+/// Initialisms Go keeps in a consistent case, following the list of `golint`
+const GO_INITIALISMS: &[&str] = &[
+    "ACL", "API", "ASCII", "CPU", "CSS", "DNS", "EOF", "GID", "GUID", "HTML", "HTTP", "HTTPS",
+    "ID", "IP", "JSON", "LHS", "QPS", "RAM", "RHS", "RPC", "SLA", "SMTP", "SQL", "SSH", "TCP",
+    "TLS", "TTL", "UDP", "UI", "UID", "URI", "URL", "UTF8", "UUID", "VM", "XML", "XMPP", "XSRF",
+    "XSS",
+];
+
+/// Target language selected with the `target` hash option
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Target {
+    Rust,
+    Go,
+    Java,
+    Python,
+    CSharp,
+    GraphQl,
+    Protobuf,
+}
+
+impl FromStr for Target {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "rust" => Ok(Target::Rust),
+            "go" => Ok(Target::Go),
+            "java" => Ok(Target::Java),
+            "python" => Ok(Target::Python),
+            "csharp" | "c#" => Ok(Target::CSharp),
+            "graphql" => Ok(Target::GraphQl),
+            "protobuf" | "proto" => Ok(Target::Protobuf),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Kind of name selected with the `kind` hash option
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Kind {
+    /// Structs, classes, enums, interfaces and traits
+    Type,
+    /// Functions and methods
+    Function,
+    /// Variables, parameters and fields
+    Variable,
+    /// Constants and statics
+    Constant,
+    /// Enum variants and members
+    EnumValue,
+}
+
+impl FromStr for Kind {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "type" => Ok(Kind::Type),
+            "function" | "method" => Ok(Kind::Function),
+            "variable" | "field" | "parameter" => Ok(Kind::Variable),
+            "constant" => Ok(Kind::Constant),
+            "enum_value" | "variant" => Ok(Kind::EnumValue),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Target {
+    /// Standard naming convention of this language for names of `kind`.
+    ///
+    /// Go names are the exported form, so functions and constants are in pascal case. GraphQL
+    /// functions and variables are fields and arguments, protobuf functions are RPC methods.
+    pub(crate) fn convention(self, kind: Kind) -> Convention {
+        match (self, kind) {
+            (_, Kind::Type) => Convention::Pascal,
+            (Target::Rust | Target::Python, Kind::Function | Kind::Variable) => Convention::Snake,
+            (Target::Protobuf, Kind::Variable) => Convention::Snake,
+            (
+                Target::Rust | Target::Python | Target::Java | Target::GraphQl | Target::Protobuf,
+                Kind::Constant,
+            ) => Convention::ScreamingSnake,
+            (
+                Target::Python | Target::Java | Target::GraphQl | Target::Protobuf,
+                Kind::EnumValue,
+            ) => Convention::ScreamingSnake,
+            (Target::Go | Target::CSharp | Target::Protobuf, Kind::Function) => Convention::Pascal,
+            (Target::Go | Target::CSharp, Kind::Constant) => Convention::Pascal,
+            (Target::Rust | Target::Go | Target::CSharp, Kind::EnumValue) => Convention::Pascal,
+            (Target::Java | Target::GraphQl, Kind::Function) => Convention::Camel,
+            (Target::Go | Target::Java | Target::CSharp | Target::GraphQl, Kind::Variable) => {
+                Convention::Camel
+            }
+        }
+    }
+
+    /// Convention of exported or unexported names, for languages where visibility is part of
+    /// the name: `UserID` and `userID` in Go.
+    pub(crate) fn visibility_convention(self, exported: bool) -> Option<Convention> {
+        match (self, exported) {
+            (Target::Go, true) => Some(Convention::Pascal),
+            (Target::Go, false) => Some(Convention::Camel),
+            _ => None,
+        }
+    }
+
+    /// Prefix the enum value `value` with the name of its enum, for languages where enum values
+    /// share one namespace: `ACTIVE` of `OrderStatus` to `ORDER_STATUS_ACTIVE` in protobuf.
+    ///
+    /// Values already carrying the prefix are left as they are.
+    pub(crate) fn prefix_enum_value(self, value: String, enum_name: &str) -> String {
+        if self != Target::Protobuf {
+            return value;
+        }
+
+        let prefix =
+            Convention::ScreamingSnake.join(&split_words(enum_name, Boundaries::default()));
+
+        if prefix.is_empty() || value.starts_with(&format!("{}_", prefix)) {
+            value
+        } else {
+            format!("{}_{}", prefix, value)
+        }
+    }
+
+    /// Initialisms this language keeps in uppercase on top of the registered acronyms.
+    pub(crate) fn initialisms(self) -> &'static [&'static str] {
+        match self {
+            Target::Go => GO_INITIALISMS,
+            _ => &[],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_picks_language_conventions() {
+        for (target, kind, expected) in [
+            ("rust", "type", Convention::Pascal),
+            ("rust", "function", Convention::Snake),
+            ("rust", "constant", Convention::ScreamingSnake),
+            ("go", "function", Convention::Pascal),
+            ("go", "variable", Convention::Camel),
+            ("java", "method", Convention::Camel),
+            ("java", "constant", Convention::ScreamingSnake),
+            ("python", "field", Convention::Snake),
+            ("csharp", "constant", Convention::Pascal),
+            ("C#", "parameter", Convention::Camel),
+            ("rust", "variant", Convention::Pascal),
+            ("python", "enum_value", Convention::ScreamingSnake),
+            ("graphql", "type", Convention::Pascal),
+            ("graphql", "field", Convention::Camel),
+            ("graphql", "enum_value", Convention::ScreamingSnake),
+            ("protobuf", "type", Convention::Pascal),
+            ("proto", "field", Convention::Snake),
+            ("protobuf", "function", Convention::Pascal),
+            ("protobuf", "enum_value", Convention::ScreamingSnake),
+        ] {
+            let target: Target = target.parse().expect("Unknown target");
+            let kind: Kind = kind.parse().expect("Unknown kind");
+
+            assert_eq!(
+                target.convention(kind),
+                expected,
+                "Failed to test {:?} {:?}",
+                target,
+                kind
+            );
+        }
+    }
+
+    #[test]
+    fn it_picks_visibility_conventions() {
+        for (target, exported, expected) in [
+            (Target::Go, true, Some(Convention::Pascal)),
+            (Target::Go, false, Some(Convention::Camel)),
+            (Target::Rust, true, None),
+        ] {
+            assert_eq!(
+                target.visibility_convention(exported),
+                expected,
+                "Failed to test {:?} {}",
+                target,
+                exported
+            );
+        }
+
+        for (target, value, expected) in [
+            (Target::Protobuf, "ACTIVE", "ORDER_STATUS_ACTIVE"),
+            (
+                Target::Protobuf,
+                "ORDER_STATUS_ACTIVE",
+                "ORDER_STATUS_ACTIVE",
+            ),
+            (
+                Target::Protobuf,
+                "ORDER_STATUSES",
+                "ORDER_STATUS_ORDER_STATUSES",
+            ),
+            (Target::GraphQl, "ACTIVE", "ACTIVE"),
+        ] {
+            assert_eq!(
+                target.prefix_enum_value(value.to_string(), "OrderStatus"),
+                expected,
+                "Failed to test {:?} {}",
+                target,
+                value
+            );
+        }
+
+        assert!(
+            Target::Go.initialisms().contains(&"HTTP"),
+            "Failed to test Go initialisms"
+        );
+    }
+}
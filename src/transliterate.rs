@@ -0,0 +1,220 @@
+use std::str::FromStr;
+
+#[cfg(feature = "pinyin")]
+use pinyin::ToPinyin;
+#[cfg(feature = "romaji")]
+use wana_kana::ConvertJapanese;
+
+/// Transliteration scheme selected with the `scheme` hash option
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Scheme {
+    /// Hanyu Pinyin without tone marks, one word per syllable (`中国` to `zhong guo`)
+    #[cfg(feature = "pinyin")]
+    Pinyin,
+    /// Hepburn romanization of hiragana and katakana (`カタカナ` to `katakana`)
+    #[cfg(feature = "romaji")]
+    Romaji,
+}
+
+impl FromStr for Scheme {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            #[cfg(feature = "pinyin")]
+            "pinyin" => Ok(Scheme::Pinyin),
+            #[cfg(feature = "romaji")]
+            "romaji" => Ok(Scheme::Romaji),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Transliterate `input` to latin script with the given scheme.
+///
+/// Characters the scheme doesn't cover are left untouched.
+#[cfg_attr(
+    not(any(feature = "pinyin", feature = "romaji")),
+    allow(unused_variables)
+)]
+pub(crate) fn transliterate(input: &str, scheme: Scheme) -> String {
+    match scheme {
+        #[cfg(feature = "pinyin")]
+        Scheme::Pinyin => to_pinyin(input),
+        #[cfg(feature = "romaji")]
+        Scheme::Romaji => input.to_romaji(),
+    }
+}
+
+/// Replace every Han character with its plain pinyin syllable, separating syllables from each
+/// other and from the surrounding text with a space so case operations see them as words.
+#[cfg(feature = "pinyin")]
+fn to_pinyin(input: &str) -> String {
+    let mut output = String::with_capacity(input.len() * 2);
+    let mut after_syllable = false;
+
+    for c in input.chars() {
+        if let Some(syllable) = c.to_pinyin() {
+            if !output.is_empty() && !output.ends_with(' ') {
+                output.push(' ');
+            }
+            output.push_str(syllable.plain());
+            after_syllable = true;
+        } else {
+            if after_syllable && !c.is_whitespace() {
+                output.push(' ');
+            }
+            output.push(c);
+            after_syllable = false;
+        }
+    }
+
+    output
+}
+
+/// Latin letters with diacritics and ligatures, grouped by their ASCII replacement
+const LATIN: &[(&str, &str)] = &[
+    ("ÀÁÂÃÄÅĀĂĄ", "A"),
+    ("àáâãäåāăą", "a"),
+    ("ÇĆĈĊČ", "C"),
+    ("çćĉċč", "c"),
+    ("ÐĎĐ", "D"),
+    ("ðďđ", "d"),
+    ("ÈÉÊËĒĔĖĘĚ", "E"),
+    ("èéêëēĕėęě", "e"),
+    ("ĜĞĠĢ", "G"),
+    ("ĝğġģ", "g"),
+    ("ĤĦ", "H"),
+    ("ĥħ", "h"),
+    ("ÌÍÎÏĨĪĬĮİ", "I"),
+    ("ìíîïĩīĭįı", "i"),
+    ("Ĵ", "J"),
+    ("ĵ", "j"),
+    ("Ķ", "K"),
+    ("ķĸ", "k"),
+    ("ĹĻĽĿŁ", "L"),
+    ("ĺļľŀł", "l"),
+    ("ÑŃŅŇŊ", "N"),
+    ("ñńņňŉŋ", "n"),
+    ("ÒÓÔÕÖØŌŎŐ", "O"),
+    ("òóôõöøōŏő", "o"),
+    ("ŔŖŘ", "R"),
+    ("ŕŗř", "r"),
+    ("ŚŜŞŠȘ", "S"),
+    ("śŝşšșſ", "s"),
+    ("ŢŤŦȚ", "T"),
+    ("ţťŧț", "t"),
+    ("ÙÚÛÜŨŪŬŮŰŲ", "U"),
+    ("ùúûüũūŭůűų", "u"),
+    ("Ŵ", "W"),
+    ("ŵ", "w"),
+    ("ÝŶŸ", "Y"),
+    ("ýÿŷ", "y"),
+    ("ŹŻŽ", "Z"),
+    ("źżž", "z"),
+    ("Æ", "AE"),
+    ("æ", "ae"),
+    ("Ĳ", "IJ"),
+    ("ĳ", "ij"),
+    ("Œ", "OE"),
+    ("œ", "oe"),
+    ("Þ", "TH"),
+    ("þ", "th"),
+    ("ß", "ss"),
+];
+
+/// Replace Latin letters with diacritics and ligatures with their ASCII base letters
+/// (`Crème Brûlée` to `Creme Brulee`), leaving other characters untouched.
+pub(crate) fn fold_diacritics(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+
+    for c in input.chars() {
+        match LATIN.iter().find(|(letters, _)| letters.contains(c)) {
+            Some((_, ascii)) => output.push_str(ascii),
+            None => output.push(c),
+        }
+    }
+
+    output
+}
+
+/// URL slug of `input`: diacritics folded, lowercase ASCII letters and digits only, words joined
+/// with `separator` and apostrophes dropped (`Crème Brûlée`, `-` to `creme-brulee`).
+pub(crate) fn parameterize(input: &str, separator: &str) -> String {
+    fold_diacritics(input)
+        .replace(['\'', '’'], "")
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_ascii_lowercase)
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "pinyin")]
+    fn it_transliterates_pinyin() {
+        assert_eq!(
+            transliterate("北京大学", Scheme::Pinyin),
+            "bei jing da xue",
+            "Failed to test pinyin"
+        );
+        assert_eq!(
+            transliterate("我的first帖子", Scheme::Pinyin),
+            "wo de first tie zi",
+            "Failed to test mixed pinyin"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "romaji")]
+    fn it_transliterates_romaji() {
+        assert_eq!(
+            transliterate("ひらがな カタカナ", Scheme::Romaji),
+            "hiragana katakana",
+            "Failed to test romaji"
+        );
+    }
+
+    #[test]
+    fn it_folds_diacritics() {
+        for (input, expected) in [
+            ("Crème Brûlée", "Creme Brulee"),
+            ("Ærøskøbing", "AEroskobing"),
+            ("Straße", "Strasse"),
+            ("Łódź", "Lodz"),
+            ("Ștefan Țiriac", "Stefan Tiriac"),
+            ("北京 ok", "北京 ok"),
+        ] {
+            assert_eq!(fold_diacritics(input), expected, "Failed to test {}", input);
+        }
+    }
+
+    #[test]
+    fn it_parameterizes() {
+        for (input, separator, expected) in [
+            ("Crème Brûlée", "-", "creme-brulee"),
+            ("  Don't  Panic!  ", "-", "dont-panic"),
+            ("C'est l’été 2024", "_", "cest-lete-2024"),
+            ("100% <b>Pure</b> & Simple", "-", "100-b-pure-b-simple"),
+            ("北京", "-", ""),
+        ] {
+            let expected = expected.replace('-', separator);
+
+            assert_eq!(
+                parameterize(input, separator),
+                expected,
+                "Failed to test {}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn it_rejects_unknown_schemes() {
+        assert!("klingon".parse::<Scheme>().is_err());
+    }
+}
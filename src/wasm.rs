@@ -0,0 +1,67 @@
+use wasm_bindgen::prelude::*;
+
+use crate::{Pipeline, Rules};
+
+/// [`Pipeline`] exported to JavaScript as `Pipeline`
+///
+/// ```js
+/// const pipeline = new Pipeline();
+/// pipeline.loadRules('{"reserved": ["type"]}');
+/// pipeline.addOp("snake");
+/// pipeline.addOp("plural");
+///
+/// pipeline.apply("ProductImage"); // "product_images"
+/// ```
+#[wasm_bindgen(js_name = Pipeline)]
+#[derive(Default)]
+pub struct JsPipeline {
+    pipeline: Pipeline,
+}
+
+#[wasm_bindgen(js_class = Pipeline)]
+impl JsPipeline {
+    /// Create an empty pipeline
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append an operation: `to_snake_case`, `snake`, `to=kebab` or `phonetic=soundex`
+    #[wasm_bindgen(js_name = addOp)]
+    pub fn add_op(&mut self, op: &str) -> Result<(), JsError> {
+        self.pipeline = std::mem::take(&mut self.pipeline).with_op(op)?;
+        Ok(())
+    }
+
+    /// Pass a hash option like `profile` or `scheme` to every operation
+    #[wasm_bindgen(js_name = setOption)]
+    pub fn set_option(&mut self, key: &str, value: &str) -> Result<(), JsError> {
+        self.pipeline = std::mem::take(&mut self.pipeline).with_option(key, value)?;
+        Ok(())
+    }
+
+    /// Add custom rules from their JSON representation, see [`Rules`]
+    #[wasm_bindgen(js_name = loadRules)]
+    pub fn load_rules(&mut self, json: &str) -> Result<(), JsError> {
+        let rules: Rules = serde_json::from_str(json)?;
+        self.pipeline = std::mem::take(&mut self.pipeline).with_rule_set(rules);
+        Ok(())
+    }
+
+    /// Apply all operations to `input` in order
+    pub fn apply(&self, input: &str) -> Result<String, JsError> {
+        Ok(self.pipeline.apply(input)?)
+    }
+}
+
+/// Names of all operations, value operations written as `name=VALUE`
+#[wasm_bindgen]
+pub fn operations() -> Vec<String> {
+    Pipeline::operations()
+}
+
+/// Names of the identifier profiles accepted by the `profile` option
+#[wasm_bindgen]
+pub fn profiles() -> Vec<String> {
+    Pipeline::profiles()
+}